@@ -1,4 +1,8 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::interval,
+};
 
 /// A generator returning the absolute value of the results of the underlying generator.
 ///
@@ -35,4 +39,14 @@ where
     fn sample(&self, point: [f64; D]) -> f64 {
         self.generator.sample(point).abs()
     }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        self.generator.output_range().map(interval::abs)
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Abs").with_child(self.generator.describe())
+    }
 }
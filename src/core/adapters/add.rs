@@ -1,4 +1,8 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::interval,
+};
 
 /// A generator adding `offset` to results of the underlying generator.
 ///
@@ -36,4 +40,17 @@ where
     fn sample(&self, point: [f64; D]) -> f64 {
         self.generator.sample(point) + self.offset
     }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        let (min, max) = self.generator.output_range()?;
+        interval::new(min + self.offset, max + self.offset)
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Add")
+            .with_parameter("offset", self.offset)
+            .with_child(self.generator.describe())
+    }
 }
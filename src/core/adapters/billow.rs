@@ -1,4 +1,9 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    error::{NoiseError, validate_octaves},
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::interval,
+};
 
 /// Create a generator applying an `fbm()`-like effect on the underlying generator.
 ///
@@ -44,6 +49,24 @@ where
             normalization_factor,
         }
     }
+
+    #[inline]
+    pub fn try_new(
+        generator: G,
+        octaves: u32,
+        frequency: f64,
+        lacunarity: f64,
+        persistence: f64,
+    ) -> Result<Self, NoiseError> {
+        validate_octaves(octaves, lacunarity)?;
+        Ok(Self::new(
+            generator,
+            octaves,
+            frequency,
+            lacunarity,
+            persistence,
+        ))
+    }
 }
 
 macro_rules! impl_generator {
@@ -54,17 +77,34 @@ macro_rules! impl_generator {
                 let mut amp = 1.0;
                 let mut freq = self.frequency;
                 for _ in 0..self.octaves {
-                    noise += amp
-                        * self
-                            .generator
-                            .sample(point.map(|x| x * freq))
-                            .abs()
-                            .mul_add(2.0, -1.0);
+                    let layer = self.generator.sample(point.map(|x| x * freq)).abs();
+                    noise += amp * (layer * 2.0 - 1.0);
                     freq *= self.lacunarity;
                     amp *= self.persistence;
                 }
                 noise * self.normalization_factor
             }
+
+            fn output_range(&self) -> Option<(f64, f64)> {
+                let (min, max) = interval::abs(self.generator.output_range()?);
+                let range = interval::new(min * 2.0 - 1.0, max * 2.0 - 1.0)?;
+                let mut noise = (0.0, 0.0);
+                let mut amp = 1.0;
+                for _ in 0..self.octaves {
+                    noise = interval::add(noise, interval::scale(range, amp)?)?;
+                    amp *= self.persistence;
+                }
+                interval::scale(noise, self.normalization_factor)
+            }
+
+            fn describe(&self) -> GraphNode {
+                GraphNode::new("Billow")
+                    .with_parameter("octaves", self.octaves)
+                    .with_parameter("frequency", self.frequency)
+                    .with_parameter("lacunarity", self.lacunarity)
+                    .with_parameter("persistence", self.persistence)
+                    .with_child(self.generator.describe())
+            }
         }
     };
 }
@@ -76,5 +116,6 @@ impl_generator!(4);
 
 #[inline]
 fn compute_normalization_factor(octaves: u32, persistence: f64) -> f64 {
-    1.0 / (0..octaves).fold(0.0, |acc, octave| acc + persistence.powi(octave as i32))
+    let (sum, _) = (0..octaves).fold((0.0, 1.0), |(sum, amp), _| (sum + amp, amp * persistence));
+    1.0 / sum
 }
@@ -1,4 +1,8 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::interval,
+};
 
 /// A generator blending the underlying generator with a given other generator based on the
 /// value supplied by a control-generator.
@@ -13,6 +17,7 @@ pub struct Blend<const D: usize, GA, GB, GC> {
     generator_a: GA,
     generator_b: GB,
     generator_control: GC,
+    clamp_control: bool,
 }
 
 impl<GA: Generator<1>, GB: Generator<1>, GC: Generator<1>> Generator1D for Blend<1, GA, GB, GC> {}
@@ -32,6 +37,17 @@ where
             generator_a,
             generator_b,
             generator_control,
+            clamp_control: false,
+        }
+    }
+
+    #[inline]
+    pub fn new_clamped(generator_a: GA, generator_b: GB, generator_control: GC) -> Self {
+        Self {
+            generator_a,
+            generator_b,
+            generator_control,
+            clamp_control: true,
         }
     }
 }
@@ -44,9 +60,43 @@ where
 {
     #[inline]
     fn sample(&self, point: [f64; D]) -> f64 {
-        let a = self.generator_a.sample(point);
-        let b = self.generator_b.sample(point);
-        let t = self.generator_control.sample(point) * 0.5 + 0.5;
-        a + t * (b - a)
+        let mut t = self.generator_control.sample(point) * 0.5 + 0.5;
+        if self.clamp_control {
+            t = t.clamp(0.0, 1.0);
+        }
+        // only sample generators which contribute to the result
+        match t {
+            0.0 => self.generator_a.sample(point),
+            1.0 => self.generator_b.sample(point),
+            t => {
+                let a = self.generator_a.sample(point);
+                let b = self.generator_b.sample(point);
+                a + t * (b - a)
+            }
+        }
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        let a = self.generator_a.output_range()?;
+        let b = self.generator_b.output_range()?;
+        let t = match self.generator_control.output_range() {
+            Some((min, max)) => (min * 0.5 + 0.5, max * 0.5 + 0.5),
+            None => (f64::NEG_INFINITY, f64::INFINITY),
+        };
+        let t = match self.clamp_control {
+            true => (t.0.clamp(0.0, 1.0), t.1.clamp(0.0, 1.0)),
+            false => t,
+        };
+        interval::lerp(a, b, t)
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Blend")
+            .with_parameter("clamp_control", self.clamp_control)
+            .with_labeled_child("a", self.generator_a.describe())
+            .with_labeled_child("b", self.generator_b.describe())
+            .with_labeled_child("control", self.generator_control.describe())
     }
 }
@@ -0,0 +1,98 @@
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+};
+use std::{
+    cell::RefCell,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// The maximum number of cached generators for which the last sample is remembered per thread.
+const CACHE_CAPACITY: usize = 64;
+
+static NEXT_CACHE_ID: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    /// The last sample of recently used cached generators, as pairs of cache id and the bit
+    /// patterns of the point together with the value, ordered from least to most recently added.
+    static LAST_SAMPLES: RefCell<Vec<(usize, Vec<u64>, f64)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// A generator remembering the last point sampled from the underlying generator and the
+/// corresponding value.
+///
+/// For details, see the documentation of [`cached()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`cached()`] implemented by [`Generator`], should be used
+/// to create [`Cached`].
+///
+/// [`cached()`]: Generator::cached
+#[derive(Clone, Copy, Debug)]
+pub struct Cached<const D: usize, G> {
+    generator: G,
+    id: usize,
+}
+
+impl<G: Generator<1>> Generator1D for Cached<1, G> {}
+impl<G: Generator<2>> Generator2D for Cached<2, G> {}
+impl<G: Generator<3>> Generator3D for Cached<3, G> {}
+impl<G: Generator<4>> Generator4D for Cached<4, G> {}
+
+impl<const D: usize, G> Cached<D, G>
+where
+    G: Generator<D>,
+{
+    #[inline]
+    pub fn new(generator: G) -> Self {
+        Self {
+            generator,
+            id: NEXT_CACHE_ID.fetch_add(1, Ordering::Relaxed),
+        }
+    }
+}
+
+impl<const D: usize, G> Generator<D> for Cached<D, G>
+where
+    G: Generator<D>,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        let key = point.map(f64::to_bits);
+        let cached = LAST_SAMPLES.with_borrow(|samples| {
+            samples
+                .iter()
+                .find(|(id, last_key, _)| *id == self.id && last_key[..] == key[..])
+                .map(|&(_, _, value)| value)
+        });
+        if let Some(value) = cached {
+            return value;
+        }
+        // the cache must not be borrowed while sampling, as the underlying generator may itself
+        // contain cached generators
+        let value = self.generator.sample(point);
+        LAST_SAMPLES.with_borrow_mut(|samples| {
+            match samples.iter_mut().find(|(id, _, _)| *id == self.id) {
+                Some((_, last_key, last_value)) => {
+                    last_key.copy_from_slice(&key);
+                    *last_value = value;
+                }
+                None => {
+                    if samples.len() == CACHE_CAPACITY {
+                        samples.remove(0);
+                    }
+                    samples.push((self.id, key.to_vec(), value));
+                }
+            }
+        });
+        value
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        self.generator.output_range()
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Cached").with_child(self.generator.describe())
+    }
+}
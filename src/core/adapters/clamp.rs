@@ -1,4 +1,8 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::interval,
+};
 
 /// A generator clamping results of the underlying generator to a given interval.
 ///
@@ -44,4 +48,24 @@ where
     fn sample(&self, point: [f64; D]) -> f64 {
         self.generator.sample(point).clamp(self.min, self.max)
     }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        let (min, max) = self
+            .generator
+            .output_range()
+            .unwrap_or((self.min, self.max));
+        interval::new(
+            min.max(self.min).min(self.max),
+            max.max(self.min).min(self.max),
+        )
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Clamp")
+            .with_parameter("min", self.min)
+            .with_parameter("max", self.max)
+            .with_child(self.generator.describe())
+    }
 }
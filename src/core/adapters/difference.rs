@@ -0,0 +1,69 @@
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::interval,
+};
+
+/// A generator combining results of the underlying generator and results of a given other
+/// generator using the difference blend mode.
+///
+/// For details, see the documentation of [`difference()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`difference()`] implemented by [`Generator`], should be used
+/// to create [`Difference`].
+///
+/// [`difference()`]: Generator::difference
+#[derive(Clone, Copy, Debug)]
+pub struct Difference<const D: usize, GA, GB> {
+    generator_a: GA,
+    generator_b: GB,
+}
+
+impl<GA: Generator<1>, GB: Generator<1>> Generator1D for Difference<1, GA, GB> {}
+impl<GA: Generator<2>, GB: Generator<2>> Generator2D for Difference<2, GA, GB> {}
+impl<GA: Generator<3>, GB: Generator<3>> Generator3D for Difference<3, GA, GB> {}
+impl<GA: Generator<4>, GB: Generator<4>> Generator4D for Difference<4, GA, GB> {}
+
+impl<const D: usize, GA, GB> Difference<D, GA, GB>
+where
+    GA: Generator<D>,
+    GB: Generator<D>,
+{
+    #[inline]
+    pub fn new(generator_a: GA, generator_b: GB) -> Self {
+        Self {
+            generator_a,
+            generator_b,
+        }
+    }
+}
+
+impl<const D: usize, GA, GB> Generator<D> for Difference<D, GA, GB>
+where
+    GA: Generator<D>,
+    GB: Generator<D>,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        // map to the [0, 1] range used by image editors and back
+        let a = self.generator_a.sample(point) * 0.5 + 0.5;
+        let b = self.generator_b.sample(point) * 0.5 + 0.5;
+        let result = (a - b).abs();
+        result * 2.0 - 1.0
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        let a = self.generator_a.output_range()?;
+        let b = self.generator_b.output_range()?;
+        // the absolute difference of the mapped values is half the absolute difference
+        let difference = interval::abs(interval::sub(a, b)?);
+        interval::new(difference.0 - 1.0, difference.1 - 1.0)
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Difference")
+            .with_labeled_child("a", self.generator_a.describe())
+            .with_labeled_child("b", self.generator_b.describe())
+    }
+}
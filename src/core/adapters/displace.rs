@@ -1,4 +1,7 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+};
 
 /// A generator producing the maximum of results of the underlying generator and results of
 /// a given other generator.
@@ -43,4 +46,17 @@ where
         point[A] += self.displacement_generator.sample(point);
         self.generator.sample(point)
     }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        self.generator.output_range()
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Displace")
+            .with_parameter("axis", A)
+            .with_child(self.generator.describe())
+            .with_labeled_child("displacement", self.displacement_generator.describe())
+    }
 }
@@ -1,4 +1,10 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::interval,
+};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
 
 /// A generator applying the exponential function on results of the underlying generator.
 ///
@@ -35,4 +41,14 @@ where
     fn sample(&self, point: [f64; D]) -> f64 {
         self.generator.sample(point).exp()
     }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        interval::map_increasing(self.generator.output_range()?, f64::exp)
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Exp").with_child(self.generator.describe())
+    }
 }
@@ -1,4 +1,9 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    error::{NoiseError, validate_octaves},
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::interval,
+};
 
 /// A generator applying fractal brownian motion on the underlying generator.
 ///
@@ -44,6 +49,24 @@ where
             normalization_factor,
         }
     }
+
+    #[inline]
+    pub fn try_new(
+        generator: G,
+        octaves: u32,
+        frequency: f64,
+        lacunarity: f64,
+        persistence: f64,
+    ) -> Result<Self, NoiseError> {
+        validate_octaves(octaves, lacunarity)?;
+        Ok(Self::new(
+            generator,
+            octaves,
+            frequency,
+            lacunarity,
+            persistence,
+        ))
+    }
 }
 
 macro_rules! impl_generator {
@@ -60,6 +83,26 @@ macro_rules! impl_generator {
                 }
                 noise * self.normalization_factor
             }
+
+            fn output_range(&self) -> Option<(f64, f64)> {
+                let range = self.generator.output_range()?;
+                let mut noise = (0.0, 0.0);
+                let mut amp = 1.0;
+                for _ in 0..self.octaves {
+                    noise = interval::add(noise, interval::scale(range, amp)?)?;
+                    amp *= self.persistence;
+                }
+                interval::scale(noise, self.normalization_factor)
+            }
+
+            fn describe(&self) -> GraphNode {
+                GraphNode::new("Fbm")
+                    .with_parameter("octaves", self.octaves)
+                    .with_parameter("frequency", self.frequency)
+                    .with_parameter("lacunarity", self.lacunarity)
+                    .with_parameter("persistence", self.persistence)
+                    .with_child(self.generator.describe())
+            }
         }
     };
 }
@@ -71,5 +114,8 @@ impl_generator!(4);
 
 #[inline]
 fn compute_normalization_factor(octaves: u32, persistence: f64) -> f64 {
-    1.0 / (0..octaves).fold(0.0, |acc, octave| acc + persistence.powi(octave as i32))
+    // amplitudes are accumulated by repeated multiplication, as the precision of powi is
+    // unspecified and may differ across platforms
+    let (sum, _) = (0..octaves).fold((0.0, 1.0), |(sum, amp), _| (sum + amp, amp * persistence));
+    1.0 / sum
 }
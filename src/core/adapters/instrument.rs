@@ -0,0 +1,56 @@
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    profile,
+};
+
+/// A generator recording the number of samples and the time spent sampling the underlying
+/// generator.
+///
+/// For details, see the documentation of [`instrument()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`instrument()`] implemented by [`Generator`], should be used
+/// to create [`Instrument`].
+///
+/// [`instrument()`]: Generator::instrument
+#[derive(Clone, Copy, Debug)]
+pub struct Instrument<const D: usize, G> {
+    generator: G,
+    name: &'static str,
+}
+
+impl<G: Generator<1>> Generator1D for Instrument<1, G> {}
+impl<G: Generator<2>> Generator2D for Instrument<2, G> {}
+impl<G: Generator<3>> Generator3D for Instrument<3, G> {}
+impl<G: Generator<4>> Generator4D for Instrument<4, G> {}
+
+impl<const D: usize, G> Instrument<D, G>
+where
+    G: Generator<D>,
+{
+    #[inline]
+    pub fn new(generator: G, name: &'static str) -> Self {
+        Self { generator, name }
+    }
+}
+
+impl<const D: usize, G> Generator<D> for Instrument<D, G>
+where
+    G: Generator<D>,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        profile::record(self.name, || self.generator.sample(point))
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        self.generator.output_range()
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Instrument")
+            .with_parameter("name", self.name)
+            .with_child(self.generator.describe())
+    }
+}
@@ -1,4 +1,7 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+};
 
 /// A generator applying the supplied closure to results of the underlying generator.
 ///
@@ -38,4 +41,9 @@ where
     fn sample(&self, point: [f64; D]) -> f64 {
         (self.lambda)(self.generator.sample(point))
     }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Lambda").with_child(self.generator.describe())
+    }
 }
@@ -0,0 +1,75 @@
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::{interval, lerp},
+};
+
+/// A generator linearly interpolating between results of the underlying generator and results
+/// of a given other generator.
+///
+/// For details, see the documentation of [`lerp()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`lerp()`] implemented by [`Generator`], should be used
+/// to create [`Lerp`].
+///
+/// [`lerp()`]: Generator::lerp
+#[derive(Clone, Copy, Debug)]
+pub struct Lerp<const D: usize, GA, GB> {
+    generator_a: GA,
+    generator_b: GB,
+    t: f64,
+}
+
+impl<GA: Generator<1>, GB: Generator<1>> Generator1D for Lerp<1, GA, GB> {}
+impl<GA: Generator<2>, GB: Generator<2>> Generator2D for Lerp<2, GA, GB> {}
+impl<GA: Generator<3>, GB: Generator<3>> Generator3D for Lerp<3, GA, GB> {}
+impl<GA: Generator<4>, GB: Generator<4>> Generator4D for Lerp<4, GA, GB> {}
+
+impl<const D: usize, GA, GB> Lerp<D, GA, GB>
+where
+    GA: Generator<D>,
+    GB: Generator<D>,
+{
+    #[inline]
+    pub fn new(generator_a: GA, generator_b: GB, t: f64) -> Self {
+        Self {
+            generator_a,
+            generator_b,
+            t,
+        }
+    }
+}
+
+impl<const D: usize, GA, GB> Generator<D> for Lerp<D, GA, GB>
+where
+    GA: Generator<D>,
+    GB: Generator<D>,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        // only sample generators which contribute to the result
+        match self.t {
+            0.0 => self.generator_a.sample(point),
+            1.0 => self.generator_b.sample(point),
+            t => lerp(
+                self.generator_a.sample(point),
+                self.generator_b.sample(point),
+                t,
+            ),
+        }
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        let a = self.generator_a.output_range()?;
+        let b = self.generator_b.output_range()?;
+        interval::lerp(a, b, (self.t, self.t))
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Lerp")
+            .with_parameter("t", self.t)
+            .with_labeled_child("a", self.generator_a.describe())
+            .with_labeled_child("b", self.generator_b.describe())
+    }
+}
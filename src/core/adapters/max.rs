@@ -1,4 +1,8 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::interval,
+};
 
 /// A generator producing the maximum of results of the underlying generator and results of
 /// a given other generator.
@@ -44,4 +48,18 @@ where
             .sample(point)
             .max(self.generator_b.sample(point))
     }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        let a = self.generator_a.output_range()?;
+        let b = self.generator_b.output_range()?;
+        interval::new(a.0.max(b.0), a.1.max(b.1))
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Max")
+            .with_child(self.generator_a.describe())
+            .with_child(self.generator_b.describe())
+    }
 }
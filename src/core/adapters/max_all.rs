@@ -0,0 +1,71 @@
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+};
+use alloc::vec::Vec;
+
+/// A generator producing the maximum of the results of all generators of a collection.
+///
+/// For details, see the documentation of [`max_all()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`max_all()`] implemented by [`Source`], should be used
+/// to create [`MaxAll`].
+///
+/// [`max_all()`]: crate::Source::max_all
+/// [`Source`]: crate::Source
+#[derive(Clone, Debug)]
+pub struct MaxAll<const D: usize, G> {
+    generators: Vec<G>,
+}
+
+impl<G: Generator<1>> Generator1D for MaxAll<1, G> {}
+impl<G: Generator<2>> Generator2D for MaxAll<2, G> {}
+impl<G: Generator<3>> Generator3D for MaxAll<3, G> {}
+impl<G: Generator<4>> Generator4D for MaxAll<4, G> {}
+
+impl<const D: usize, G> MaxAll<D, G>
+where
+    G: Generator<D>,
+{
+    #[inline]
+    pub fn new<I>(generators: I) -> Self
+    where
+        I: IntoIterator<Item = G>,
+    {
+        Self {
+            generators: generators.into_iter().collect(),
+        }
+    }
+}
+
+impl<const D: usize, G> Generator<D> for MaxAll<D, G>
+where
+    G: Generator<D>,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        self.generators
+            .iter()
+            .map(|generator| generator.sample(point))
+            .fold(f64::NEG_INFINITY, f64::max)
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        self.generators.iter().try_fold(
+            (f64::NEG_INFINITY, f64::NEG_INFINITY),
+            |range, generator| {
+                let (min, max) = generator.output_range()?;
+                Some((range.0.max(min), range.1.max(max)))
+            },
+        )
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        self.generators
+            .iter()
+            .fold(GraphNode::new("MaxAll"), |node, generator| {
+                node.with_child(generator.describe())
+            })
+    }
+}
@@ -2,45 +2,95 @@ mod abs;
 mod add;
 mod billow;
 mod blend;
+#[cfg(feature = "std")]
+mod cached;
 mod clamp;
+mod difference;
 mod displace;
 mod exp;
 mod fbm;
+#[cfg(feature = "profile")]
+mod instrument;
 mod lambda;
+mod lerp;
 mod max;
+mod max_all;
 mod min;
+mod min_all;
 mod mul;
 mod neg;
+mod overlay;
 mod pow;
 mod power;
 mod product;
+mod product_all;
+mod quotient;
 mod ridgedmulti;
 mod rotate;
 mod scale;
+mod scale_bias;
+mod screen;
 mod select;
+mod selector;
+mod shared;
+mod smooth_max;
+mod smooth_max_all;
+mod smooth_min;
+mod smooth_min_all;
 mod spline;
+mod subtraction;
 mod sum;
+mod sum_all;
+mod transform;
 mod translate;
+mod weighted_sum;
 pub use abs::Abs;
 pub use add::Add;
 pub use billow::Billow;
 pub use blend::Blend;
+#[cfg(feature = "std")]
+pub use cached::Cached;
 pub use clamp::Clamp;
+pub use difference::Difference;
 pub use displace::Displace;
 pub use exp::Exp;
 pub use fbm::Fbm;
+#[cfg(feature = "profile")]
+pub use instrument::Instrument;
 pub use lambda::Lambda;
+pub use lerp::Lerp;
 pub use max::Max;
+pub use max_all::MaxAll;
 pub use min::Min;
+pub use min_all::MinAll;
 pub use mul::Mul;
 pub use neg::Neg;
+pub use overlay::Overlay;
 pub use pow::Pow;
 pub use power::Power;
 pub use product::Product;
+pub use product_all::ProductAll;
+pub use quotient::Quotient;
 pub use ridgedmulti::RidgedMulti;
 pub use rotate::Rotate;
 pub use scale::Scale;
+pub use scale_bias::ScaleBias;
+pub use screen::Screen;
 pub use select::Select;
+pub use selector::Selector;
+pub use shared::Shared;
+pub use smooth_max::SmoothMax;
+pub use smooth_max_all::SmoothMaxAll;
+pub use smooth_min::SmoothMin;
+pub use smooth_min_all::SmoothMinAll;
+pub use subtraction::Subtraction;
 pub use sum::Sum;
+pub use sum_all::SumAll;
+pub use transform::Transform;
 pub use translate::Translate;
-pub use {spline::NaturalCubicSpline, spline::Spline, spline::SplineImpl};
+pub use weighted_sum::WeightedSum;
+pub use {
+    spline::CatmullRomSpline, spline::HermiteSpline, spline::LinearSpline,
+    spline::MonotoneCubicSpline, spline::NaturalCubicSpline, spline::Spline, spline::SplineError,
+    spline::SplineExtrapolation, spline::SplineImpl,
+};
@@ -1,4 +1,8 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::interval,
+};
 
 /// A generator multiplying `scale` to results of the underlying generator.
 ///
@@ -36,4 +40,16 @@ where
     fn sample(&self, point: [f64; D]) -> f64 {
         self.generator.sample(point) * self.scale
     }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        interval::scale(self.generator.output_range()?, self.scale)
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Mul")
+            .with_parameter("scale", self.scale)
+            .with_child(self.generator.describe())
+    }
 }
@@ -0,0 +1,82 @@
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::interval,
+};
+
+/// A generator combining results of the underlying generator and results of a given other
+/// generator using the overlay blend mode.
+///
+/// For details, see the documentation of [`overlay()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`overlay()`] implemented by [`Generator`], should be used
+/// to create [`Overlay`].
+///
+/// [`overlay()`]: Generator::overlay
+#[derive(Clone, Copy, Debug)]
+pub struct Overlay<const D: usize, GA, GB> {
+    generator_a: GA,
+    generator_b: GB,
+}
+
+impl<GA: Generator<1>, GB: Generator<1>> Generator1D for Overlay<1, GA, GB> {}
+impl<GA: Generator<2>, GB: Generator<2>> Generator2D for Overlay<2, GA, GB> {}
+impl<GA: Generator<3>, GB: Generator<3>> Generator3D for Overlay<3, GA, GB> {}
+impl<GA: Generator<4>, GB: Generator<4>> Generator4D for Overlay<4, GA, GB> {}
+
+impl<const D: usize, GA, GB> Overlay<D, GA, GB>
+where
+    GA: Generator<D>,
+    GB: Generator<D>,
+{
+    #[inline]
+    pub fn new(generator_a: GA, generator_b: GB) -> Self {
+        Self {
+            generator_a,
+            generator_b,
+        }
+    }
+}
+
+impl<const D: usize, GA, GB> Generator<D> for Overlay<D, GA, GB>
+where
+    GA: Generator<D>,
+    GB: Generator<D>,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        overlay(
+            self.generator_a.sample(point),
+            self.generator_b.sample(point),
+        )
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        let a = self.generator_a.output_range()?;
+        let b = self.generator_b.output_range()?;
+        // the blend mode is monotonically increasing in both arguments within [-1, 1]
+        if a.0 < -1.0 || a.1 > 1.0 || b.0 < -1.0 || b.1 > 1.0 {
+            return None;
+        }
+        interval::new(overlay(a.0, b.0), overlay(a.1, b.1))
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Overlay")
+            .with_labeled_child("a", self.generator_a.describe())
+            .with_labeled_child("b", self.generator_b.describe())
+    }
+}
+
+#[inline]
+fn overlay(a: f64, b: f64) -> f64 {
+    // map to the [0, 1] range used by image editors and back
+    let a = a * 0.5 + 0.5;
+    let b = b * 0.5 + 0.5;
+    let result = match a < 0.5 {
+        true => 2.0 * a * b,
+        false => 1.0 - 2.0 * (1.0 - a) * (1.0 - b),
+    };
+    result * 2.0 - 1.0
+}
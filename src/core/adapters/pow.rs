@@ -1,4 +1,10 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::interval,
+};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
 
 /// A generator raising results of the underlying generator to the power of `exponent`.
 ///
@@ -45,6 +51,20 @@ where
     fn sample(&self, point: [f64; D]) -> f64 {
         self.generator.sample(point).powi(self.exponent)
     }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        interval::pow(self.generator.output_range()?, self.exponent as f64, |x| {
+            x.powi(self.exponent)
+        })
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Pow")
+            .with_parameter("exponent", self.exponent)
+            .with_child(self.generator.describe())
+    }
 }
 
 impl<const D: usize, G: Generator<D>> Generator<D> for Pow<D, G, f64>
@@ -55,4 +75,18 @@ where
     fn sample(&self, point: [f64; D]) -> f64 {
         self.generator.sample(point).powf(self.exponent)
     }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        interval::pow(self.generator.output_range()?, self.exponent, |x| {
+            x.powf(self.exponent)
+        })
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Pow")
+            .with_parameter("exponent", self.exponent)
+            .with_child(self.generator.describe())
+    }
 }
@@ -1,4 +1,9 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
 
 /// A generator raising results of the underlying generator to the power of results of a
 /// given other generator.
@@ -44,4 +49,11 @@ where
             .sample(point)
             .powf(self.generator_b.sample(point))
     }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Power")
+            .with_labeled_child("base", self.generator_a.describe())
+            .with_labeled_child("exponent", self.generator_b.describe())
+    }
 }
@@ -0,0 +1,70 @@
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::interval,
+};
+use alloc::vec::Vec;
+
+/// A generator multiplying the results of all generators of a collection.
+///
+/// For details, see the documentation of [`product_all()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`product_all()`] implemented by [`Source`], should be used
+/// to create [`ProductAll`].
+///
+/// [`product_all()`]: crate::Source::product_all
+/// [`Source`]: crate::Source
+#[derive(Clone, Debug)]
+pub struct ProductAll<const D: usize, G> {
+    generators: Vec<G>,
+}
+
+impl<G: Generator<1>> Generator1D for ProductAll<1, G> {}
+impl<G: Generator<2>> Generator2D for ProductAll<2, G> {}
+impl<G: Generator<3>> Generator3D for ProductAll<3, G> {}
+impl<G: Generator<4>> Generator4D for ProductAll<4, G> {}
+
+impl<const D: usize, G> ProductAll<D, G>
+where
+    G: Generator<D>,
+{
+    #[inline]
+    pub fn new<I>(generators: I) -> Self
+    where
+        I: IntoIterator<Item = G>,
+    {
+        Self {
+            generators: generators.into_iter().collect(),
+        }
+    }
+}
+
+impl<const D: usize, G> Generator<D> for ProductAll<D, G>
+where
+    G: Generator<D>,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        self.generators
+            .iter()
+            .map(|generator| generator.sample(point))
+            .product()
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        self.generators
+            .iter()
+            .try_fold((1.0, 1.0), |range, generator| {
+                interval::mul(range, generator.output_range()?)
+            })
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        self.generators
+            .iter()
+            .fold(GraphNode::new("ProductAll"), |node, generator| {
+                node.with_child(generator.describe())
+            })
+    }
+}
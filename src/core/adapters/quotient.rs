@@ -0,0 +1,75 @@
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::interval,
+};
+
+/// A generator dividing results of the underlying generator by results of a given other
+/// generator.
+///
+/// Typically, this struct is not meant to be used directly. Instead, the `/` operator, which is
+/// implemented for all generators of this crate, should be used to create [`Quotient`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{Source, Generator};
+/// let point = [0.2, 0.5];
+///
+/// let generator = Source::simplex(42) / Source::simplex(43);
+///
+/// let value = generator.sample(point);
+///
+/// assert_eq!(value, Source::simplex(42).sample(point) / Source::simplex(43).sample(point))
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Quotient<const D: usize, GA, GB> {
+    generator_a: GA,
+    generator_b: GB,
+}
+
+impl<GA: Generator<1>, GB: Generator<1>> Generator1D for Quotient<1, GA, GB> {}
+impl<GA: Generator<2>, GB: Generator<2>> Generator2D for Quotient<2, GA, GB> {}
+impl<GA: Generator<3>, GB: Generator<3>> Generator3D for Quotient<3, GA, GB> {}
+impl<GA: Generator<4>, GB: Generator<4>> Generator4D for Quotient<4, GA, GB> {}
+
+impl<const D: usize, GA, GB> Quotient<D, GA, GB>
+where
+    GA: Generator<D>,
+    GB: Generator<D>,
+{
+    #[inline]
+    pub fn new(generator_a: GA, generator_b: GB) -> Self {
+        Self {
+            generator_a,
+            generator_b,
+        }
+    }
+}
+
+impl<const D: usize, GA, GB> Generator<D> for Quotient<D, GA, GB>
+where
+    GA: Generator<D>,
+    GB: Generator<D>,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        self.generator_a.sample(point) / self.generator_b.sample(point)
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        let a = self.generator_a.output_range()?;
+        let b = self.generator_b.output_range()?;
+        interval::div(a, b)
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Quotient")
+            .with_labeled_child("a", self.generator_a.describe())
+            .with_labeled_child("b", self.generator_b.describe())
+    }
+}
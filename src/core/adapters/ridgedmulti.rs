@@ -1,4 +1,9 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    error::{NoiseError, validate_octaves},
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::interval,
+};
 
 /// Create a generator applying an `fbm()`-like effect on the underlying generator.
 ///
@@ -45,6 +50,24 @@ where
             normalization_factor,
         }
     }
+
+    #[inline]
+    pub fn try_new(
+        generator: G,
+        octaves: u32,
+        frequency: f64,
+        lacunarity: f64,
+        attenuation: f64,
+    ) -> Result<Self, NoiseError> {
+        validate_octaves(octaves, lacunarity)?;
+        Ok(Self::new(
+            generator,
+            octaves,
+            frequency,
+            lacunarity,
+            attenuation,
+        ))
+    }
 }
 
 macro_rules! impl_generator {
@@ -62,7 +85,34 @@ macro_rules! impl_generator {
                     freq *= self.lacunarity;
                     amp = (layer / self.attenuation).clamp(0.0, 1.0);
                 }
-                (noise * self.normalization_factor).mul_add(2.0, -1.0)
+                noise * self.normalization_factor * 2.0 - 1.0
+            }
+
+            fn output_range(&self) -> Option<(f64, f64)> {
+                let (min, max) = interval::abs(self.generator.output_range()?);
+                let layer = interval::pow((1.0 - max, 1.0 - min), 2.0, |x| x * x)?;
+                // the amplitude of all but the first octave is the previous octave divided by the
+                // attenuation, restricted to [0, 1]
+                let mut octave = layer;
+                let mut noise = layer;
+                for _ in 1..self.octaves {
+                    let amp = interval::scale(octave, 1.0 / self.attenuation)
+                        .map(|(min, max)| (min.clamp(0.0, 1.0), max.clamp(0.0, 1.0)))
+                        .unwrap_or((0.0, 1.0));
+                    octave = interval::mul(layer, amp)?;
+                    noise = interval::add(noise, octave)?;
+                }
+                let (min, max) = interval::scale(noise, self.normalization_factor)?;
+                interval::new(min * 2.0 - 1.0, max * 2.0 - 1.0)
+            }
+
+            fn describe(&self) -> GraphNode {
+                GraphNode::new("RidgedMulti")
+                    .with_parameter("octaves", self.octaves)
+                    .with_parameter("frequency", self.frequency)
+                    .with_parameter("lacunarity", self.lacunarity)
+                    .with_parameter("attenuation", self.attenuation)
+                    .with_child(self.generator.describe())
             }
         }
     };
@@ -75,7 +125,8 @@ impl_generator!(4);
 
 #[inline]
 fn compute_normalization_factor(octaves: u32, attenuation: f64) -> f64 {
-    1.0 / (0..octaves).fold(0.0, |acc, octave| {
-        acc + (1.0 / attenuation).powi(octave as i32)
-    })
+    let (sum, _) = (0..octaves).fold((0.0, 1.0), |(sum, amp), _| {
+        (sum + amp, amp * (1.0 / attenuation))
+    });
+    1.0 / sum
 }
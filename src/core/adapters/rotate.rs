@@ -1,16 +1,38 @@
-use crate::core::generator::{Generator, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::mat_vec_mul,
+};
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+use sealed::RotationAngles;
 
 /// A generator which rotates input points before passing them to the underlying generator.
 ///
 /// For details, see the documentation of [`rotate()`]. Typically, this struct is not meant
-/// to be used directly. Instead, [`rotate()`] implemented by [`Rotate`], should be used
-/// to create [`Rotate`].
+/// to be used directly. Instead, [`rotate()`] implemented by [`Generator2D`], [`Generator3D`] and
+/// [`Generator4D`], should be used to create [`Rotate`]. To create a rotation from a matrix, or in
+/// 3 dimensions from an axis and an angle or a viewing direction, use the constructors of this
+/// struct instead.
+///
+/// The rotation matrix is computed once when creating the generator, so sampling a rotated
+/// generator costs a single matrix multiplication.
+///
+/// # Direct usage of this struct
+///
+/// Rotating around an arbitrary axis:
+///
+/// ```
+/// # use libnoise::{Rotate, Source, Generator};
+/// let generator = Rotate::from_axis_angle(Source::simplex(42), [1.0, 1.0, 0.0], 0.4);
+/// let value = generator.sample([0.2, 0.5, 0.3]);
+/// ```
 ///
 /// [`rotate()`]: Generator2D::rotate
 #[derive(Clone, Copy, Debug)]
 pub struct Rotate<const D: usize, const P: usize, G> {
     generator: G,
-    rotation: [f64; P],
+    matrix: [[f64; D]; D],
 }
 
 impl<G: Generator<2>> Generator2D for Rotate<2, 1, G> {}
@@ -21,114 +43,243 @@ impl<const D: usize, const P: usize, G> Rotate<D, P, G>
 where
     G: Generator<D>,
 {
+    /// Create a new rotate generator from the angles of rotation in radians. For the meaning of
+    /// the angles, see the `rotate()` function of [`Generator2D`], [`Generator3D`] or
+    /// [`Generator4D`], depending on the dimensionality.
     #[inline]
-    pub fn new(generator: G, rotation: [f64; P]) -> Self {
+    pub fn new(generator: G, rotation: [f64; P]) -> Self
+    where
+        [f64; P]: RotationAngles<D>,
+    {
         Self {
             generator,
-            rotation,
+            matrix: rotation.rotation_matrix(),
         }
     }
-}
-
-impl<G: Generator<2>> Generator<2> for Rotate<2, 1, G> {
-    fn sample(&self, point: [f64; 2]) -> f64 {
-        let x = point[0];
-        let y = point[1];
 
-        let sin_theta = self.rotation[0].sin();
-        let cos_theta = self.rotation[0].cos();
+    /// Create a new rotate generator from a rotation matrix in row-major order. The input point is
+    /// multiplied by the matrix before being passed to the underlying generator.
+    ///
+    /// The matrix is expected to be orthonormal. For arbitrary linear maps, use [`transform()`]
+    /// instead. As the number of rotation angles `P` cannot be inferred from the matrix, it may need
+    /// to be specified explicitly:
+    ///
+    /// ```
+    /// # use libnoise::{Rotate, Source, Generator};
+    /// // swap the x- and y-axis and mirror the y-axis
+    /// let matrix = [[0.0, 1.0], [-1.0, 0.0]];
+    /// let generator = Rotate::<2, 1, _>::from_matrix(Source::simplex(42), matrix);
+    ///
+    /// assert_eq!(generator.sample([0.2, 0.5]), Source::simplex(42).sample([0.5, -0.2]));
+    /// ```
+    ///
+    /// [`transform()`]: Generator::transform
+    #[inline]
+    pub fn from_matrix(generator: G, matrix: [[f64; D]; D]) -> Self {
+        Self { generator, matrix }
+    }
+}
 
-        let xr = x * cos_theta - y * sin_theta;
-        let yr = x * sin_theta + y * cos_theta;
+impl<G: Generator<3>> Rotate<3, 3, G> {
+    /// Create a new rotate generator which rotates input points counterclockwise around `axis` by
+    /// `angle` in radians, following the right-hand rule. The axis needs not be of unit length.
+    ///
+    /// <p style="background:rgba(255,181,77,0.16);padding:0.75em;">
+    /// <strong>Warning:</strong>
+    /// An axis of length zero does not define a rotation. The generator created by this
+    /// constructor will produce NaN, if this contract is violated.
+    /// </p>
+    #[inline]
+    pub fn from_axis_angle(generator: G, axis: [f64; 3], angle: f64) -> Self {
+        let [x, y, z] = normalize(axis);
+        let (sin_theta, cos_theta) = angle.sin_cos();
+        let t = 1.0 - cos_theta;
+        let matrix = [
+            [
+                t * x * x + cos_theta,
+                t * x * y - sin_theta * z,
+                t * x * z + sin_theta * y,
+            ],
+            [
+                t * x * y + sin_theta * z,
+                t * y * y + cos_theta,
+                t * y * z - sin_theta * x,
+            ],
+            [
+                t * x * z - sin_theta * y,
+                t * y * z + sin_theta * x,
+                t * z * z + cos_theta,
+            ],
+        ];
+        Self { generator, matrix }
+    }
 
-        self.generator.sample([xr, yr])
+    /// Create a new rotate generator which aligns the z-axis of the underlying generator with
+    /// `direction`, and its y-axis with the component of `up` perpendicular to `direction`.
+    ///
+    /// That is, an input point lying on `direction` is rotated onto the positive z-axis before being
+    /// passed to the underlying generator. This is useful for orienting anisotropic noise, such as
+    /// noise stretched along one axis, along a given direction. Neither `direction` nor `up` need to
+    /// be of unit length.
+    ///
+    /// <p style="background:rgba(255,181,77,0.16);padding:0.75em;">
+    /// <strong>Warning:</strong>
+    /// If `direction` is of length zero or parallel to `up`, the orientation is undefined. The
+    /// generator created by this constructor will produce NaN, if this contract is violated.
+    /// </p>
+    #[inline]
+    pub fn look_at(generator: G, direction: [f64; 3], up: [f64; 3]) -> Self {
+        let forward = normalize(direction);
+        let right = normalize(cross(up, forward));
+        let up = cross(forward, right);
+        let matrix = [right, up, forward];
+        Self { generator, matrix }
     }
 }
 
-impl<G: Generator<3>> Generator<3> for Rotate<3, 3, G> {
-    fn sample(&self, point: [f64; 3]) -> f64 {
-        let x = point[0];
-        let y = point[1];
-        let z = point[2];
+macro_rules! impl_rotate {
+    ($dim:literal, $angles:literal, $rotation_matrix:ident) => {
+        impl RotationAngles<$dim> for [f64; $angles] {
+            #[inline]
+            fn rotation_matrix(self) -> [[f64; $dim]; $dim] {
+                $rotation_matrix(self)
+            }
+        }
 
-        let sin_a = self.rotation[0].sin();
-        let cos_a = self.rotation[0].cos();
+        impl<G: Generator<$dim>> Generator<$dim> for Rotate<$dim, $angles, G> {
+            #[inline]
+            fn sample(&self, point: [f64; $dim]) -> f64 {
+                self.generator.sample(mat_vec_mul(&self.matrix, point))
+            }
 
-        let sin_b = self.rotation[1].sin();
-        let cos_b = self.rotation[1].cos();
+            #[inline]
+            fn output_range(&self) -> Option<(f64, f64)> {
+                self.generator.output_range()
+            }
 
-        let sin_g = self.rotation[2].sin();
-        let cos_g = self.rotation[2].cos();
+            #[inline]
+            fn describe(&self) -> GraphNode {
+                GraphNode::new("Rotate")
+                    .with_parameter("matrix", self.matrix)
+                    .with_child(self.generator.describe())
+            }
+        }
+    };
+}
 
-        let xr = cos_b * (x * cos_g + y * sin_g) + z * (-sin_b);
-        let yr =
-            sin_a * (sin_b * (x * cos_g + y * sin_g) + z * cos_b) - cos_a * (x * sin_g - y * cos_g);
-        let zr =
-            cos_a * (sin_b * (x * cos_g + y * sin_g) + z * cos_b) + sin_a * (x * sin_g - y * cos_g);
+impl_rotate!(2, 1, rotation_matrix_2d);
+impl_rotate!(3, 3, rotation_matrix_3d);
+impl_rotate!(4, 6, rotation_matrix_4d);
 
-        self.generator.sample([xr, yr, zr])
+mod sealed {
+    /// Angles of rotation in radians which define a rotation in `D` dimensions. This trait is
+    /// implemented only for the supported combinations of dimensionality and number of angles.
+    pub trait RotationAngles<const D: usize> {
+        fn rotation_matrix(self) -> [[f64; D]; D];
     }
 }
 
-impl<G: Generator<4>> Generator<4> for Rotate<4, 6, G> {
-    fn sample(&self, point: [f64; 4]) -> f64 {
-        let x = point[0];
-        let y = point[1];
-        let z = point[2];
-        let w = point[3];
-        let sin_alpha = self.rotation[0].sin();
-        let cos_alpha = self.rotation[0].cos();
-        let sin_beta = self.rotation[1].sin();
-        let cos_beta = self.rotation[1].cos();
-        let sin_gamma = self.rotation[2].sin();
-        let cos_gamma = self.rotation[2].cos();
-        let sin_delta = self.rotation[3].sin();
-        let cos_delta = self.rotation[3].cos();
-        let sin_epsilon = self.rotation[4].sin();
-        let cos_epsilon = self.rotation[4].cos();
-        let sin_digamma = self.rotation[5].sin();
-        let cos_digamma = self.rotation[5].cos();
-
-        let xr = cos_alpha
-            * (x * cos_beta * cos_gamma
-                + sin_beta
-                    * (sin_delta
-                        * (sin_epsilon * (z * sin_digamma + w * cos_digamma) - y * cos_epsilon)
-                        + cos_delta * (-z * cos_digamma + w * sin_digamma))
-                + sin_gamma
-                    * (cos_beta
-                        * (cos_epsilon * (-z * sin_digamma - w * cos_digamma) - y * sin_epsilon)))
-            + sin_alpha
-                * (cos_delta
+fn rotation_matrix_2d([theta]: [f64; 1]) -> [[f64; 2]; 2] {
+    let (sin_theta, cos_theta) = theta.sin_cos();
+    [[cos_theta, -sin_theta], [sin_theta, cos_theta]]
+}
+
+fn rotation_matrix_3d([alpha, beta, gamma]: [f64; 3]) -> [[f64; 3]; 3] {
+    let (sin_a, cos_a) = alpha.sin_cos();
+    let (sin_b, cos_b) = beta.sin_cos();
+    let (sin_g, cos_g) = gamma.sin_cos();
+    [
+        [cos_b * cos_g, cos_b * sin_g, -sin_b],
+        [
+            sin_a * sin_b * cos_g - cos_a * sin_g,
+            sin_a * sin_b * sin_g + cos_a * cos_g,
+            sin_a * cos_b,
+        ],
+        [
+            cos_a * sin_b * cos_g + sin_a * sin_g,
+            cos_a * sin_b * sin_g - sin_a * cos_g,
+            cos_a * cos_b,
+        ],
+    ]
+}
+
+fn rotation_matrix_4d(rotation: [f64; 6]) -> [[f64; 4]; 4] {
+    // the columns of the matrix are the images of the basis vectors
+    let columns: [[f64; 4]; 4] = core::array::from_fn(|j| {
+        rotate_4d(
+            rotation,
+            core::array::from_fn(|i| if i == j { 1.0 } else { 0.0 }),
+        )
+    });
+    core::array::from_fn(|i| core::array::from_fn(|j| columns[j][i]))
+}
+
+fn rotate_4d(rotation: [f64; 6], point: [f64; 4]) -> [f64; 4] {
+    let x = point[0];
+    let y = point[1];
+    let z = point[2];
+    let w = point[3];
+    let sin_alpha = rotation[0].sin();
+    let cos_alpha = rotation[0].cos();
+    let sin_beta = rotation[1].sin();
+    let cos_beta = rotation[1].cos();
+    let sin_gamma = rotation[2].sin();
+    let cos_gamma = rotation[2].cos();
+    let sin_delta = rotation[3].sin();
+    let cos_delta = rotation[3].cos();
+    let sin_epsilon = rotation[4].sin();
+    let cos_epsilon = rotation[4].cos();
+    let sin_digamma = rotation[5].sin();
+    let cos_digamma = rotation[5].cos();
+
+    let xr = cos_alpha
+        * (x * cos_beta * cos_gamma
+            + sin_beta
+                * (sin_delta
                     * (sin_epsilon * (z * sin_digamma + w * cos_digamma) - y * cos_epsilon)
-                    + sin_delta * (z * cos_digamma - w * sin_digamma));
-
-        let yr = sin_alpha
-            * (cos_beta
-                * (sin_gamma
-                    * (cos_epsilon * (-z * sin_digamma - w * cos_digamma) - y * sin_epsilon)
-                    + x * cos_gamma)
-                + sin_beta
-                    * (sin_delta
-                        * (sin_epsilon * (z * sin_digamma + w * cos_digamma) - y * cos_epsilon)
-                        + cos_delta * (-z * cos_digamma + w * sin_digamma)))
-            + cos_alpha
-                * (cos_delta
-                    * (sin_epsilon * (-z * sin_digamma - w * cos_digamma) + y * cos_epsilon)
-                    + sin_delta * (-z * cos_digamma + w * sin_digamma));
-
-        let zr = cos_beta
-            * (sin_epsilon * (sin_delta * (-z * sin_digamma - w * cos_digamma))
-                + cos_delta * (z * cos_digamma - w * sin_digamma)
-                + y * sin_gamma * cos_epsilon)
+                    + cos_delta * (-z * cos_digamma + w * sin_digamma))
+            + sin_gamma
+                * (cos_beta
+                    * (cos_epsilon * (-z * sin_digamma - w * cos_digamma) - y * sin_epsilon)))
+        + sin_alpha
+            * (cos_delta * (sin_epsilon * (z * sin_digamma + w * cos_digamma) - y * cos_epsilon)
+                + sin_delta * (z * cos_digamma - w * sin_digamma));
+
+    let yr = sin_alpha
+        * (cos_beta
+            * (sin_gamma * (cos_epsilon * (-z * sin_digamma - w * cos_digamma) - y * sin_epsilon)
+                + x * cos_gamma)
             + sin_beta
-                * (sin_gamma
-                    * (sin_epsilon * (-y - z * sin_digamma) - w * cos_digamma * cos_epsilon)
-                    + x * cos_gamma);
+                * (sin_delta
+                    * (sin_epsilon * (z * sin_digamma + w * cos_digamma) - y * cos_epsilon)
+                    + cos_delta * (-z * cos_digamma + w * sin_digamma)))
+        + cos_alpha
+            * (cos_delta * (sin_epsilon * (-z * sin_digamma - w * cos_digamma) + y * cos_epsilon)
+                + sin_delta * (-z * cos_digamma + w * sin_digamma));
 
-        let wr = cos_gamma * (cos_epsilon * (z * sin_digamma + w * cos_digamma) + y * sin_epsilon)
-            + x * sin_gamma;
+    let zr = cos_beta
+        * (sin_epsilon * (sin_delta * (-z * sin_digamma - w * cos_digamma))
+            + cos_delta * (z * cos_digamma - w * sin_digamma)
+            + y * sin_gamma * cos_epsilon)
+        + sin_beta
+            * (sin_gamma * (sin_epsilon * (-y - z * sin_digamma) - w * cos_digamma * cos_epsilon)
+                + x * cos_gamma);
 
-        self.generator.sample([xr, yr, zr, wr])
-    }
+    let wr = cos_gamma * (cos_epsilon * (z * sin_digamma + w * cos_digamma) + y * sin_epsilon)
+        + x * sin_gamma;
+
+    [xr, yr, zr, wr]
+}
+
+fn normalize([x, y, z]: [f64; 3]) -> [f64; 3] {
+    let length = (x * x + y * y + z * z).sqrt();
+    [x / length, y / length, z / length]
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
 }
@@ -1,4 +1,7 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+};
 
 /// A generator which scales input points before passing them to the underlying generator.
 ///
@@ -35,6 +38,18 @@ where
     #[inline]
     fn sample(&self, point: [f64; D]) -> f64 {
         self.generator
-            .sample(std::array::from_fn(|i| point[i] * self.scale[i]))
+            .sample(core::array::from_fn(|i| point[i] * self.scale[i]))
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        self.generator.output_range()
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Scale")
+            .with_parameter("scale", self.scale)
+            .with_child(self.generator.describe())
     }
 }
@@ -0,0 +1,133 @@
+use crate::core::{
+    error::{NoiseError, validate_estimated_range, validate_output_range},
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::{math::interval, ptable::Seed, stats},
+};
+
+/// A generator multiplying `scale` to results of the underlying generator and adding `bias`
+/// afterwards.
+///
+/// For details, see the documentation of [`scale_bias()`], [`remap()`], [`normalize()`], and
+/// [`normalize_estimated()`].
+/// Typically, this struct is not meant to be used directly. Instead, any of these methods
+/// implemented by [`Generator`] should be used to create [`ScaleBias`].
+///
+/// [`scale_bias()`]: Generator::scale_bias
+/// [`remap()`]: Generator::remap
+/// [`normalize()`]: Generator::normalize
+/// [`normalize_estimated()`]: Generator::normalize_estimated
+#[derive(Clone, Copy, Debug)]
+pub struct ScaleBias<const D: usize, G> {
+    generator: G,
+    scale: f64,
+    bias: f64,
+}
+
+impl<G: Generator<1>> Generator1D for ScaleBias<1, G> {}
+impl<G: Generator<2>> Generator2D for ScaleBias<2, G> {}
+impl<G: Generator<3>> Generator3D for ScaleBias<3, G> {}
+impl<G: Generator<4>> Generator4D for ScaleBias<4, G> {}
+
+impl<const D: usize, G> ScaleBias<D, G>
+where
+    G: Generator<D>,
+{
+    #[inline]
+    pub fn new(generator: G, scale: f64, bias: f64) -> Self {
+        Self {
+            generator,
+            scale,
+            bias,
+        }
+    }
+
+    #[inline]
+    pub fn remap(generator: G, from_min: f64, from_max: f64, to_min: f64, to_max: f64) -> Self {
+        // a degenerate source interval is mapped to the center of the target interval
+        if from_min == from_max {
+            return Self::new(generator, 0.0, (to_min + to_max) / 2.0);
+        }
+        let scale = (to_max - to_min) / (from_max - from_min);
+        Self::new(generator, scale, to_min - from_min * scale)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the output range of `generator` is unknown or not finite. See [`try_normalize()`]
+    /// for a fallible alternative.
+    ///
+    /// [`try_normalize()`]: ScaleBias::try_normalize
+    #[inline]
+    pub fn normalize(generator: G, to_min: f64, to_max: f64) -> Self {
+        match Self::try_normalize(generator, to_min, to_max) {
+            Ok(scale_bias) => scale_bias,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    #[inline]
+    pub fn try_normalize(generator: G, to_min: f64, to_max: f64) -> Result<Self, NoiseError> {
+        let (from_min, from_max) = validate_output_range(generator.output_range())?;
+        Ok(Self::remap(generator, from_min, from_max, to_min, to_max))
+    }
+
+    /// # Panics
+    ///
+    /// Panics if no sample is other than NaN, such as if `samples` is zero, or if a sample is
+    /// infinite. See [`try_normalize_estimated()`] for a fallible alternative.
+    ///
+    /// [`try_normalize_estimated()`]: ScaleBias::try_normalize_estimated
+    #[inline]
+    pub fn normalize_estimated(
+        generator: G,
+        region: [(f64, f64); D],
+        samples: usize,
+        seed: impl Seed,
+        to_min: f64,
+        to_max: f64,
+    ) -> Self {
+        match Self::try_normalize_estimated(generator, region, samples, seed, to_min, to_max) {
+            Ok(scale_bias) => scale_bias,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    #[inline]
+    pub fn try_normalize_estimated(
+        generator: G,
+        region: [(f64, f64); D],
+        samples: usize,
+        seed: impl Seed,
+        to_min: f64,
+        to_max: f64,
+    ) -> Result<Self, NoiseError> {
+        let stats = stats::estimate(&generator, region, samples, seed);
+        let (from_min, from_max) = validate_estimated_range(&stats)?;
+        Ok(Self::remap(generator, from_min, from_max, to_min, to_max))
+    }
+}
+
+impl<const D: usize, G> Generator<D> for ScaleBias<D, G>
+where
+    G: Generator<D>,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        self.generator.sample(point) * self.scale + self.bias
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        let (min, max) = interval::scale(self.generator.output_range()?, self.scale)?;
+        interval::new(min + self.bias, max + self.bias)
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("ScaleBias")
+            .with_parameter("scale", self.scale)
+            .with_parameter("bias", self.bias)
+            .with_child(self.generator.describe())
+    }
+}
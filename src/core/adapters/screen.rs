@@ -0,0 +1,79 @@
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::interval,
+};
+
+/// A generator combining results of the underlying generator and results of a given other
+/// generator using the screen blend mode.
+///
+/// For details, see the documentation of [`screen()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`screen()`] implemented by [`Generator`], should be used
+/// to create [`Screen`].
+///
+/// [`screen()`]: Generator::screen
+#[derive(Clone, Copy, Debug)]
+pub struct Screen<const D: usize, GA, GB> {
+    generator_a: GA,
+    generator_b: GB,
+}
+
+impl<GA: Generator<1>, GB: Generator<1>> Generator1D for Screen<1, GA, GB> {}
+impl<GA: Generator<2>, GB: Generator<2>> Generator2D for Screen<2, GA, GB> {}
+impl<GA: Generator<3>, GB: Generator<3>> Generator3D for Screen<3, GA, GB> {}
+impl<GA: Generator<4>, GB: Generator<4>> Generator4D for Screen<4, GA, GB> {}
+
+impl<const D: usize, GA, GB> Screen<D, GA, GB>
+where
+    GA: Generator<D>,
+    GB: Generator<D>,
+{
+    #[inline]
+    pub fn new(generator_a: GA, generator_b: GB) -> Self {
+        Self {
+            generator_a,
+            generator_b,
+        }
+    }
+}
+
+impl<const D: usize, GA, GB> Generator<D> for Screen<D, GA, GB>
+where
+    GA: Generator<D>,
+    GB: Generator<D>,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        screen(
+            self.generator_a.sample(point),
+            self.generator_b.sample(point),
+        )
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        let a = self.generator_a.output_range()?;
+        let b = self.generator_b.output_range()?;
+        // the blend mode is monotonically increasing in both arguments within [-1, 1]
+        if a.0 < -1.0 || a.1 > 1.0 || b.0 < -1.0 || b.1 > 1.0 {
+            return None;
+        }
+        interval::new(screen(a.0, b.0), screen(a.1, b.1))
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Screen")
+            .with_labeled_child("a", self.generator_a.describe())
+            .with_labeled_child("b", self.generator_b.describe())
+    }
+}
+
+#[inline]
+fn screen(a: f64, b: f64) -> f64 {
+    // map to the [0, 1] range used by image editors and back
+    let a = a * 0.5 + 0.5;
+    let b = b * 0.5 + 0.5;
+    let result = 1.0 - (1.0 - a) * (1.0 - b);
+    result * 2.0 - 1.0
+}
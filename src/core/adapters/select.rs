@@ -1,4 +1,9 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    error::{NoiseError, validate_edge_falloff, validate_selection},
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::{interval, lerp, smoothstep_3},
+};
 
 /// Create a generator selecting the result of either the underlying generator or that of a given
 /// other generator based on whether the value supplied by a control-generator lies within the
@@ -16,6 +21,7 @@ pub struct Select<const D: usize, GA, GB, GC> {
     generator_control: GC,
     selection_min: f64,
     selection_max: f64,
+    edge_falloff: f64,
 }
 
 impl<GA: Generator<1>, GB: Generator<1>, GC: Generator<1>> Generator1D for Select<1, GA, GB, GC> {}
@@ -43,8 +49,53 @@ where
             generator_control,
             selection_min,
             selection_max,
+            edge_falloff: 0.0,
         }
     }
+
+    #[inline]
+    pub fn try_new(
+        generator_a: GA,
+        generator_b: GB,
+        generator_control: GC,
+        selection_min: f64,
+        selection_max: f64,
+    ) -> Result<Self, NoiseError> {
+        validate_selection(selection_min, selection_max)?;
+        Ok(Self::new(
+            generator_a,
+            generator_b,
+            generator_control,
+            selection_min,
+            selection_max,
+        ))
+    }
+
+    /// Set the half-width of the band around each bound of the selection interval in which the
+    /// results of both generators are smoothly blended. The falloff is restricted to the range
+    /// from 0 to half the width of the selection interval. By default, the falloff is 0. A NaN
+    /// falloff is treated as 0, see [`try_with_edge_falloff()`] for rejecting it instead.
+    ///
+    /// [`try_with_edge_falloff()`]: Select::try_with_edge_falloff
+    #[inline]
+    pub fn with_edge_falloff(mut self, edge_falloff: f64) -> Self {
+        if edge_falloff.is_nan() {
+            self.edge_falloff = 0.0;
+            return self;
+        }
+        let half_width = (self.selection_max - self.selection_min) / 2.0;
+        self.edge_falloff = edge_falloff.min(half_width).max(0.0);
+        self
+    }
+
+    /// Set the edge falloff like [`with_edge_falloff()`], but return an error if it is NaN.
+    ///
+    /// [`with_edge_falloff()`]: Select::with_edge_falloff
+    #[inline]
+    pub fn try_with_edge_falloff(self, edge_falloff: f64) -> Result<Self, NoiseError> {
+        validate_edge_falloff(edge_falloff)?;
+        Ok(self.with_edge_falloff(edge_falloff))
+    }
 }
 
 impl<const D: usize, GA, GB, GC> Generator<D> for Select<D, GA, GB, GC>
@@ -55,11 +106,56 @@ where
 {
     #[inline]
     fn sample(&self, point: [f64; D]) -> f64 {
-        match self.generator_control.sample(point) {
-            t if self.selection_min <= t && t <= self.selection_max => {
-                self.generator_a.sample(point)
+        let t = self.generator_control.sample(point);
+        if self.edge_falloff == 0.0 {
+            return match t {
+                t if self.selection_min <= t && t <= self.selection_max => {
+                    self.generator_a.sample(point)
+                }
+                _ => self.generator_b.sample(point),
+            };
+        }
+        let lower = self.selection_min;
+        let upper = self.selection_max;
+        let falloff = self.edge_falloff;
+        match t {
+            t if lower + falloff <= t && t <= upper - falloff => self.generator_a.sample(point),
+            t if lower - falloff < t && t < lower + falloff => {
+                let alpha = smoothstep_3((t - (lower - falloff)) / (2.0 * falloff));
+                lerp(
+                    self.generator_b.sample(point),
+                    self.generator_a.sample(point),
+                    alpha,
+                )
+            }
+            t if upper - falloff < t && t < upper + falloff => {
+                let alpha = smoothstep_3((t - (upper - falloff)) / (2.0 * falloff));
+                lerp(
+                    self.generator_a.sample(point),
+                    self.generator_b.sample(point),
+                    alpha,
+                )
             }
             _ => self.generator_b.sample(point),
         }
     }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        let a = self.generator_a.output_range()?;
+        let b = self.generator_b.output_range()?;
+        // crossfading within the edge falloff never leaves the range of both generators
+        Some(interval::union(a, b))
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Select")
+            .with_parameter("selection_min", self.selection_min)
+            .with_parameter("selection_max", self.selection_max)
+            .with_parameter("edge_falloff", self.edge_falloff)
+            .with_labeled_child("a", self.generator_a.describe())
+            .with_labeled_child("b", self.generator_b.describe())
+            .with_labeled_child("control", self.generator_control.describe())
+    }
 }
@@ -0,0 +1,163 @@
+use crate::core::{
+    error::{NoiseError, validate_edge_falloff, validate_thresholds},
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::{interval, lerp, smoothstep_3},
+};
+use alloc::{format, vec::Vec};
+
+/// A generator selecting the result of one of several generators based on the band in which the
+/// value supplied by a control-generator lies.
+///
+/// For details, see the documentation of [`selector()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`selector()`] implemented by [`Generator`], should be used
+/// to create [`Selector`].
+///
+/// [`selector()`]: Generator::selector
+#[derive(Clone, Debug)]
+pub struct Selector<const D: usize, GC, G> {
+    generator_control: GC,
+    thresholds: Vec<f64>,
+    generators: Vec<G>,
+    edge_falloff: f64,
+}
+
+impl<GC: Generator<1>, G: Generator<1>> Generator1D for Selector<1, GC, G> {}
+impl<GC: Generator<2>, G: Generator<2>> Generator2D for Selector<2, GC, G> {}
+impl<GC: Generator<3>, G: Generator<3>> Generator3D for Selector<3, GC, G> {}
+impl<GC: Generator<4>, G: Generator<4>> Generator4D for Selector<4, GC, G> {}
+
+impl<const D: usize, GC, G> Selector<D, GC, G>
+where
+    GC: Generator<D>,
+    G: Generator<D>,
+{
+    /// # Panics
+    ///
+    /// Panics if `bands` is empty or contains a NaN threshold. See [`try_new()`] for a fallible
+    /// alternative.
+    ///
+    /// [`try_new()`]: Selector::try_new
+    #[inline]
+    pub fn new<I>(generator_control: GC, bands: I) -> Self
+    where
+        I: IntoIterator<Item = (f64, G)>,
+    {
+        match Self::try_new(generator_control, bands) {
+            Ok(selector) => selector,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    #[inline]
+    pub fn try_new<I>(generator_control: GC, bands: I) -> Result<Self, NoiseError>
+    where
+        I: IntoIterator<Item = (f64, G)>,
+    {
+        let mut bands = bands.into_iter().collect::<Vec<(f64, G)>>();
+        bands.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+        let (thresholds, generators): (Vec<f64>, Vec<G>) = bands.into_iter().unzip();
+        validate_thresholds(&thresholds)?;
+        Ok(Self {
+            generator_control,
+            thresholds,
+            generators,
+            edge_falloff: 0.0,
+        })
+    }
+
+    /// Set the half-width of the band around each threshold in which the results of neighboring
+    /// generators are smoothly blended. The falloff is restricted to the range from 0 to half the
+    /// width of the narrowest band. By default, the falloff is 0. A NaN falloff is treated as 0,
+    /// see [`try_with_edge_falloff()`] for rejecting it instead.
+    ///
+    /// [`try_with_edge_falloff()`]: Selector::try_with_edge_falloff
+    #[inline]
+    pub fn with_edge_falloff(mut self, edge_falloff: f64) -> Self {
+        if edge_falloff.is_nan() {
+            self.edge_falloff = 0.0;
+            return self;
+        }
+        let half_width = self
+            .thresholds
+            .windows(2)
+            .map(|w| (w[1] - w[0]) / 2.0)
+            .fold(f64::INFINITY, f64::min);
+        self.edge_falloff = edge_falloff.min(half_width).max(0.0);
+        self
+    }
+
+    /// Set the edge falloff like [`with_edge_falloff()`], but return an error if it is NaN.
+    ///
+    /// [`with_edge_falloff()`]: Selector::with_edge_falloff
+    #[inline]
+    pub fn try_with_edge_falloff(self, edge_falloff: f64) -> Result<Self, NoiseError> {
+        validate_edge_falloff(edge_falloff)?;
+        Ok(self.with_edge_falloff(edge_falloff))
+    }
+}
+
+impl<const D: usize, GC, G> Generator<D> for Selector<D, GC, G>
+where
+    GC: Generator<D>,
+    G: Generator<D>,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        let t = self.generator_control.sample(point);
+        // index of the band containing the control value, where values below the first threshold
+        // belong to the first band
+        let idx = self
+            .thresholds
+            .partition_point(|&threshold| threshold <= t)
+            .saturating_sub(1);
+        if self.edge_falloff > 0.0 {
+            let falloff = self.edge_falloff;
+            // blend with the next band when approaching its threshold
+            if let Some(&upper) = self.thresholds.get(idx + 1)
+                && t > upper - falloff
+            {
+                let alpha = smoothstep_3((t - (upper - falloff)) / (2.0 * falloff));
+                return lerp(
+                    self.generators[idx].sample(point),
+                    self.generators[idx + 1].sample(point),
+                    alpha,
+                );
+            }
+            // blend with the previous band shortly after passing the own threshold
+            let lower = self.thresholds[idx];
+            if idx > 0 && t < lower + falloff {
+                let alpha = smoothstep_3((t - (lower - falloff)) / (2.0 * falloff));
+                return lerp(
+                    self.generators[idx - 1].sample(point),
+                    self.generators[idx].sample(point),
+                    alpha,
+                );
+            }
+        }
+        self.generators[idx].sample(point)
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        let mut generators = self.generators.iter();
+        let first = generators.next()?.output_range()?;
+        // blending between neighboring bands never leaves the range of both generators
+        generators.try_fold(first, |range, generator| {
+            Some(interval::union(range, generator.output_range()?))
+        })
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        let node = GraphNode::new("Selector")
+            .with_parameter("edge_falloff", self.edge_falloff)
+            .with_labeled_child("control", self.generator_control.describe());
+        self.thresholds
+            .iter()
+            .zip(&self.generators)
+            .fold(node, |node, (threshold, generator)| {
+                node.with_labeled_child(format!("from {threshold:?}"), generator.describe())
+            })
+    }
+}
@@ -0,0 +1,69 @@
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+};
+use alloc::sync::Arc;
+
+/// A reference-counted handle to a generator, which allows using the same generator in several
+/// places of a pipeline.
+///
+/// For details, see the documentation of [`shared()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`shared()`] implemented by [`Generator`], should be used
+/// to create [`Shared`].
+///
+/// [`shared()`]: Generator::shared
+#[derive(Debug)]
+pub struct Shared<G: ?Sized> {
+    generator: Arc<G>,
+}
+
+impl<G: Generator<1> + ?Sized> Generator1D for Shared<G> {}
+impl<G: Generator<2> + ?Sized> Generator2D for Shared<G> {}
+impl<G: Generator<3> + ?Sized> Generator3D for Shared<G> {}
+impl<G: Generator<4> + ?Sized> Generator4D for Shared<G> {}
+
+impl<G> Shared<G> {
+    #[inline]
+    pub fn new(generator: G) -> Self {
+        Self {
+            generator: Arc::new(generator),
+        }
+    }
+}
+
+impl<G: ?Sized> Clone for Shared<G> {
+    /// Creates another handle to the same generator without cloning the generator itself.
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            generator: Arc::clone(&self.generator),
+        }
+    }
+}
+
+impl<G: ?Sized> From<Arc<G>> for Shared<G> {
+    #[inline]
+    fn from(generator: Arc<G>) -> Self {
+        Self { generator }
+    }
+}
+
+impl<const D: usize, G> Generator<D> for Shared<G>
+where
+    G: Generator<D> + ?Sized,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        self.generator.sample(point)
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        self.generator.output_range()
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Shared").with_child(self.generator.describe())
+    }
+}
@@ -0,0 +1,72 @@
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::{interval, smooth_max},
+};
+
+/// A generator producing the smooth maximum of results of the underlying generator and results
+/// of a given other generator.
+///
+/// For details, see the documentation of [`smooth_max()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`smooth_max()`] implemented by [`Generator`], should be used
+/// to create [`SmoothMax`].
+///
+/// [`smooth_max()`]: Generator::smooth_max
+#[derive(Clone, Copy, Debug)]
+pub struct SmoothMax<const D: usize, GA, GB> {
+    generator_a: GA,
+    generator_b: GB,
+    radius: f64,
+}
+
+impl<GA: Generator<1>, GB: Generator<1>> Generator1D for SmoothMax<1, GA, GB> {}
+impl<GA: Generator<2>, GB: Generator<2>> Generator2D for SmoothMax<2, GA, GB> {}
+impl<GA: Generator<3>, GB: Generator<3>> Generator3D for SmoothMax<3, GA, GB> {}
+impl<GA: Generator<4>, GB: Generator<4>> Generator4D for SmoothMax<4, GA, GB> {}
+
+impl<const D: usize, GA, GB> SmoothMax<D, GA, GB>
+where
+    GA: Generator<D>,
+    GB: Generator<D>,
+{
+    #[inline]
+    pub fn new(generator_a: GA, generator_b: GB, radius: f64) -> Self {
+        Self {
+            generator_a,
+            generator_b,
+            radius,
+        }
+    }
+}
+
+impl<const D: usize, GA, GB> Generator<D> for SmoothMax<D, GA, GB>
+where
+    GA: Generator<D>,
+    GB: Generator<D>,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        smooth_max(
+            self.generator_a.sample(point),
+            self.generator_b.sample(point),
+            self.radius,
+        )
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        let a = self.generator_a.output_range()?;
+        let b = self.generator_b.output_range()?;
+        // the smooth maximum is at most a quarter of the radius greater than the maximum
+        let radius = self.radius.max(0.0);
+        interval::new(a.0.max(b.0), a.1.max(b.1) + radius * 0.25)
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("SmoothMax")
+            .with_parameter("radius", self.radius)
+            .with_child(self.generator_a.describe())
+            .with_child(self.generator_b.describe())
+    }
+}
@@ -0,0 +1,77 @@
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::{interval, smooth_max},
+};
+use alloc::vec::Vec;
+
+/// A generator producing the smooth maximum of the results of all generators of a collection.
+///
+/// For details, see the documentation of [`smooth_max_all()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`smooth_max_all()`] implemented by [`Source`], should be used
+/// to create [`SmoothMaxAll`].
+///
+/// [`smooth_max_all()`]: crate::Source::smooth_max_all
+/// [`Source`]: crate::Source
+#[derive(Clone, Debug)]
+pub struct SmoothMaxAll<const D: usize, G> {
+    generators: Vec<G>,
+    radius: f64,
+}
+
+impl<G: Generator<1>> Generator1D for SmoothMaxAll<1, G> {}
+impl<G: Generator<2>> Generator2D for SmoothMaxAll<2, G> {}
+impl<G: Generator<3>> Generator3D for SmoothMaxAll<3, G> {}
+impl<G: Generator<4>> Generator4D for SmoothMaxAll<4, G> {}
+
+impl<const D: usize, G> SmoothMaxAll<D, G>
+where
+    G: Generator<D>,
+{
+    #[inline]
+    pub fn new<I>(generators: I, radius: f64) -> Self
+    where
+        I: IntoIterator<Item = G>,
+    {
+        Self {
+            generators: generators.into_iter().collect(),
+            radius,
+        }
+    }
+}
+
+impl<const D: usize, G> Generator<D> for SmoothMaxAll<D, G>
+where
+    G: Generator<D>,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        self.generators
+            .iter()
+            .map(|generator| generator.sample(point))
+            .reduce(|a, b| smooth_max(a, b, self.radius))
+            .unwrap_or(f64::NEG_INFINITY)
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        let mut generators = self.generators.iter();
+        let Some(first) = generators.next() else {
+            return Some((f64::NEG_INFINITY, f64::NEG_INFINITY));
+        };
+        // each application of the smooth maximum is at most a quarter of the radius greater than the maximum
+        let radius = self.radius.max(0.0);
+        generators.try_fold(first.output_range()?, |range, generator| {
+            let (min, max) = generator.output_range()?;
+            interval::new(range.0.max(min), range.1.max(max) + radius * 0.25)
+        })
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        self.generators.iter().fold(
+            GraphNode::new("SmoothMaxAll").with_parameter("radius", self.radius),
+            |node, generator| node.with_child(generator.describe()),
+        )
+    }
+}
@@ -0,0 +1,77 @@
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::{interval, smooth_min},
+};
+use alloc::vec::Vec;
+
+/// A generator producing the smooth minimum of the results of all generators of a collection.
+///
+/// For details, see the documentation of [`smooth_min_all()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`smooth_min_all()`] implemented by [`Source`], should be used
+/// to create [`SmoothMinAll`].
+///
+/// [`smooth_min_all()`]: crate::Source::smooth_min_all
+/// [`Source`]: crate::Source
+#[derive(Clone, Debug)]
+pub struct SmoothMinAll<const D: usize, G> {
+    generators: Vec<G>,
+    radius: f64,
+}
+
+impl<G: Generator<1>> Generator1D for SmoothMinAll<1, G> {}
+impl<G: Generator<2>> Generator2D for SmoothMinAll<2, G> {}
+impl<G: Generator<3>> Generator3D for SmoothMinAll<3, G> {}
+impl<G: Generator<4>> Generator4D for SmoothMinAll<4, G> {}
+
+impl<const D: usize, G> SmoothMinAll<D, G>
+where
+    G: Generator<D>,
+{
+    #[inline]
+    pub fn new<I>(generators: I, radius: f64) -> Self
+    where
+        I: IntoIterator<Item = G>,
+    {
+        Self {
+            generators: generators.into_iter().collect(),
+            radius,
+        }
+    }
+}
+
+impl<const D: usize, G> Generator<D> for SmoothMinAll<D, G>
+where
+    G: Generator<D>,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        self.generators
+            .iter()
+            .map(|generator| generator.sample(point))
+            .reduce(|a, b| smooth_min(a, b, self.radius))
+            .unwrap_or(f64::INFINITY)
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        let mut generators = self.generators.iter();
+        let Some(first) = generators.next() else {
+            return Some((f64::INFINITY, f64::INFINITY));
+        };
+        // each application of the smooth minimum is at most a quarter of the radius less than the minimum
+        let radius = self.radius.max(0.0);
+        generators.try_fold(first.output_range()?, |range, generator| {
+            let (min, max) = generator.output_range()?;
+            interval::new(range.0.min(min) - radius * 0.25, range.1.min(max))
+        })
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        self.generators.iter().fold(
+            GraphNode::new("SmoothMinAll").with_parameter("radius", self.radius),
+            |node, generator| node.with_child(generator.describe()),
+        )
+    }
+}
@@ -1,13 +1,98 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::interval,
+};
+use alloc::{vec, vec::Vec};
+use core::fmt;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
 
 /// Error type for errors relating to the [`Spline`] adapter.
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum SplineError {
-    NotEnoughKnots(String),
+    /// Fewer knots were provided than the kind of spline requires.
+    NotEnoughKnots {
+        spline: &'static str,
+        required: usize,
+        provided: usize,
+    },
+    /// The knot vector and knots differ in length.
+    LengthMismatch { knot_vector: usize, knots: usize },
+    /// The knot vector contained NaN, Inf or -Inf.
+    NonFiniteKnotVector,
+    /// The knots contained NaN, Inf or -Inf.
+    NonFiniteKnots,
+    /// The knot vector was not strictly increasing.
+    UnsortedKnotVector,
+    /// The number of tangents differs from the number of knots.
+    TangentLengthMismatch { knots: usize, tangents: usize },
+    /// The tangents contained NaN, Inf or -Inf.
+    NonFiniteTangents,
+}
+
+impl fmt::Display for SplineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotEnoughKnots {
+                spline,
+                required,
+                provided,
+            } => write!(
+                f,
+                "{spline} expected at least {required} knots, but got {provided}."
+            ),
+            Self::LengthMismatch { knot_vector, knots } => write!(
+                f,
+                "Knot vector and knots must be the same length, but got {knot_vector} and {knots}."
+            ),
+            Self::NonFiniteKnotVector => write!(
+                f,
+                "Knot vector must contain finite values, but encountered either NaN, Inf or -Inf."
+            ),
+            Self::NonFiniteKnots => write!(
+                f,
+                "Knots must contain finite values, but encountered either NaN, Inf or -Inf."
+            ),
+            Self::UnsortedKnotVector => write!(
+                f,
+                "Knot vector must be strictly increasing, but it was not."
+            ),
+            Self::TangentLengthMismatch { knots, tangents } => write!(
+                f,
+                "Expected {knots} tangents, one for each knot, but got {tangents}."
+            ),
+            Self::NonFiniteTangents => write!(
+                f,
+                "Tangents must contain finite values, but encountered either NaN, Inf or -Inf."
+            ),
+        }
+    }
+}
+
+impl core::error::Error for SplineError {}
+
+/// Behavior of the [`Spline`] adapter for inputs outside of the domain of the spline.
+///
+/// The domain of a spline is the interval between the first and last entry of its knot vector.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SplineExtrapolation {
+    /// Produce NaN for inputs outside of the domain.
+    #[default]
+    Nan,
+    /// Produce the function value at the nearest end of the domain.
+    Clamp,
+    /// Continue the spline linearly using the value and slope at the nearest end of the domain.
+    Linear,
 }
 
 trait SplineCoefficients {
     fn evaluate(&self, point: f64, knot_vector: &[f64], interval_idx: usize) -> f64;
+
+    fn evaluate_derivative(&self, point: f64, knot_vector: &[f64], interval_idx: usize) -> f64;
+
+    fn critical_points(&self, knot_vector: &[f64], interval_idx: usize) -> [f64; 2];
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -21,7 +106,28 @@ struct CubicSplineCoefficients {
 impl SplineCoefficients for CubicSplineCoefficients {
     fn evaluate(&self, point: f64, knot_vector: &[f64], interval_idx: usize) -> f64 {
         let t = point - knot_vector[interval_idx];
-        self.a + self.b * t + self.c * t.powi(2) + self.d * t.powi(3)
+        self.a + self.b * t + self.c * t * t + self.d * t * t * t
+    }
+
+    fn evaluate_derivative(&self, point: f64, knot_vector: &[f64], interval_idx: usize) -> f64 {
+        let t = point - knot_vector[interval_idx];
+        self.b + 2.0 * self.c * t + 3.0 * self.d * t * t
+    }
+
+    fn critical_points(&self, knot_vector: &[f64], interval_idx: usize) -> [f64; 2] {
+        // roots of the derivative, where missing roots are represented by NaN
+        let roots = match (self.c, self.d) {
+            (0.0, 0.0) => [f64::NAN; 2],
+            (c, 0.0) => [-self.b / (2.0 * c), f64::NAN],
+            (c, d) => {
+                let sqrt_discriminant = (c * c - 3.0 * d * self.b).sqrt();
+                [
+                    (-c + sqrt_discriminant) / (3.0 * d),
+                    (-c - sqrt_discriminant) / (3.0 * d),
+                ]
+            }
+        };
+        roots.map(|t| t + knot_vector[interval_idx])
     }
 }
 
@@ -44,9 +150,48 @@ pub trait SplineImpl {
     fn precompute_coefficients(&mut self);
 
     fn evaluate(&self, point: f64) -> f64;
+
+    /// Returns the interval over which the spline is defined.
+    ///
+    /// The [`Spline`] adapter only applies its [`SplineExtrapolation`] to inputs outside of this
+    /// interval. The default implementation covers all values, which leaves out of bounds handling
+    /// entirely to [`evaluate()`].
+    ///
+    /// [`evaluate()`]: SplineImpl::evaluate
+    fn domain(&self) -> (f64, f64) {
+        (f64::NEG_INFINITY, f64::INFINITY)
+    }
+
+    /// Evaluates the first derivative of the spline at the given point within its domain.
+    ///
+    /// This is used by [`SplineExtrapolation::Linear`]. The default implementation returns NaN.
+    fn evaluate_derivative(&self, _point: f64) -> f64 {
+        f64::NAN
+    }
+
+    /// Returns the range of values the spline takes for inputs within the given interval, or
+    /// `None` if it is unknown.
+    ///
+    /// The interval is guaranteed to lie within the [`domain()`]. This is used to determine the
+    /// output range of the [`Spline`] adapter. The default implementation returns `None`.
+    ///
+    /// [`domain()`]: SplineImpl::domain
+    fn output_range(&self, _min: f64, _max: f64) -> Option<(f64, f64)> {
+        None
+    }
+
+    /// Returns a description of the spline and its parameters for use in [`Generator::describe()`].
+    ///
+    /// The default implementation returns a node named after the type of the spline.
+    fn describe(&self) -> GraphNode {
+        GraphNode::of_type::<Self>()
+    }
 }
 
 /// Implementation of natural cubic splines used in the [`Spline`] adapter.
+///
+/// Natural cubic splines are C2-continuous, but may overshoot the knots between knot vector
+/// entries. At least 4 knots are required.
 #[derive(Clone, Debug)]
 pub struct NaturalCubicSpline {
     knot_vector: Vec<f64>,
@@ -64,35 +209,7 @@ impl SplineImpl for NaturalCubicSpline {
     }
 
     fn validate(&self) -> Result<(), SplineError> {
-        if self.knots.len() < 4 {
-            return Err(SplineError::NotEnoughKnots(format!(
-                "Cubic spline expected at least 4 knots, but got {}.",
-                self.knots.len()
-            )));
-        }
-        if self.knots.len() != self.knot_vector.len() {
-            return Err(SplineError::NotEnoughKnots(
-                "Knot vector and knots must be the same length, but they were not.".to_owned(),
-            ));
-        }
-        if !self.knot_vector.is_sorted() {
-            return Err(SplineError::NotEnoughKnots(
-                "Knot vector must be sorted, but it was not.".to_owned(),
-            ));
-        }
-        if self.knot_vector.iter().any(|x| !x.is_finite()) {
-            return Err(SplineError::NotEnoughKnots(
-                "Knot vector must contain finite values, but encountered either NaN, Inf or -Inf."
-                    .to_owned(),
-            ));
-        }
-        if self.knots.iter().any(|x| !x.is_finite()) {
-            return Err(SplineError::NotEnoughKnots(
-                "Knots must contain finite values, but encountered either NaN, Inf or -Inf."
-                    .to_owned(),
-            ));
-        }
-        Ok(())
+        validate_knots("Cubic spline", &self.knot_vector, &self.knots, 4)
     }
 
     fn precompute_coefficients(&mut self) {
@@ -146,30 +263,461 @@ impl SplineImpl for NaturalCubicSpline {
     }
 
     fn evaluate(&self, point: f64) -> f64 {
-        // handle the case where point is out of bounds
-        if point < *self.knot_vector.first().unwrap() || point > *self.knot_vector.last().unwrap() {
-            return f64::NAN;
+        evaluate_piecewise(&self.knot_vector, &self.knots, &self.coefficients, point)
+    }
+
+    fn domain(&self) -> (f64, f64) {
+        knot_vector_domain(&self.knot_vector)
+    }
+
+    fn evaluate_derivative(&self, point: f64) -> f64 {
+        evaluate_piecewise_derivative(&self.knot_vector, &self.coefficients, point)
+    }
+
+    fn output_range(&self, min: f64, max: f64) -> Option<(f64, f64)> {
+        piecewise_output_range(&self.knot_vector, &self.coefficients, min, max)
+    }
+
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("NaturalCubicSpline")
+            .with_parameter("knot_vector", &self.knot_vector)
+            .with_parameter("knots", &self.knots)
+    }
+}
+
+/// Implementation of piecewise linear splines used in the [`Spline`] adapter.
+///
+/// Linear splines connect neighboring knots with straight lines and therefore never overshoot.
+/// At least 2 knots are required.
+#[derive(Clone, Debug)]
+pub struct LinearSpline {
+    knot_vector: Vec<f64>,
+    knots: Vec<f64>,
+    coefficients: Vec<CubicSplineCoefficients>,
+}
+
+impl SplineImpl for LinearSpline {
+    fn init(knot_vector: &[f64], knots: &[f64]) -> Self {
+        Self {
+            knot_vector: knot_vector.into(),
+            knots: knots.into(),
+            coefficients: Vec::new(),
         }
-        // obtain the index of the interval containing the point
-        let idx = self
-            .knot_vector
-            .binary_search_by(|x| x.partial_cmp(&point).unwrap())
-            .unwrap_or_else(|idx| idx - 1);
-        // evaluate the spline
-        self.coefficients[idx].evaluate(point, &self.knot_vector, idx)
+    }
+
+    fn validate(&self) -> Result<(), SplineError> {
+        validate_knots("Linear spline", &self.knot_vector, &self.knots, 2)
+    }
+
+    fn precompute_coefficients(&mut self) {
+        self.coefficients = (0..self.knots.len() - 1)
+            .map(|i| CubicSplineCoefficients {
+                a: self.knots[i],
+                b: secant(&self.knot_vector, &self.knots, i),
+                c: 0.0,
+                d: 0.0,
+            })
+            .collect();
+    }
+
+    fn evaluate(&self, point: f64) -> f64 {
+        evaluate_piecewise(&self.knot_vector, &self.knots, &self.coefficients, point)
+    }
+
+    fn domain(&self) -> (f64, f64) {
+        knot_vector_domain(&self.knot_vector)
+    }
+
+    fn evaluate_derivative(&self, point: f64) -> f64 {
+        evaluate_piecewise_derivative(&self.knot_vector, &self.coefficients, point)
+    }
+
+    fn output_range(&self, min: f64, max: f64) -> Option<(f64, f64)> {
+        piecewise_output_range(&self.knot_vector, &self.coefficients, min, max)
+    }
+
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("LinearSpline")
+            .with_parameter("knot_vector", &self.knot_vector)
+            .with_parameter("knots", &self.knots)
     }
 }
 
-/// A generator returning the absolute value of the results of the underlying generator.
+/// Implementation of Catmull-Rom splines used in the [`Spline`] adapter.
 ///
-/// Typically, this struct is not meant to be used directly. Instead, [`spline()`]
-/// implemented by [`Generator`], should be used to create [`Spline`].
+/// Catmull-Rom splines are C1-continuous cubic Hermite splines whose tangents are derived from
+/// the neighboring knots. Compared to [`NaturalCubicSpline`] they are local, meaning that moving
+/// a knot only affects the neighboring intervals. At least 2 knots are required.
+#[derive(Clone, Debug)]
+pub struct CatmullRomSpline {
+    knot_vector: Vec<f64>,
+    knots: Vec<f64>,
+    coefficients: Vec<CubicSplineCoefficients>,
+}
+
+impl SplineImpl for CatmullRomSpline {
+    fn init(knot_vector: &[f64], knots: &[f64]) -> Self {
+        Self {
+            knot_vector: knot_vector.into(),
+            knots: knots.into(),
+            coefficients: Vec::new(),
+        }
+    }
+
+    fn validate(&self) -> Result<(), SplineError> {
+        validate_knots("Catmull-Rom spline", &self.knot_vector, &self.knots, 2)
+    }
+
+    fn precompute_coefficients(&mut self) {
+        let n = self.knots.len();
+        // interior tangents span both neighbors, boundary tangents are one-sided
+        let tangents = (0..n)
+            .map(|i| {
+                let lo = i.saturating_sub(1);
+                let hi = (i + 1).min(n - 1);
+                (self.knots[hi] - self.knots[lo]) / (self.knot_vector[hi] - self.knot_vector[lo])
+            })
+            .collect::<Vec<f64>>();
+        self.coefficients = hermite_coefficients(&self.knot_vector, &self.knots, &tangents);
+    }
+
+    fn evaluate(&self, point: f64) -> f64 {
+        evaluate_piecewise(&self.knot_vector, &self.knots, &self.coefficients, point)
+    }
+
+    fn domain(&self) -> (f64, f64) {
+        knot_vector_domain(&self.knot_vector)
+    }
+
+    fn evaluate_derivative(&self, point: f64) -> f64 {
+        evaluate_piecewise_derivative(&self.knot_vector, &self.coefficients, point)
+    }
+
+    fn output_range(&self, min: f64, max: f64) -> Option<(f64, f64)> {
+        piecewise_output_range(&self.knot_vector, &self.coefficients, min, max)
+    }
+
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("CatmullRomSpline")
+            .with_parameter("knot_vector", &self.knot_vector)
+            .with_parameter("knots", &self.knots)
+    }
+}
+
+/// Implementation of monotone cubic splines used in the [`Spline`] adapter.
+///
+/// Tangents are chosen using the Fritsch-Carlson method, such that the spline is monotone on
+/// every interval where the knots are monotone. In particular, the spline never overshoots the
+/// range spanned by two neighboring knots. At least 2 knots are required.
+#[derive(Clone, Debug)]
+pub struct MonotoneCubicSpline {
+    knot_vector: Vec<f64>,
+    knots: Vec<f64>,
+    coefficients: Vec<CubicSplineCoefficients>,
+}
+
+impl SplineImpl for MonotoneCubicSpline {
+    fn init(knot_vector: &[f64], knots: &[f64]) -> Self {
+        Self {
+            knot_vector: knot_vector.into(),
+            knots: knots.into(),
+            coefficients: Vec::new(),
+        }
+    }
+
+    fn validate(&self) -> Result<(), SplineError> {
+        validate_knots("Monotone cubic spline", &self.knot_vector, &self.knots, 2)
+    }
+
+    fn precompute_coefficients(&mut self) {
+        let n = self.knots.len();
+        let secants = (0..n - 1)
+            .map(|i| secant(&self.knot_vector, &self.knots, i))
+            .collect::<Vec<f64>>();
+        // initial tangents, flattened at local extrema
+        let mut tangents = vec![0.0; n];
+        tangents[0] = secants[0];
+        tangents[n - 1] = secants[n - 2];
+        for i in 1..n - 1 {
+            if secants[i - 1] * secants[i] > 0.0 {
+                tangents[i] = (secants[i - 1] + secants[i]) / 2.0;
+            }
+        }
+        // restrict tangents to the region which guarantees monotonicity
+        for i in 0..n - 1 {
+            if secants[i] == 0.0 {
+                tangents[i] = 0.0;
+                tangents[i + 1] = 0.0;
+                continue;
+            }
+            let alpha = tangents[i] / secants[i];
+            let beta = tangents[i + 1] / secants[i];
+            // avoid hypot(), which is not guaranteed to be identical across platforms
+            let magnitude_squared = alpha * alpha + beta * beta;
+            if magnitude_squared > 9.0 {
+                let tau = 3.0 / magnitude_squared.sqrt();
+                tangents[i] = tau * alpha * secants[i];
+                tangents[i + 1] = tau * beta * secants[i];
+            }
+        }
+        self.coefficients = hermite_coefficients(&self.knot_vector, &self.knots, &tangents);
+    }
+
+    fn evaluate(&self, point: f64) -> f64 {
+        evaluate_piecewise(&self.knot_vector, &self.knots, &self.coefficients, point)
+    }
+
+    fn domain(&self) -> (f64, f64) {
+        knot_vector_domain(&self.knot_vector)
+    }
+
+    fn evaluate_derivative(&self, point: f64) -> f64 {
+        evaluate_piecewise_derivative(&self.knot_vector, &self.coefficients, point)
+    }
+
+    fn output_range(&self, min: f64, max: f64) -> Option<(f64, f64)> {
+        piecewise_output_range(&self.knot_vector, &self.coefficients, min, max)
+    }
+
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("MonotoneCubicSpline")
+            .with_parameter("knot_vector", &self.knot_vector)
+            .with_parameter("knots", &self.knots)
+    }
+}
+
+/// Implementation of cubic Hermite splines with user supplied tangents used in the [`Spline`]
+/// adapter.
+///
+/// As the tangents are not part of [`SplineImpl::new()`], this spline must be created using
+/// [`with_tangents()`] and passed to the adapter via [`spline_with()`]. Creating it through
+/// [`SplineImpl::new()`] fails validation, as no tangents are supplied. At least 2 knots are
+/// required.
+///
+/// [`with_tangents()`]: HermiteSpline::with_tangents
+/// [`spline_with()`]: Generator::spline_with
+#[derive(Clone, Debug)]
+pub struct HermiteSpline {
+    knot_vector: Vec<f64>,
+    knots: Vec<f64>,
+    tangents: Vec<f64>,
+    coefficients: Vec<CubicSplineCoefficients>,
+}
+
+impl HermiteSpline {
+    /// Create a new cubic Hermite spline where `tangents[i]` is the slope of the spline at
+    /// `knot_vector[i]`.
+    pub fn with_tangents(
+        knot_vector: &[f64],
+        knots: &[f64],
+        tangents: &[f64],
+    ) -> Result<Self, SplineError> {
+        let mut spline = Self::init(knot_vector, knots);
+        spline.tangents = tangents.into();
+        spline.validate()?;
+        spline.precompute_coefficients();
+        Ok(spline)
+    }
+}
+
+impl SplineImpl for HermiteSpline {
+    fn init(knot_vector: &[f64], knots: &[f64]) -> Self {
+        Self {
+            knot_vector: knot_vector.into(),
+            knots: knots.into(),
+            tangents: Vec::new(),
+            coefficients: Vec::new(),
+        }
+    }
+
+    fn validate(&self) -> Result<(), SplineError> {
+        validate_knots("Hermite spline", &self.knot_vector, &self.knots, 2)?;
+        if self.tangents.len() != self.knots.len() {
+            return Err(SplineError::TangentLengthMismatch {
+                knots: self.knots.len(),
+                tangents: self.tangents.len(),
+            });
+        }
+        if self.tangents.iter().any(|x| !x.is_finite()) {
+            return Err(SplineError::NonFiniteTangents);
+        }
+        Ok(())
+    }
+
+    fn precompute_coefficients(&mut self) {
+        self.coefficients = hermite_coefficients(&self.knot_vector, &self.knots, &self.tangents);
+    }
+
+    fn evaluate(&self, point: f64) -> f64 {
+        evaluate_piecewise(&self.knot_vector, &self.knots, &self.coefficients, point)
+    }
+
+    fn domain(&self) -> (f64, f64) {
+        knot_vector_domain(&self.knot_vector)
+    }
+
+    fn evaluate_derivative(&self, point: f64) -> f64 {
+        evaluate_piecewise_derivative(&self.knot_vector, &self.coefficients, point)
+    }
+
+    fn output_range(&self, min: f64, max: f64) -> Option<(f64, f64)> {
+        piecewise_output_range(&self.knot_vector, &self.coefficients, min, max)
+    }
+
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("HermiteSpline")
+            .with_parameter("knot_vector", &self.knot_vector)
+            .with_parameter("knots", &self.knots)
+            .with_parameter("tangents", &self.tangents)
+    }
+}
+
+fn validate_knots(
+    spline: &'static str,
+    knot_vector: &[f64],
+    knots: &[f64],
+    required: usize,
+) -> Result<(), SplineError> {
+    if knots.len() < required {
+        return Err(SplineError::NotEnoughKnots {
+            spline,
+            required,
+            provided: knots.len(),
+        });
+    }
+    if knots.len() != knot_vector.len() {
+        return Err(SplineError::LengthMismatch {
+            knot_vector: knot_vector.len(),
+            knots: knots.len(),
+        });
+    }
+    if knot_vector.iter().any(|x| !x.is_finite()) {
+        return Err(SplineError::NonFiniteKnotVector);
+    }
+    if knots.iter().any(|x| !x.is_finite()) {
+        return Err(SplineError::NonFiniteKnots);
+    }
+    if knot_vector.windows(2).any(|w| w[0] >= w[1]) {
+        return Err(SplineError::UnsortedKnotVector);
+    }
+    Ok(())
+}
+
+#[inline]
+fn secant(knot_vector: &[f64], knots: &[f64], i: usize) -> f64 {
+    (knots[i + 1] - knots[i]) / (knot_vector[i + 1] - knot_vector[i])
+}
+
+fn hermite_coefficients(
+    knot_vector: &[f64],
+    knots: &[f64],
+    tangents: &[f64],
+) -> Vec<CubicSplineCoefficients> {
+    (0..knots.len() - 1)
+        .map(|i| {
+            let h = knot_vector[i + 1] - knot_vector[i];
+            let s = secant(knot_vector, knots, i);
+            CubicSplineCoefficients {
+                a: knots[i],
+                b: tangents[i],
+                c: (3.0 * s - 2.0 * tangents[i] - tangents[i + 1]) / h,
+                d: (tangents[i] + tangents[i + 1] - 2.0 * s) / (h * h),
+            }
+        })
+        .collect()
+}
+
+#[inline]
+fn knot_vector_domain(knot_vector: &[f64]) -> (f64, f64) {
+    (*knot_vector.first().unwrap(), *knot_vector.last().unwrap())
+}
+
+#[inline]
+fn interval_index(knot_vector: &[f64], point: f64) -> Option<Result<usize, usize>> {
+    // handle the case where point is out of bounds
+    let (min, max) = knot_vector_domain(knot_vector);
+    if !(min..=max).contains(&point) {
+        return None;
+    }
+    // obtain the index of the interval containing the point, or the index of the knot if the
+    // point lies exactly on one
+    Some(
+        knot_vector
+            .binary_search_by(|x| x.total_cmp(&point))
+            .map_err(|idx| idx - 1),
+    )
+}
+
+#[inline]
+fn evaluate_piecewise(
+    knot_vector: &[f64],
+    knots: &[f64],
+    coefficients: &[CubicSplineCoefficients],
+    point: f64,
+) -> f64 {
+    match interval_index(knot_vector, point) {
+        Some(Ok(idx)) => knots[idx],
+        Some(Err(idx)) => coefficients[idx].evaluate(point, knot_vector, idx),
+        None => f64::NAN,
+    }
+}
+
+#[inline]
+fn evaluate_piecewise_derivative(
+    knot_vector: &[f64],
+    coefficients: &[CubicSplineCoefficients],
+    point: f64,
+) -> f64 {
+    match interval_index(knot_vector, point) {
+        // the last knot belongs to the last interval
+        Some(Ok(idx) | Err(idx)) => {
+            let idx = idx.min(coefficients.len() - 1);
+            coefficients[idx].evaluate_derivative(point, knot_vector, idx)
+        }
+        None => f64::NAN,
+    }
+}
+
+#[inline]
+fn piecewise_output_range(
+    knot_vector: &[f64],
+    coefficients: &[CubicSplineCoefficients],
+    min: f64,
+    max: f64,
+) -> Option<(f64, f64)> {
+    let mut values = Vec::new();
+    for (idx, coefficients) in coefficients.iter().enumerate() {
+        // the part of the interval overlapping the current piece
+        let start = min.max(knot_vector[idx]);
+        let end = max.min(knot_vector[idx + 1]);
+        if start > end {
+            continue;
+        }
+        // the extrema of each piece lie either at its bounds or at its critical points
+        values.push(coefficients.evaluate(start, knot_vector, idx));
+        values.push(coefficients.evaluate(end, knot_vector, idx));
+        for point in coefficients.critical_points(knot_vector, idx) {
+            if start < point && point < end {
+                values.push(coefficients.evaluate(point, knot_vector, idx));
+            }
+        }
+    }
+    interval::hull(&values)
+}
+
+/// A generator returning the function value of a provided spline given the result of the
+/// underlying generator as input.
+///
+/// For details, see the documentation of [`spline()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`spline()`] implemented by [`Generator`], should be used
+/// to create [`Spline`].
 ///
 /// [`spline()`]: Generator::spline
 #[derive(Clone, Debug)]
 pub struct Spline<const D: usize, G, S: SplineImpl> {
     generator: G,
     spline: S,
+    extrapolation: SplineExtrapolation,
 }
 
 impl<G: Generator<1>, S: SplineImpl> Generator1D for Spline<1, G, S> {}
@@ -181,10 +729,41 @@ impl<const D: usize, G, S: SplineImpl> Spline<D, G, S>
 where
     G: Generator<D>,
 {
+    /// # Panics
+    ///
+    /// Panics if the spline could not be constructed from the given knots. See [`try_new()`]
+    /// for a fallible alternative.
+    ///
+    /// [`try_new()`]: Spline::try_new
     #[inline]
     pub fn new(generator: G, knot_vector: &[f64], knots: &[f64]) -> Self {
-        let spline = SplineImpl::new(knot_vector, knots).unwrap();
-        Self { generator, spline }
+        match Self::try_new(generator, knot_vector, knots) {
+            Ok(spline) => spline,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    #[inline]
+    pub fn try_new(generator: G, knot_vector: &[f64], knots: &[f64]) -> Result<Self, SplineError> {
+        let spline = SplineImpl::new(knot_vector, knots)?;
+        Ok(Self::with_spline(generator, spline))
+    }
+
+    #[inline]
+    pub fn with_spline(generator: G, spline: S) -> Self {
+        Self {
+            generator,
+            spline,
+            extrapolation: SplineExtrapolation::default(),
+        }
+    }
+
+    /// Set the behavior for inputs outside of the domain of the spline. By default, such inputs
+    /// produce NaN.
+    #[inline]
+    pub fn with_extrapolation(mut self, extrapolation: SplineExtrapolation) -> Self {
+        self.extrapolation = extrapolation;
+        self
     }
 }
 
@@ -195,6 +774,61 @@ where
 {
     #[inline]
     fn sample(&self, point: [f64; D]) -> f64 {
-        self.spline.evaluate(self.generator.sample(point))
+        let x = self.generator.sample(point);
+        let (min, max) = self.spline.domain();
+        let bound = match x {
+            x if x < min => min,
+            x if x > max => max,
+            x => return self.spline.evaluate(x),
+        };
+        match self.extrapolation {
+            SplineExtrapolation::Nan => f64::NAN,
+            SplineExtrapolation::Clamp => self.spline.evaluate(bound),
+            SplineExtrapolation::Linear => {
+                self.spline.evaluate(bound) + self.spline.evaluate_derivative(bound) * (x - bound)
+            }
+        }
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        let (min, max) = self.generator.output_range()?;
+        let (lower, upper) = self.spline.domain();
+        let mut range = match (min.max(lower), max.min(upper)) {
+            (start, end) if start <= end => Some(self.spline.output_range(start, end)?),
+            _ => None,
+        };
+        // inputs outside of the domain only contribute values if they are extrapolated
+        let below = (min < lower).then_some((lower, min - lower));
+        let above = (max > upper).then_some((upper, max - upper));
+        for (bound, extent) in [below, above].into_iter().flatten() {
+            let extrapolated = match self.extrapolation {
+                SplineExtrapolation::Nan => continue,
+                SplineExtrapolation::Clamp => {
+                    let value = self.spline.evaluate(bound);
+                    interval::new(value, value)?
+                }
+                SplineExtrapolation::Linear => {
+                    let value = self.spline.evaluate(bound);
+                    match self.spline.evaluate_derivative(bound) {
+                        0.0 => interval::new(value, value)?,
+                        slope => interval::hull(&[value, value + slope * extent])?,
+                    }
+                }
+            };
+            range = Some(match range {
+                Some(range) => interval::union(range, extrapolated),
+                None => extrapolated,
+            });
+        }
+        range
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Spline")
+            .with_parameter("extrapolation", self.extrapolation)
+            .with_child(self.generator.describe())
+            .with_labeled_child("spline", self.spline.describe())
     }
 }
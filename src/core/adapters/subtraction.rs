@@ -0,0 +1,78 @@
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::interval,
+};
+
+/// A generator subtracting results of a given other generator from results of the underlying
+/// generator.
+///
+/// Typically, this struct is not meant to be used directly. Instead, the `-` operator, which is
+/// implemented for all generators of this crate, should be used to create [`Subtraction`]. Not to
+/// be confused with [`Difference`], which implements the difference blend mode.
+///
+/// [`Difference`]: crate::Difference
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{Source, Generator};
+/// let point = [0.2, 0.5];
+///
+/// let generator = Source::simplex(42) - Source::simplex(43);
+///
+/// let value = generator.sample(point);
+///
+/// assert_eq!(value, Source::simplex(42).sample(point) - Source::simplex(43).sample(point))
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Subtraction<const D: usize, GA, GB> {
+    generator_a: GA,
+    generator_b: GB,
+}
+
+impl<GA: Generator<1>, GB: Generator<1>> Generator1D for Subtraction<1, GA, GB> {}
+impl<GA: Generator<2>, GB: Generator<2>> Generator2D for Subtraction<2, GA, GB> {}
+impl<GA: Generator<3>, GB: Generator<3>> Generator3D for Subtraction<3, GA, GB> {}
+impl<GA: Generator<4>, GB: Generator<4>> Generator4D for Subtraction<4, GA, GB> {}
+
+impl<const D: usize, GA, GB> Subtraction<D, GA, GB>
+where
+    GA: Generator<D>,
+    GB: Generator<D>,
+{
+    #[inline]
+    pub fn new(generator_a: GA, generator_b: GB) -> Self {
+        Self {
+            generator_a,
+            generator_b,
+        }
+    }
+}
+
+impl<const D: usize, GA, GB> Generator<D> for Subtraction<D, GA, GB>
+where
+    GA: Generator<D>,
+    GB: Generator<D>,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        self.generator_a.sample(point) - self.generator_b.sample(point)
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        let a = self.generator_a.output_range()?;
+        let b = self.generator_b.output_range()?;
+        interval::sub(a, b)
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Subtraction")
+            .with_labeled_child("a", self.generator_a.describe())
+            .with_labeled_child("b", self.generator_b.describe())
+    }
+}
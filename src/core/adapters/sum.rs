@@ -1,4 +1,8 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::interval,
+};
 
 /// A generator adding results of the underlying generator to results of a given other
 /// generator.
@@ -42,4 +46,18 @@ where
     fn sample(&self, point: [f64; D]) -> f64 {
         self.generator_a.sample(point) + self.generator_b.sample(point)
     }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        let a = self.generator_a.output_range()?;
+        let b = self.generator_b.output_range()?;
+        interval::add(a, b)
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Sum")
+            .with_child(self.generator_a.describe())
+            .with_child(self.generator_b.describe())
+    }
 }
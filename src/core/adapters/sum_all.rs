@@ -0,0 +1,70 @@
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::interval,
+};
+use alloc::vec::Vec;
+
+/// A generator adding the results of all generators of a collection.
+///
+/// For details, see the documentation of [`sum_all()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`sum_all()`] implemented by [`Source`], should be used
+/// to create [`SumAll`].
+///
+/// [`sum_all()`]: crate::Source::sum_all
+/// [`Source`]: crate::Source
+#[derive(Clone, Debug)]
+pub struct SumAll<const D: usize, G> {
+    generators: Vec<G>,
+}
+
+impl<G: Generator<1>> Generator1D for SumAll<1, G> {}
+impl<G: Generator<2>> Generator2D for SumAll<2, G> {}
+impl<G: Generator<3>> Generator3D for SumAll<3, G> {}
+impl<G: Generator<4>> Generator4D for SumAll<4, G> {}
+
+impl<const D: usize, G> SumAll<D, G>
+where
+    G: Generator<D>,
+{
+    #[inline]
+    pub fn new<I>(generators: I) -> Self
+    where
+        I: IntoIterator<Item = G>,
+    {
+        Self {
+            generators: generators.into_iter().collect(),
+        }
+    }
+}
+
+impl<const D: usize, G> Generator<D> for SumAll<D, G>
+where
+    G: Generator<D>,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        self.generators
+            .iter()
+            .map(|generator| generator.sample(point))
+            .sum()
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        self.generators
+            .iter()
+            .try_fold((0.0, 0.0), |range, generator| {
+                interval::add(range, generator.output_range()?)
+            })
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        self.generators
+            .iter()
+            .fold(GraphNode::new("SumAll"), |node, generator| {
+                node.with_child(generator.describe())
+            })
+    }
+}
@@ -0,0 +1,64 @@
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::mat_vec_mul,
+};
+
+/// A generator which applies an affine transformation to input points before passing them to the
+/// underlying generator.
+///
+/// For details, see the documentation of [`transform()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`transform()`] implemented by [`Generator`], should be used
+/// to create [`Transform`].
+///
+/// [`transform()`]: Generator::transform
+#[derive(Clone, Copy, Debug)]
+pub struct Transform<const D: usize, G> {
+    generator: G,
+    matrix: [[f64; D]; D],
+    offset: [f64; D],
+}
+
+impl<G: Generator<1>> Generator1D for Transform<1, G> {}
+impl<G: Generator<2>> Generator2D for Transform<2, G> {}
+impl<G: Generator<3>> Generator3D for Transform<3, G> {}
+impl<G: Generator<4>> Generator4D for Transform<4, G> {}
+
+impl<const D: usize, G> Transform<D, G>
+where
+    G: Generator<D>,
+{
+    #[inline]
+    pub fn new(generator: G, matrix: [[f64; D]; D], offset: [f64; D]) -> Self {
+        Self {
+            generator,
+            matrix,
+            offset,
+        }
+    }
+}
+
+impl<const D: usize, G> Generator<D> for Transform<D, G>
+where
+    G: Generator<D>,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        let transformed = mat_vec_mul(&self.matrix, point);
+        self.generator
+            .sample(core::array::from_fn(|i| transformed[i] + self.offset[i]))
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        self.generator.output_range()
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Transform")
+            .with_parameter("matrix", self.matrix)
+            .with_parameter("offset", self.offset)
+            .with_child(self.generator.describe())
+    }
+}
@@ -1,4 +1,7 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+};
 
 /// A generator which translates input points before passing them to the underlying generator.
 ///
@@ -38,6 +41,18 @@ where
     #[inline]
     fn sample(&self, point: [f64; D]) -> f64 {
         self.generator
-            .sample(std::array::from_fn(|i| point[i] + self.translation[i]))
+            .sample(core::array::from_fn(|i| point[i] + self.translation[i]))
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        self.generator.output_range()
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Translate")
+            .with_parameter("translation", self.translation)
+            .with_child(self.generator.describe())
     }
 }
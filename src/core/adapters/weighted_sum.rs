@@ -0,0 +1,71 @@
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::interval,
+};
+use alloc::{format, vec::Vec};
+
+/// A generator adding the weighted results of all generators of a collection.
+///
+/// For details, see the documentation of [`weighted_sum()`]. Typically, this struct is not meant
+/// to be used directly. Instead, [`weighted_sum()`] implemented by [`Source`], should be used
+/// to create [`WeightedSum`].
+///
+/// [`weighted_sum()`]: crate::Source::weighted_sum
+/// [`Source`]: crate::Source
+#[derive(Clone, Debug)]
+pub struct WeightedSum<const D: usize, G> {
+    weighted_generators: Vec<(f64, G)>,
+}
+
+impl<G: Generator<1>> Generator1D for WeightedSum<1, G> {}
+impl<G: Generator<2>> Generator2D for WeightedSum<2, G> {}
+impl<G: Generator<3>> Generator3D for WeightedSum<3, G> {}
+impl<G: Generator<4>> Generator4D for WeightedSum<4, G> {}
+
+impl<const D: usize, G> WeightedSum<D, G>
+where
+    G: Generator<D>,
+{
+    #[inline]
+    pub fn new<I>(weighted_generators: I) -> Self
+    where
+        I: IntoIterator<Item = (f64, G)>,
+    {
+        Self {
+            weighted_generators: weighted_generators.into_iter().collect(),
+        }
+    }
+}
+
+impl<const D: usize, G> Generator<D> for WeightedSum<D, G>
+where
+    G: Generator<D>,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        self.weighted_generators
+            .iter()
+            .map(|(weight, generator)| weight * generator.sample(point))
+            .sum()
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        self.weighted_generators
+            .iter()
+            .try_fold((0.0, 0.0), |range, (weight, generator)| {
+                interval::add(range, interval::scale(generator.output_range()?, *weight)?)
+            })
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        self.weighted_generators.iter().fold(
+            GraphNode::new("WeightedSum"),
+            |node, (weight, generator)| {
+                node.with_labeled_child(format!("weight {weight:?}"), generator.describe())
+            },
+        )
+    }
+}
@@ -0,0 +1,156 @@
+use crate::core::{
+    adapters::SplineError,
+    utils::{ptable::MAX_PERMUTATION_TABLE_SIZE, stats::NoiseStats},
+};
+use core::fmt;
+
+/// Error type for invalid parameters passed when building a generator.
+///
+/// This error is produced by the fallible counterparts of adapters which validate their
+/// parameters, such as [`try_fbm()`] or [`try_select()`]. The infallible adapters perform no
+/// validation and instead produce meaningless values such as NaN when sampled. It is also produced
+/// by the fallible counterparts of sources which would otherwise panic, such as
+/// [`try_perlin_with()`].
+///
+/// [`try_fbm()`]: crate::Generator::try_fbm
+/// [`try_select()`]: crate::Generator::try_select
+/// [`try_perlin_with()`]: crate::Source::try_perlin_with
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum NoiseError {
+    /// The number of octaves of a fractal adapter was zero.
+    ZeroOctaves,
+    /// The lacunarity of a fractal adapter was not strictly positive.
+    NonPositiveLacunarity(f64),
+    /// The selection interval of a select adapter contained no values.
+    EmptySelectionRange { min: f64, max: f64 },
+    /// A selector adapter was created without any generators to select from.
+    EmptySelector,
+    /// A threshold of a selector adapter was NaN.
+    NanThreshold,
+    /// The edge falloff of a select or selector adapter was NaN.
+    NanEdgeFalloff,
+    /// The output range of the generator to normalize was unknown.
+    UnknownOutputRange,
+    /// The output range of the generator to normalize was not bounded by finite values.
+    UnboundedOutputRange { min: f64, max: f64 },
+    /// None of the samples taken to estimate the output range of a generator were other than NaN.
+    NoValidSamples,
+    /// The size of a permutation table was not within [1, 65536].
+    InvalidTableSize(usize),
+    /// The spline of a spline adapter was invalid.
+    Spline(SplineError),
+}
+
+impl fmt::Display for NoiseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ZeroOctaves => write!(f, "Expected at least 1 octave, but got 0."),
+            Self::NonPositiveLacunarity(lacunarity) => write!(
+                f,
+                "Lacunarity must be strictly positive, but got {lacunarity}."
+            ),
+            Self::EmptySelectionRange { min, max } => write!(
+                f,
+                "Selection interval [{min}, {max}] must not be empty, but it was."
+            ),
+            Self::EmptySelector => {
+                write!(f, "Selector expected at least 1 generator, but got none.")
+            }
+            Self::NanThreshold => write!(
+                f,
+                "Selector thresholds must not be NaN, but encountered NaN."
+            ),
+            Self::NanEdgeFalloff => write!(f, "Edge falloff must not be NaN, but got NaN."),
+            Self::UnknownOutputRange => write!(
+                f,
+                "Expected a generator with a known output range, but it was unknown."
+            ),
+            Self::UnboundedOutputRange { min, max } => {
+                write!(f, "Output range must be finite, but got [{min}, {max}].")
+            }
+            Self::NoValidSamples => write!(
+                f,
+                "Expected at least 1 sample other than NaN to estimate the output range, but got none."
+            ),
+            Self::InvalidTableSize(size) => write!(
+                f,
+                "Permutation table size must be within [1, {MAX_PERMUTATION_TABLE_SIZE}], but got {size}."
+            ),
+            Self::Spline(err) => err.fmt(f),
+        }
+    }
+}
+
+impl core::error::Error for NoiseError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Spline(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<SplineError> for NoiseError {
+    fn from(err: SplineError) -> Self {
+        Self::Spline(err)
+    }
+}
+
+pub(crate) fn validate_octaves(octaves: u32, lacunarity: f64) -> Result<(), NoiseError> {
+    if octaves == 0 {
+        return Err(NoiseError::ZeroOctaves);
+    }
+    if lacunarity.is_nan() || lacunarity <= 0.0 {
+        return Err(NoiseError::NonPositiveLacunarity(lacunarity));
+    }
+    Ok(())
+}
+
+pub(crate) fn validate_selection(min: f64, max: f64) -> Result<(), NoiseError> {
+    if min.is_nan() || max.is_nan() || min > max {
+        return Err(NoiseError::EmptySelectionRange { min, max });
+    }
+    Ok(())
+}
+
+pub(crate) fn validate_edge_falloff(edge_falloff: f64) -> Result<(), NoiseError> {
+    if edge_falloff.is_nan() {
+        return Err(NoiseError::NanEdgeFalloff);
+    }
+    Ok(())
+}
+
+pub(crate) fn validate_thresholds(thresholds: &[f64]) -> Result<(), NoiseError> {
+    if thresholds.is_empty() {
+        return Err(NoiseError::EmptySelector);
+    }
+    if thresholds.iter().any(|x| x.is_nan()) {
+        return Err(NoiseError::NanThreshold);
+    }
+    Ok(())
+}
+
+pub(crate) fn validate_output_range(range: Option<(f64, f64)>) -> Result<(f64, f64), NoiseError> {
+    match range {
+        None => Err(NoiseError::UnknownOutputRange),
+        Some((min, max)) if !min.is_finite() || !max.is_finite() => {
+            Err(NoiseError::UnboundedOutputRange { min, max })
+        }
+        Some(range) => Ok(range),
+    }
+}
+
+pub(crate) fn validate_estimated_range(stats: &NoiseStats) -> Result<(f64, f64), NoiseError> {
+    if stats.count == 0 {
+        return Err(NoiseError::NoValidSamples);
+    }
+    validate_output_range(Some((stats.min, stats.max)))
+}
+
+pub(crate) fn validate_table_size(size: usize) -> Result<(), NoiseError> {
+    if !(1..=MAX_PERMUTATION_TABLE_SIZE).contains(&size) {
+        return Err(NoiseError::InvalidTableSize(size));
+    }
+    Ok(())
+}
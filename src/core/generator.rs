@@ -1,5 +1,10 @@
-use crate::core::adapters;
-use std::marker::Sized;
+use crate::core::{
+    adapters,
+    error::NoiseError,
+    graph::GraphNode,
+    utils::{math::quaternion_to_matrix, ptable::Seed},
+};
+use alloc::boxed::Box;
 
 /// A trait for building a coherent noise generation pipeline.
 ///
@@ -54,7 +59,32 @@ use std::marker::Sized;
 /// // sample the generator at [0.2, 0.5, 0.3, 0.7]
 /// let value = generator.sample([0.2, 0.5, 0.3, 0.7]);
 /// ```
-pub trait Generator<const D: usize>: Sized {
+///
+/// # Arithmetic operators
+///
+/// All generators of this crate, except for boxed and [`Shared`] generators, implement the
+/// operators `+`, `-`, `*`, `/` and unary `-`, both between two generators and between a
+/// generator and an `f64`. These operators build the
+/// corresponding adapters, such as [`Sum`] for `a + b` or [`Mul`] for `a * 2.0`, allowing
+/// generators to be combined as expressions:
+///
+/// ```
+/// # use libnoise::{Source, Generator};
+/// let point = [0.2, 0.5];
+///
+/// let generator = (Source::simplex(42) + Source::perlin(43) * 0.5) / 1.5 - 0.25;
+///
+/// let value = generator.sample(point);
+///
+/// let expected =
+///     (Source::simplex(42).sample(point) + Source::perlin(43).sample(point) * 0.5) / 1.5 - 0.25;
+/// assert_eq!(value, expected);
+/// ```
+///
+/// [`Sum`]: adapters::Sum
+/// [`Mul`]: adapters::Mul
+/// [`Shared`]: adapters::Shared
+pub trait Generator<const D: usize> {
     /// Samples the generator at a given `point` and returns the resulting value.
     ///
     /// The input dimension is determined by the specific generator, and the required size of `point`
@@ -77,6 +107,106 @@ pub trait Generator<const D: usize>: Sized {
     /// ```
     fn sample(&self, point: [f64; D]) -> f64;
 
+    /// Returns bounds for the values produced by the generator, if they can be determined.
+    ///
+    /// If this returns `Some((min, max))`, every value returned by [`sample()`] other than NaN lies
+    /// within `[min, max]`, up to floating point rounding errors. Sources report the bounds of their
+    /// noise functions, and adapters derive their range from the ranges of the underlying
+    /// generators. The bounds are thus guaranteed, but not necessarily tight. For example, the sum of
+    /// two generators is bounded by the sum of their bounds, even if both never reach their maximum
+    /// for the same input. If no bounds can be determined, such as for [`lambda()`] or
+    /// [`custom()`], this returns `None`.
+    ///
+    /// The default implementation returns `None`. Custom generators may override it to take part
+    /// in the range analysis.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::<2>::simplex(42).abs().mul(0.5).add(0.5);
+    ///
+    /// assert_eq!(generator.output_range(), Some((0.5, 1.0)));
+    /// ```
+    ///
+    /// [`sample()`]: Generator::sample
+    /// [`lambda()`]: Generator::lambda
+    /// [`custom()`]: crate::Source::custom
+    fn output_range(&self) -> Option<(f64, f64)> {
+        None
+    }
+
+    /// Returns a description of the generator as a tree of its components and their parameters.
+    ///
+    /// The returned [`GraphNode`] describes the generator itself, and contains a child node for
+    /// each generator it is composed of. This is useful to inspect complex pipelines, whose types
+    /// become unwieldy quickly. The tree can be printed as text or exported to Graphviz, see
+    /// [`GraphNode`] for details.
+    ///
+    /// The default implementation returns a node named after the type of the generator without
+    /// parameters or children. Custom generators may override it to describe themselves.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::<2>::simplex(42).scale([0.5, 2.0]).abs();
+    ///
+    /// let node = generator.describe();
+    ///
+    /// assert_eq!(node.name, "Abs");
+    /// assert_eq!(node.children[0].1.name, "Scale");
+    /// assert_eq!(node.children[0].1.parameters, [("scale".into(), "[0.5, 2.0]".into())]);
+    /// ```
+    fn describe(&self) -> GraphNode {
+        GraphNode::of_type::<Self>()
+    }
+
+    /// Create a generator which records the number of samples and the time spent sampling the
+    /// underlying generator under the given name.
+    ///
+    /// Creates a generator which is exactly the same as the underlying generator, except each
+    /// sample is timed and recorded in a global registry. Instrumented generators nested within
+    /// each other form a tree, such that slow branches of a pipeline can be identified. The
+    /// measurements can be retrieved using [`profile::report()`]. This adapter is only available
+    /// when the `profile` feature is enabled.
+    ///
+    /// <p style="background:rgba(122,186,255,0.16);padding:0.75em;">
+    /// <strong>Note:</strong>
+    /// Measuring each sample adds a constant overhead, which is included in the time of enclosing
+    /// instrumented generators. Instrumenting cheap generators thus inflates the measurements.
+    /// </p>
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, profile};
+    /// let generator = Source::simplex(42)     // build a generator
+    ///     .instrument("simplex");             // apply the adapter
+    ///
+    /// generator.sample([0.2, 0.5]);           // sample the generator
+    ///
+    /// let report = profile::report();
+    /// let entry = report.entries.iter().find(|entry| entry.path == ["simplex"]).unwrap();
+    /// assert!(entry.samples >= 1);
+    /// ```
+    ///
+    /// [`profile::report()`]: crate::profile::report
+    #[cfg(feature = "profile")]
+    #[inline]
+    fn instrument(self, name: &'static str) -> adapters::Instrument<D, Self>
+    where
+        Self: Sized,
+    {
+        adapters::Instrument::new(self, name)
+    }
+
     /// Create a generator which scales input points before passing them to the underlying generator.
     ///
     /// Takes a scale factor for each dimension of the input space and crates a generator which scales
@@ -99,7 +229,10 @@ pub trait Generator<const D: usize>: Sized {
     /// assert_eq!(value, Source::simplex(42).sample([0.4, 0.1]))
     /// ```
     #[inline]
-    fn scale(self, scale: [f64; D]) -> adapters::Scale<D, Self> {
+    fn scale(self, scale: [f64; D]) -> adapters::Scale<D, Self>
+    where
+        Self: Sized,
+    {
         adapters::Scale::new(self, scale)
     }
 
@@ -125,10 +258,48 @@ pub trait Generator<const D: usize>: Sized {
     /// assert_eq!(value, Source::simplex(42).sample([0.5, 1.5]))
     /// ```
     #[inline]
-    fn translate(self, translation: [f64; D]) -> adapters::Translate<D, Self> {
+    fn translate(self, translation: [f64; D]) -> adapters::Translate<D, Self>
+    where
+        Self: Sized,
+    {
         adapters::Translate::new(self, translation)
     }
 
+    /// Create a generator which applies an affine transformation to input points before passing them
+    /// to the underlying generator.
+    ///
+    /// Takes a square `matrix` and an `offset` and creates a generator which transforms each input
+    /// point `p` into `matrix * p + offset` before passing it to the underlying generator. The
+    /// matrix is given in row-major order, that is, `matrix[i]` is the `i`-th row, and points are
+    /// treated as column vectors. This allows arbitrary linear maps including rotation, shear and
+    /// anisotropic stretch along any direction, followed by a translation, to be applied at the cost
+    /// of a single matrix multiplication.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let point = [0.2, 0.5];
+    ///
+    /// // shear along the x-axis, then translate
+    /// let generator = Source::simplex(42)     // build a generator
+    ///     .transform([[1.0, 0.5], [0.0, 1.0]], [0.3, 1.0]); // apply the adapter
+    ///
+    /// let value = generator.sample(point);    // sample the generator
+    ///
+    /// // [0.75, 1.5] is equivalent to [0.2 + 0.5 * 0.5 + 0.3, 0.5 + 1.0]
+    /// assert_eq!(value, Source::simplex(42).sample([0.75, 1.5]))
+    /// ```
+    #[inline]
+    fn transform(self, matrix: [[f64; D]; D], offset: [f64; D]) -> adapters::Transform<D, Self>
+    where
+        Self: Sized,
+    {
+        adapters::Transform::new(self, matrix, offset)
+    }
+
     /// Create a generator which negates the results of the underlying generator.
     ///
     /// Creates a generator which is exactly the same as the underlying generator, except it changes
@@ -150,7 +321,10 @@ pub trait Generator<const D: usize>: Sized {
     /// assert_eq!(value, -Source::simplex(42).sample(point))
     /// ```
     #[inline]
-    fn neg(self) -> adapters::Neg<D, Self> {
+    fn neg(self) -> adapters::Neg<D, Self>
+    where
+        Self: Sized,
+    {
         adapters::Neg::new(self)
     }
 
@@ -175,7 +349,10 @@ pub trait Generator<const D: usize>: Sized {
     /// assert_eq!(value, Source::simplex(42).sample(point).abs())
     /// ```
     #[inline]
-    fn abs(self) -> adapters::Abs<D, Self> {
+    fn abs(self) -> adapters::Abs<D, Self>
+    where
+        Self: Sized,
+    {
         adapters::Abs::new(self)
     }
 
@@ -201,7 +378,10 @@ pub trait Generator<const D: usize>: Sized {
     /// assert_eq!(value, Source::simplex(42).sample(point).exp())
     /// ```
     #[inline]
-    fn exp(self) -> adapters::Exp<D, Self> {
+    fn exp(self) -> adapters::Exp<D, Self>
+    where
+        Self: Sized,
+    {
         adapters::Exp::new(self)
     }
 
@@ -226,7 +406,10 @@ pub trait Generator<const D: usize>: Sized {
     /// assert_eq!(value, Source::simplex(42).sample(point) + 1.5)
     /// ```
     #[inline]
-    fn add(self, offset: f64) -> adapters::Add<D, Self> {
+    fn add(self, offset: f64) -> adapters::Add<D, Self>
+    where
+        Self: Sized,
+    {
         adapters::Add::new(self, offset)
     }
 
@@ -251,10 +434,46 @@ pub trait Generator<const D: usize>: Sized {
     /// assert_eq!(value, Source::simplex(42).sample(point) * 1.5)
     /// ```
     #[inline]
-    fn mul(self, scale: f64) -> adapters::Mul<D, Self> {
+    fn mul(self, scale: f64) -> adapters::Mul<D, Self>
+    where
+        Self: Sized,
+    {
         adapters::Mul::new(self, scale)
     }
 
+    /// Create a generator multiplying `scale` to results of the underlying generator and adding
+    /// `bias` afterwards.
+    ///
+    /// Creates a generator which is exactly the same as the underlying generator, except the result
+    /// is multiplied by `scale` and then offset by `bias`. This is equivalent to chaining [`mul()`]
+    /// and [`add()`], but applies both in a single adapter.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let point = [0.2, 0.5];
+    ///
+    /// let generator = Source::simplex(42)     // build a generator
+    ///     .scale_bias(0.5, 0.5);              // apply the adapter
+    ///
+    /// let value = generator.sample(point);    // sample the generator
+    ///
+    /// assert_eq!(value, Source::simplex(42).sample(point) * 0.5 + 0.5)
+    /// ```
+    ///
+    /// [`mul()`]: Generator::mul
+    /// [`add()`]: Generator::add
+    #[inline]
+    fn scale_bias(self, scale: f64, bias: f64) -> adapters::ScaleBias<D, Self>
+    where
+        Self: Sized,
+    {
+        adapters::ScaleBias::new(self, scale, bias)
+    }
+
     /// Create a generator raising results of the underlying generator to the power of `exponent`.
     ///
     /// Creates a generator which is exactly the same as the underlying generator, except the result
@@ -278,7 +497,10 @@ pub trait Generator<const D: usize>: Sized {
     /// assert_eq!(value, Source::simplex(42).sample(point).powi(2))
     /// ```
     #[inline]
-    fn powi(self, exponent: i32) -> adapters::Pow<D, Self, i32> {
+    fn powi(self, exponent: i32) -> adapters::Pow<D, Self, i32>
+    where
+        Self: Sized,
+    {
         adapters::Pow::new(self, exponent)
     }
 
@@ -305,7 +527,10 @@ pub trait Generator<const D: usize>: Sized {
     /// assert_eq!(value, Source::simplex(42).sample(point).powf(1.5))
     /// ```
     #[inline]
-    fn powf(self, exponent: f64) -> adapters::Pow<D, Self, f64> {
+    fn powf(self, exponent: f64) -> adapters::Pow<D, Self, f64>
+    where
+        Self: Sized,
+    {
         adapters::Pow::new(self, exponent)
     }
 
@@ -332,10 +557,231 @@ pub trait Generator<const D: usize>: Sized {
     /// assert_eq!(value, Source::simplex(42).sample(point).clamp(-0.5, 0.5))
     /// ```
     #[inline]
-    fn clamp(self, min: f64, max: f64) -> adapters::Clamp<D, Self> {
+    fn clamp(self, min: f64, max: f64) -> adapters::Clamp<D, Self>
+    where
+        Self: Sized,
+    {
         adapters::Clamp::new(self, min, max)
     }
 
+    /// Create a generator linearly remapping results of the underlying generator from one interval
+    /// to another.
+    ///
+    /// Creates a generator which is exactly the same as the underlying generator, except the result
+    /// is linearly mapped such that `from_min` becomes `to_min` and `from_max` becomes `to_max`.
+    /// Values outside of the source interval are extrapolated, not clamped. If `from_min` equals
+    /// `from_max`, all values are mapped to the center of the target interval.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let point = [0.2, 0.5];
+    ///
+    /// let generator = Source::simplex(42)     // build a generator
+    ///     .remap(-1.0, 1.0, 0.0, 255.0);      // apply the adapter
+    ///
+    /// let value = generator.sample(point);    // sample the generator
+    ///
+    /// let expected = (Source::simplex(42).sample(point) + 1.0) / 2.0 * 255.0;
+    /// assert!((value - expected).abs() < 1e-12)
+    /// ```
+    #[inline]
+    fn remap(
+        self,
+        from_min: f64,
+        from_max: f64,
+        to_min: f64,
+        to_max: f64,
+    ) -> adapters::ScaleBias<D, Self>
+    where
+        Self: Sized,
+    {
+        adapters::ScaleBias::remap(self, from_min, from_max, to_min, to_max)
+    }
+
+    /// Create a generator linearly remapping results of the underlying generator from its output
+    /// range to the interval from `to_min` to `to_max`.
+    ///
+    /// Creates a generator which is exactly the same as the underlying generator, except the result
+    /// is [`remap()`]ped from the [`output_range()`] of the underlying generator to the given
+    /// interval. Unlike a hand-tuned combination of [`mul()`] and [`add()`], the mapping adjusts
+    /// itself when parameters of the underlying generator, such as the persistence of [`fbm()`],
+    /// change.
+    ///
+    /// The output range is a guaranteed, but not necessarily tight bound, such that the normalized
+    /// values may not span the entire target interval. To map the values actually produced within
+    /// a region instead, use [`normalize_estimated()`].
+    ///
+    /// <p style="background:rgba(255,181,77,0.16);padding:0.75em;">
+    /// <strong>Note:</strong>
+    /// If the output range of the underlying generator is unknown, such as for generators involving
+    /// a closure, or not finite, there is no linear map onto the target interval, and this adapter
+    /// panics. Use [`try_normalize()`] to handle this case instead, or [`normalize_estimated()`] to
+    /// estimate the range by sampling.
+    /// </p>
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::<2>::simplex(42) // build a generator
+    ///     .fbm(5, 0.013, 2.0, 0.5)            // apply an adapter
+    ///     .normalize(0.0, 1.0);               // normalize the result
+    ///
+    /// let (min, max) = generator.output_range().unwrap();
+    /// assert!((min - 0.0).abs() < 1e-12 && (max - 1.0).abs() < 1e-12);
+    /// ```
+    ///
+    /// [`remap()`]: Generator::remap
+    /// [`output_range()`]: Generator::output_range
+    /// [`mul()`]: Generator::mul
+    /// [`add()`]: Generator::add
+    /// [`fbm()`]: Generator::fbm
+    /// [`normalize_estimated()`]: Generator::normalize_estimated
+    /// [`try_normalize()`]: Generator::try_normalize
+    #[inline]
+    fn normalize(self, to_min: f64, to_max: f64) -> adapters::ScaleBias<D, Self>
+    where
+        Self: Sized,
+    {
+        adapters::ScaleBias::normalize(self, to_min, to_max)
+    }
+
+    /// Create a generator applying [`normalize()`] on the underlying generator, after validating
+    /// its output range.
+    ///
+    /// This adapter is equivalent to [`normalize()`], except it returns an error instead of
+    /// panicking if the output range of the underlying generator is unknown or not finite.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, NoiseError};
+    /// let generator = Source::<2>::simplex(42).try_normalize(0.0, 1.0);
+    /// assert!(generator.is_ok());
+    ///
+    /// let generator = Source::<2>::simplex(42).lambda(|x| x * 2.0).try_normalize(0.0, 1.0);
+    /// assert_eq!(generator.err(), Some(NoiseError::UnknownOutputRange));
+    /// ```
+    ///
+    /// [`normalize()`]: Generator::normalize
+    #[inline]
+    fn try_normalize(
+        self,
+        to_min: f64,
+        to_max: f64,
+    ) -> Result<adapters::ScaleBias<D, Self>, NoiseError>
+    where
+        Self: Sized,
+    {
+        adapters::ScaleBias::try_normalize(self, to_min, to_max)
+    }
+
+    /// Create a generator linearly remapping results of the underlying generator from the range of
+    /// values it produces within a region to the interval from `to_min` to `to_max`.
+    ///
+    /// Creates a generator which is exactly the same as the underlying generator, except the result
+    /// is [`remap()`]ped from the smallest and largest of `samples` values sampled within `region`
+    /// to the given interval. The sample points are placed as by [`stats::estimate()`] using
+    /// `seed`, and the range is estimated once when creating the generator. Unlike [`normalize()`],
+    /// this works for generators with an unknown output range, and maps the values actually
+    /// produced onto the target interval, even if the output range is loose.
+    ///
+    /// <p style="background:rgba(255,181,77,0.16);padding:0.75em;">
+    /// <strong>Warning:</strong>
+    /// The estimated range is not a bound. Values outside of the region, or between the sample
+    /// points, may fall outside of the target interval. If this is not acceptable, clamp the
+    /// result.
+    /// </p>
+    ///
+    /// <p style="background:rgba(255,181,77,0.16);padding:0.75em;">
+    /// <strong>Note:</strong>
+    /// If no sample is other than NaN, such as if `samples` is zero, or if a sample is infinite,
+    /// there is no linear map onto the target interval, and this adapter panics. Use
+    /// [`try_normalize_estimated()`] to handle this case instead.
+    /// </p>
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::<2>::simplex(42)       // build a generator
+    ///     .lambda(|x| x * x)                        // apply an adapter
+    ///     .normalize_estimated(                     // normalize the result
+    ///         [(0.0, 100.0); 2],                    // region to sample
+    ///         10000,                                // number of samples
+    ///         0,                                    // seed for placing the samples
+    ///         0.0,                                  // target minimum
+    ///         1.0,                                  // target maximum
+    ///     );
+    ///
+    /// let value = generator.sample([12.3, 45.6]);
+    /// ```
+    ///
+    /// [`remap()`]: Generator::remap
+    /// [`stats::estimate()`]: crate::stats::estimate
+    /// [`normalize()`]: Generator::normalize
+    /// [`try_normalize_estimated()`]: Generator::try_normalize_estimated
+    #[inline]
+    fn normalize_estimated(
+        self,
+        region: [(f64, f64); D],
+        samples: usize,
+        seed: impl Seed,
+        to_min: f64,
+        to_max: f64,
+    ) -> adapters::ScaleBias<D, Self>
+    where
+        Self: Sized,
+    {
+        adapters::ScaleBias::normalize_estimated(self, region, samples, seed, to_min, to_max)
+    }
+
+    /// Create a generator applying [`normalize_estimated()`] on the underlying generator, after
+    /// validating the estimated range.
+    ///
+    /// This adapter is equivalent to [`normalize_estimated()`], except it returns an error instead
+    /// of panicking if no sample is other than NaN, or if a sample is infinite.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, NoiseError};
+    /// let region = [(0.0, 100.0); 2];
+    /// let generator = Source::<2>::simplex(42).try_normalize_estimated(region, 100, 0, 0.0, 1.0);
+    /// assert!(generator.is_ok());
+    ///
+    /// let generator = Source::<2>::simplex(42).try_normalize_estimated(region, 0, 0, 0.0, 1.0);
+    /// assert_eq!(generator.err(), Some(NoiseError::NoValidSamples));
+    /// ```
+    ///
+    /// [`normalize_estimated()`]: Generator::normalize_estimated
+    #[inline]
+    fn try_normalize_estimated(
+        self,
+        region: [(f64, f64); D],
+        samples: usize,
+        seed: impl Seed,
+        to_min: f64,
+        to_max: f64,
+    ) -> Result<adapters::ScaleBias<D, Self>, NoiseError>
+    where
+        Self: Sized,
+    {
+        adapters::ScaleBias::try_normalize_estimated(self, region, samples, seed, to_min, to_max)
+    }
+
     /// Create a generator applying the supplied closure to results of the underlying generator.
     ///
     /// Creates a generator which is exactly the same as the underlying generator, except the result
@@ -360,6 +806,7 @@ pub trait Generator<const D: usize>: Sized {
     #[inline]
     fn lambda<L>(self, lambda: L) -> adapters::Lambda<D, Self, L>
     where
+        Self: Sized,
         L: Fn(f64) -> f64,
     {
         adapters::Lambda::new(self, lambda)
@@ -389,6 +836,7 @@ pub trait Generator<const D: usize>: Sized {
     #[inline]
     fn sum<G>(self, other: G) -> adapters::Sum<D, Self, G>
     where
+        Self: Sized,
         G: Generator<D>,
     {
         adapters::Sum::new(self, other)
@@ -418,6 +866,7 @@ pub trait Generator<const D: usize>: Sized {
     #[inline]
     fn product<G>(self, other: G) -> adapters::Product<D, Self, G>
     where
+        Self: Sized,
         G: Generator<D>,
     {
         adapters::Product::new(self, other)
@@ -447,6 +896,7 @@ pub trait Generator<const D: usize>: Sized {
     #[inline]
     fn min<G>(self, other: G) -> adapters::Min<D, Self, G>
     where
+        Self: Sized,
         G: Generator<D>,
     {
         adapters::Min::new(self, other)
@@ -476,11 +926,82 @@ pub trait Generator<const D: usize>: Sized {
     #[inline]
     fn max<G>(self, other: G) -> adapters::Max<D, Self, G>
     where
+        Self: Sized,
         G: Generator<D>,
     {
         adapters::Max::new(self, other)
     }
 
+    /// Create a generator producing the smooth minimum of results of the underlying generator and
+    /// results of a given other generator.
+    ///
+    /// Creates a generator which is producing the minimum of the result of the underlying generator
+    /// and the result of the given generator, except where the two differ by less than `radius`. There,
+    /// both results are blended using a quadratic polynomial, removing the crease produced by [`min()`].
+    /// The result is thus never greater than that of [`min()`], and at most `radius / 4` less. A
+    /// `radius` of 0 or less is equivalent to [`min()`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let point = [0.2, 0.5];
+    ///
+    /// let generator = Source::simplex(42)     // build a generator
+    ///     .smooth_min(Source::simplex(43), 0.25); // apply the adapter
+    ///
+    /// let value = generator.sample(point);    // sample the generator
+    ///
+    /// assert!(value <= Source::simplex(42).sample(point).min(Source::simplex(43).sample(point)))
+    /// ```
+    ///
+    /// [`min()`]: Generator::min
+    #[inline]
+    fn smooth_min<G>(self, other: G, radius: f64) -> adapters::SmoothMin<D, Self, G>
+    where
+        Self: Sized,
+        G: Generator<D>,
+    {
+        adapters::SmoothMin::new(self, other, radius)
+    }
+
+    /// Create a generator producing the smooth maximum of results of the underlying generator and
+    /// results of a given other generator.
+    ///
+    /// Creates a generator which is producing the maximum of the result of the underlying generator
+    /// and the result of the given generator, except where the two differ by less than `radius`. There,
+    /// both results are blended using a quadratic polynomial, removing the crease produced by [`max()`].
+    /// The result is thus never less than that of [`max()`], and at most `radius / 4` greater. A
+    /// `radius` of 0 or less is equivalent to [`max()`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let point = [0.2, 0.5];
+    ///
+    /// let generator = Source::simplex(42)     // build a generator
+    ///     .smooth_max(Source::simplex(43), 0.25); // apply the adapter
+    ///
+    /// let value = generator.sample(point);    // sample the generator
+    ///
+    /// assert!(value >= Source::simplex(42).sample(point).max(Source::simplex(43).sample(point)))
+    /// ```
+    ///
+    /// [`max()`]: Generator::max
+    #[inline]
+    fn smooth_max<G>(self, other: G, radius: f64) -> adapters::SmoothMax<D, Self, G>
+    where
+        Self: Sized,
+        G: Generator<D>,
+    {
+        adapters::SmoothMax::new(self, other, radius)
+    }
+
     /// Create a generator raising results of the underlying generator to the power of results of a
     /// given other generator.
     ///
@@ -506,6 +1027,7 @@ pub trait Generator<const D: usize>: Sized {
     #[inline]
     fn power<G>(self, other: G) -> adapters::Power<D, Self, G>
     where
+        Self: Sized,
         G: Generator<D>,
     {
         adapters::Power::new(self, other)
@@ -578,10 +1100,47 @@ pub trait Generator<const D: usize>: Sized {
         frequency: f64,
         lacunarity: f64,
         persistence: f64,
-    ) -> adapters::Fbm<D, Self> {
+    ) -> adapters::Fbm<D, Self>
+    where
+        Self: Sized,
+    {
         adapters::Fbm::new(self, octaves, frequency, lacunarity, persistence)
     }
 
+    /// Create a generator applying [`fbm()`] on the underlying generator, after validating the
+    /// parameters.
+    ///
+    /// This adapter is equivalent to [`fbm()`], except it returns an error instead of producing a
+    /// meaningless generator if `octaves` is zero or `lacunarity` is not strictly positive.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, NoiseError};
+    /// let generator = Source::<2>::simplex(42).try_fbm(6, 1.0, 2.0, 0.5);
+    /// assert!(generator.is_ok());
+    ///
+    /// let generator = Source::<2>::simplex(42).try_fbm(0, 1.0, 2.0, 0.5);
+    /// assert_eq!(generator.unwrap_err(), NoiseError::ZeroOctaves);
+    /// ```
+    ///
+    /// [`fbm()`]: Generator::fbm
+    #[inline]
+    fn try_fbm(
+        self,
+        octaves: u32,
+        frequency: f64,
+        lacunarity: f64,
+        persistence: f64,
+    ) -> Result<adapters::Fbm<D, Self>, NoiseError>
+    where
+        Self: Sized,
+    {
+        adapters::Fbm::try_new(self, octaves, frequency, lacunarity, persistence)
+    }
+
     /// Create a generator applying an [`fbm()`]-like effect on the underlying generator.
     ///
     /// This adapter is very similar to the [`fbm()`] adapter, except instead of using the output of the
@@ -650,10 +1209,47 @@ pub trait Generator<const D: usize>: Sized {
         frequency: f64,
         lacunarity: f64,
         persistence: f64,
-    ) -> adapters::Billow<D, Self> {
+    ) -> adapters::Billow<D, Self>
+    where
+        Self: Sized,
+    {
         adapters::Billow::new(self, octaves, frequency, lacunarity, persistence)
     }
 
+    /// Create a generator applying [`billow()`] on the underlying generator, after validating the
+    /// parameters.
+    ///
+    /// This adapter is equivalent to [`billow()`], except it returns an error instead of producing a
+    /// meaningless generator if `octaves` is zero or `lacunarity` is not strictly positive.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, NoiseError};
+    /// let generator = Source::<2>::simplex(42).try_billow(6, 1.0, 2.0, 0.5);
+    /// assert!(generator.is_ok());
+    ///
+    /// let generator = Source::<2>::simplex(42).try_billow(0, 1.0, 2.0, 0.5);
+    /// assert_eq!(generator.unwrap_err(), NoiseError::ZeroOctaves);
+    /// ```
+    ///
+    /// [`billow()`]: Generator::billow
+    #[inline]
+    fn try_billow(
+        self,
+        octaves: u32,
+        frequency: f64,
+        lacunarity: f64,
+        persistence: f64,
+    ) -> Result<adapters::Billow<D, Self>, NoiseError>
+    where
+        Self: Sized,
+    {
+        adapters::Billow::try_new(self, octaves, frequency, lacunarity, persistence)
+    }
+
     /// Create a generator applying an [`fbm()`]-like effect on the underlying generator.
     ///
     /// This adapter is very similar to the [`fbm()`] adapter. A core difference is the lack of a
@@ -729,10 +1325,47 @@ pub trait Generator<const D: usize>: Sized {
         frequency: f64,
         lacunarity: f64,
         attenuation: f64,
-    ) -> adapters::RidgedMulti<D, Self> {
+    ) -> adapters::RidgedMulti<D, Self>
+    where
+        Self: Sized,
+    {
         adapters::RidgedMulti::new(self, octaves, frequency, lacunarity, attenuation)
     }
 
+    /// Create a generator applying [`ridgedmulti()`] on the underlying generator, after validating the
+    /// parameters.
+    ///
+    /// This adapter is equivalent to [`ridgedmulti()`], except it returns an error instead of producing a
+    /// meaningless generator if `octaves` is zero or `lacunarity` is not strictly positive.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, NoiseError};
+    /// let generator = Source::<2>::simplex(42).try_ridgedmulti(6, 1.0, 2.0, 2.0);
+    /// assert!(generator.is_ok());
+    ///
+    /// let generator = Source::<2>::simplex(42).try_ridgedmulti(0, 1.0, 2.0, 2.0);
+    /// assert_eq!(generator.unwrap_err(), NoiseError::ZeroOctaves);
+    /// ```
+    ///
+    /// [`ridgedmulti()`]: Generator::ridgedmulti
+    #[inline]
+    fn try_ridgedmulti(
+        self,
+        octaves: u32,
+        frequency: f64,
+        lacunarity: f64,
+        attenuation: f64,
+    ) -> Result<adapters::RidgedMulti<D, Self>, NoiseError>
+    where
+        Self: Sized,
+    {
+        adapters::RidgedMulti::try_new(self, octaves, frequency, lacunarity, attenuation)
+    }
+
     /// Create a generator blending the underlying generator with a given other generator based on the
     /// value supplied by a control-generator.
     ///
@@ -743,6 +1376,12 @@ pub trait Generator<const D: usize>: Sized {
     /// `control` values, the result is the linear interpolation between the results of the underlying
     /// generator and `other`.
     ///
+    /// If the value is exactly -1 or 1, only the generator contributing to the result is sampled, and
+    /// its result is returned unchanged. This makes blending expensive generators cheap in regions where
+    /// the control generator saturates, see also [`blend_clamped()`]. Prior to version 1.3, a value of
+    /// exactly 1 instead produced `a + (b - a)`, which may differ from the result `b` of `other` due to
+    /// rounding.
+    ///
     /// <p style="background:rgba(255,181,77,0.16);padding:0.75em;">
     /// <strong>Warning:</strong>
     /// This adapter assumes that the control generator produces values in the [-1, 1] range. The
@@ -757,28 +1396,261 @@ pub trait Generator<const D: usize>: Sized {
     /// # use libnoise::{Source, Generator};
     /// let point = [0.2, 0.5];
     ///
-    /// // build a generator using the adapter
-    /// let generator = Source::simplex(42)
-    ///     .blend(Source::simplex(43), Source::simplex(44));
+    /// // build a generator using the adapter
+    /// let generator = Source::simplex(42)
+    ///     .blend(Source::simplex(43), Source::simplex(44));
+    ///
+    /// // sample the generator
+    /// let value = generator.sample(point);
+    ///
+    /// // compute manually for the given point to illustrate
+    /// let a = Source::simplex(42).sample(point);
+    /// let b = Source::simplex(43).sample(point);
+    /// let t = Source::simplex(44).sample(point) * 0.5 + 0.5;
+    /// let expected = a + t * (b - a);
+    ///
+    /// assert!(value - expected < f64::EPSILON);
+    /// ```
+    ///
+    /// [`blend_clamped()`]: Generator::blend_clamped
+    #[inline]
+    fn blend<G, GC>(self, other: G, control: GC) -> adapters::Blend<D, Self, G, GC>
+    where
+        Self: Sized,
+        G: Generator<D>,
+        GC: Generator<D>,
+    {
+        adapters::Blend::new(self, other, control)
+    }
+
+    /// Create a generator blending the underlying generator with a given other generator based on the
+    /// clamped value supplied by a control-generator.
+    ///
+    /// This adapter is equivalent to [`blend()`], except the value of `control` is clamped to the
+    /// [-1, 1] range. Control values outside of this range therefore do not extrapolate, and instead
+    /// produce the result of only the underlying generator or `other`, without sampling the other one.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let point = [0.2, 0.5];
+    ///
+    /// // build a generator using the adapter
+    /// let generator = Source::simplex(42)
+    ///     .blend_clamped(Source::simplex(43), Source::simplex(44).mul(3.0));
+    ///
+    /// // sample the generator
+    /// let value = generator.sample(point);
+    ///
+    /// // compute manually for the given point to illustrate
+    /// let a = Source::simplex(42).sample(point);
+    /// let b = Source::simplex(43).sample(point);
+    /// let t = (Source::simplex(44).sample(point) * 3.0 * 0.5 + 0.5).clamp(0.0, 1.0);
+    /// let expected = a + t * (b - a);
+    ///
+    /// assert!((value - expected).abs() < f64::EPSILON);
+    /// ```
+    ///
+    /// [`blend()`]: Generator::blend
+    #[inline]
+    fn blend_clamped<G, GC>(self, other: G, control: GC) -> adapters::Blend<D, Self, G, GC>
+    where
+        Self: Sized,
+        G: Generator<D>,
+        GC: Generator<D>,
+    {
+        adapters::Blend::new_clamped(self, other, control)
+    }
+
+    /// Create a generator combining results of the underlying generator and results of a given other
+    /// generator using the overlay blend mode.
+    ///
+    /// Creates a generator which combines the result of the underlying generator, acting as the base
+    /// layer, with the result of the given generator, acting as the blend layer, for the same input
+    /// point. Where the base layer is dark, the layers are multiplied. Where it is bright, they are
+    /// screened, see also [`screen()`]. This increases the contrast of the base layer.
+    ///
+    /// Like in image editors, the blend mode operates on values in the [0, 1] range. Results are thus
+    /// mapped from the [-1, 1] range to [0, 1] before blending, and the blended value is mapped back to
+    /// [-1, 1]. This matches the mapping used when visualizing noise.
+    ///
+    /// <p style="background:rgba(255,181,77,0.16);padding:0.75em;">
+    /// <strong>Warning:</strong>
+    /// This adapter assumes that both generators produce values in the [-1, 1] range. The generator
+    /// created by this adapter will not produce results matching those of image editors, if this
+    /// contract is violated.
+    /// </p>
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let point = [0.2, 0.5];
+    ///
+    /// let generator = Source::simplex(42)     // build a generator
+    ///     .overlay(Source::simplex(43));      // apply the adapter
+    ///
+    /// let value = generator.sample(point);    // sample the generator
+    ///
+    /// // compute manually for the given point to illustrate
+    /// let a = Source::simplex(42).sample(point) * 0.5 + 0.5;
+    /// let b = Source::simplex(43).sample(point) * 0.5 + 0.5;
+    /// let expected = match a < 0.5 {
+    ///     true => 2.0 * a * b,
+    ///     false => 1.0 - 2.0 * (1.0 - a) * (1.0 - b),
+    /// } * 2.0 - 1.0;
+    ///
+    /// assert!((value - expected).abs() < 1e-12);
+    /// ```
+    ///
+    /// [`screen()`]: Generator::screen
+    #[inline]
+    fn overlay<G>(self, other: G) -> adapters::Overlay<D, Self, G>
+    where
+        Self: Sized,
+        G: Generator<D>,
+    {
+        adapters::Overlay::new(self, other)
+    }
+
+    /// Create a generator combining results of the underlying generator and results of a given other
+    /// generator using the screen blend mode.
+    ///
+    /// Creates a generator which combines the result of the underlying generator and the result of the
+    /// given generator for the same input point by multiplying their inverses and inverting the
+    /// product. The result is never darker than either of the inputs.
+    ///
+    /// Like in image editors, the blend mode operates on values in the [0, 1] range. Results are thus
+    /// mapped from the [-1, 1] range to [0, 1] before blending, and the blended value is mapped back to
+    /// [-1, 1]. This matches the mapping used when visualizing noise.
+    ///
+    /// <p style="background:rgba(255,181,77,0.16);padding:0.75em;">
+    /// <strong>Warning:</strong>
+    /// This adapter assumes that both generators produce values in the [-1, 1] range. The generator
+    /// created by this adapter will not produce results matching those of image editors, if this
+    /// contract is violated.
+    /// </p>
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let point = [0.2, 0.5];
+    ///
+    /// let generator = Source::simplex(42)     // build a generator
+    ///     .screen(Source::simplex(43));       // apply the adapter
+    ///
+    /// let value = generator.sample(point);    // sample the generator
+    ///
+    /// // compute manually for the given point to illustrate
+    /// let a = Source::simplex(42).sample(point) * 0.5 + 0.5;
+    /// let b = Source::simplex(43).sample(point) * 0.5 + 0.5;
+    /// let expected = (1.0 - (1.0 - a) * (1.0 - b)) * 2.0 - 1.0;
+    ///
+    /// assert!((value - expected).abs() < 1e-12);
+    /// ```
+    #[inline]
+    fn screen<G>(self, other: G) -> adapters::Screen<D, Self, G>
+    where
+        Self: Sized,
+        G: Generator<D>,
+    {
+        adapters::Screen::new(self, other)
+    }
+
+    /// Create a generator combining results of the underlying generator and results of a given other
+    /// generator using the difference blend mode.
+    ///
+    /// Creates a generator which produces the absolute difference of the result of the underlying
+    /// generator and the result of the given generator for the same input point. Identical inputs
+    /// produce black, that is -1.
+    ///
+    /// Like in image editors, the blend mode operates on values in the [0, 1] range. Results are thus
+    /// mapped from the [-1, 1] range to [0, 1] before blending, and the blended value is mapped back to
+    /// [-1, 1]. This matches the mapping used when visualizing noise.
+    ///
+    /// <p style="background:rgba(255,181,77,0.16);padding:0.75em;">
+    /// <strong>Warning:</strong>
+    /// This adapter assumes that both generators produce values in the [-1, 1] range. The generator
+    /// created by this adapter will not produce results matching those of image editors, if this
+    /// contract is violated.
+    /// </p>
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let point = [0.2, 0.5];
+    ///
+    /// let generator = Source::simplex(42)     // build a generator
+    ///     .difference(Source::simplex(43));   // apply the adapter
+    ///
+    /// let value = generator.sample(point);    // sample the generator
+    ///
+    /// // compute manually for the given point to illustrate
+    /// let a = Source::simplex(42).sample(point) * 0.5 + 0.5;
+    /// let b = Source::simplex(43).sample(point) * 0.5 + 0.5;
+    /// let expected = (a - b).abs() * 2.0 - 1.0;
+    ///
+    /// assert!((value - expected).abs() < 1e-12);
+    /// ```
+    #[inline]
+    fn difference<G>(self, other: G) -> adapters::Difference<D, Self, G>
+    where
+        Self: Sized,
+        G: Generator<D>,
+    {
+        adapters::Difference::new(self, other)
+    }
+
+    /// Create a generator linearly interpolating between results of the underlying generator and results
+    /// of a given other generator.
+    ///
+    /// Creates a generator which produces the linear interpolation between the result of the underlying
+    /// generator and the result of the given generator for the same input point, weighted by `t`. This
+    /// corresponds to the normal blend mode of image editors with an opacity of `t`. If `t` is 0, the
+    /// result is equal to that of the underlying generator. If `t` is 1, the result is equal to that of
+    /// `other`. In these cases, only the generator contributing to the result is sampled. To vary the
+    /// weight across the input space, use [`blend()`] instead.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let point = [0.2, 0.5];
+    ///
+    /// let generator = Source::simplex(42)     // build a generator
+    ///     .lerp(Source::simplex(43), 0.25);   // apply the adapter
     ///
-    /// // sample the generator
-    /// let value = generator.sample(point);
+    /// let value = generator.sample(point);    // sample the generator
     ///
     /// // compute manually for the given point to illustrate
     /// let a = Source::simplex(42).sample(point);
     /// let b = Source::simplex(43).sample(point);
-    /// let t = Source::simplex(44).sample(point) * 0.5 + 0.5;
-    /// let expected = a + t * (b - a);
+    /// let expected = a + 0.25 * (b - a);
     ///
-    /// assert!(value - expected < f64::EPSILON);
+    /// assert!((value - expected).abs() < 1e-12);
     /// ```
+    ///
+    /// [`blend()`]: Generator::blend
     #[inline]
-    fn blend<G, GC>(self, other: G, control: GC) -> adapters::Blend<D, Self, G, GC>
+    fn lerp<G>(self, other: G, t: f64) -> adapters::Lerp<D, Self, G>
     where
+        Self: Sized,
         G: Generator<D>,
-        GC: Generator<D>,
     {
-        adapters::Blend::new(self, other, control)
+        adapters::Lerp::new(self, other, t)
     }
 
     /// Create a generator selecting the result of either the underlying generator or that of a given
@@ -790,6 +1662,11 @@ pub trait Generator<const D: usize>: Sized {
     /// lies within the provided interval, produce the result of the underlying generator. Otherwise,
     /// produce the result of `other`.
     ///
+    /// By default, the transition between both generators at the bounds of the interval is a hard
+    /// cut. Calling [`with_edge_falloff()`] on the resulting generator instead crossfades the results
+    /// of both generators using an S-curve within a band of the given half-width around each bound.
+    /// Outside of these bands, only the selected generator is sampled.
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -813,6 +1690,23 @@ pub trait Generator<const D: usize>: Sized {
     ///
     /// assert!(value - expected < f64::EPSILON);
     /// ```
+    ///
+    /// Smoothing the seams between both generators:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let point = [0.2, 0.5];
+    ///
+    /// // build a generator using the adapter
+    /// let generator = Source::simplex(42)
+    ///     .select(Source::simplex(43), Source::simplex(44), -0.3, 0.1)
+    ///     .with_edge_falloff(0.05);
+    ///
+    /// // sample the generator
+    /// let value = generator.sample(point);
+    /// ```
+    ///
+    /// [`with_edge_falloff()`]: adapters::Select::with_edge_falloff
     #[inline]
     fn select<G, GC>(
         self,
@@ -822,12 +1716,131 @@ pub trait Generator<const D: usize>: Sized {
         selection_max: f64,
     ) -> adapters::Select<D, Self, G, GC>
     where
+        Self: Sized,
         G: Generator<D>,
         GC: Generator<D>,
     {
         adapters::Select::new(self, other, control, selection_min, selection_max)
     }
 
+    /// Create a generator applying [`select()`], after validating the parameters.
+    ///
+    /// This adapter is equivalent to [`select()`], except it returns an error instead of producing
+    /// a generator which never selects the underlying generator, if the interval defined by
+    /// `selection_min` and `selection_max` is empty.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, NoiseError};
+    /// let generator = Source::<2>::simplex(42)
+    ///     .try_select(Source::simplex(43), Source::simplex(44), 0.1, -0.3);
+    ///
+    /// assert_eq!(generator.unwrap_err(), NoiseError::EmptySelectionRange { min: 0.1, max: -0.3 });
+    /// ```
+    ///
+    /// [`select()`]: Generator::select
+    #[inline]
+    fn try_select<G, GC>(
+        self,
+        other: G,
+        control: GC,
+        selection_min: f64,
+        selection_max: f64,
+    ) -> Result<adapters::Select<D, Self, G, GC>, NoiseError>
+    where
+        Self: Sized,
+        G: Generator<D>,
+        GC: Generator<D>,
+    {
+        adapters::Select::try_new(self, other, control, selection_min, selection_max)
+    }
+
+    /// Create a generator selecting the result of one of several generators based on the band in
+    /// which the value supplied by the underlying generator lies.
+    ///
+    /// The underlying generator acts as the control generator. This adapter takes `bands`, a list of
+    /// pairs of a threshold and a generator. The generator of a pair is selected if the control value
+    /// is greater than or equal to its threshold and less than the next greater threshold. Control
+    /// values below the smallest threshold select the generator with the smallest threshold. The
+    /// pairs need not be sorted. As all generators must be of the same type, use [`boxed()`] to
+    /// select between generators of different types, such as when building the list at runtime.
+    ///
+    /// By default, the transition between neighboring generators is a hard cut. Calling
+    /// [`with_edge_falloff()`] on the resulting generator instead crossfades the results of
+    /// neighboring generators using an S-curve within a band of the given half-width around each
+    /// threshold. Outside of these bands, only the selected generator is sampled.
+    ///
+    /// <p style="background:rgba(255,181,77,0.16);padding:0.75em;">
+    /// <strong>Note:</strong>
+    /// This adapter panics if `bands` is empty or contains a NaN threshold. Use [`try_selector()`]
+    /// to handle such inputs instead.
+    /// </p>
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let point = [0.2, 0.5];
+    ///
+    /// // build a generator using the adapter
+    /// let generator = Source::simplex(42)
+    ///     .selector(vec![
+    ///         (-1.0, Source::constant(-0.5).boxed()),
+    ///         (-0.2, Source::simplex(43).fbm(4, 0.02, 2.0, 0.5).boxed()),
+    ///         (0.4, Source::worley(44).boxed()),
+    ///     ])
+    ///     .with_edge_falloff(0.05);
+    ///
+    /// // sample the generator
+    /// let value = generator.sample(point);
+    /// ```
+    ///
+    /// [`boxed()`]: Generator::boxed
+    /// [`with_edge_falloff()`]: adapters::Selector::with_edge_falloff
+    /// [`try_selector()`]: Generator::try_selector
+    #[inline]
+    fn selector<G, I>(self, bands: I) -> adapters::Selector<D, Self, G>
+    where
+        Self: Sized,
+        G: Generator<D>,
+        I: IntoIterator<Item = (f64, G)>,
+    {
+        adapters::Selector::new(self, bands)
+    }
+
+    /// Create a generator applying [`selector()`], after validating the parameters.
+    ///
+    /// This adapter is equivalent to [`selector()`], except it returns an error instead of
+    /// panicking if `bands` is empty or contains a NaN threshold.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, NoiseError};
+    /// let generator = Source::<2>::simplex(42)
+    ///     .try_selector(vec![(f64::NAN, Source::simplex(43))]);
+    ///
+    /// assert_eq!(generator.unwrap_err(), NoiseError::NanThreshold);
+    /// ```
+    ///
+    /// [`selector()`]: Generator::selector
+    #[inline]
+    fn try_selector<G, I>(self, bands: I) -> Result<adapters::Selector<D, Self, G>, NoiseError>
+    where
+        Self: Sized,
+        G: Generator<D>,
+        I: IntoIterator<Item = (f64, G)>,
+    {
+        adapters::Selector::try_new(self, bands)
+    }
+
     /// Create a generator returning the function value of a provided spline given the result of the
     /// underlying generator as input.
     ///
@@ -842,15 +1855,22 @@ pub trait Generator<const D: usize>: Sized {
     /// <p style="background:rgba(255,181,77,0.16);padding:0.75em;">
     /// <strong>Note:</strong>
     /// This adapter performs sanity checks on whether there are enough knots, whether knot_vector and
-    /// knots are all finite values and of the same length, and whether knot_vector is sorted. Ensure
-    /// inputs are well formed.
+    /// knots are all finite values and of the same length, and whether knot_vector is sorted, and
+    /// panics if any of them fail. Use [`try_spline()`] to handle malformed inputs instead.
     /// </p>
     ///
     /// <p style="background:rgba(122,186,255,0.16);padding:0.75em;">
     /// <strong>Note:</strong>
-    /// Values out of bounds for the spline domain cause the generator to yield NaN.
+    /// Values out of bounds for the spline domain cause the generator to yield NaN by default. This
+    /// can be changed to clamping or linear extrapolation using [`with_extrapolation()`].
     /// </p>
     ///
+    /// The kind of spline is selected through the type parameter `S`. Available are
+    /// [`NaturalCubicSpline`], [`LinearSpline`], [`CatmullRomSpline`], and [`MonotoneCubicSpline`].
+    /// Natural cubic splines may overshoot between knots, whereas linear and monotone cubic splines
+    /// never leave the range spanned by two neighboring knots. For splines with user supplied
+    /// tangents, see [`spline_with()`] and [`HermiteSpline`].
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -867,14 +1887,247 @@ pub trait Generator<const D: usize>: Sized {
     ///
     /// let value = generator.sample(point);    // sample the generator
     /// ```
+    ///
+    /// Clamping out of bounds values to the ends of a monotone spline:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, MonotoneCubicSpline, SplineExtrapolation};
+    /// let generator = Source::constant(3.0)   // build a generator
+    ///     .spline::<MonotoneCubicSpline>(     // apply the adapter
+    ///         &[-1.0, 0.0, 1.0],
+    ///         &[0.0, 0.2, 1.0],
+    ///     )
+    ///     .with_extrapolation(SplineExtrapolation::Clamp);
+    ///
+    /// assert_eq!(generator.sample([0.2, 0.5]), 1.0);
+    /// ```
     /// [`lambda()`]: Generator::lambda
+    /// [`with_extrapolation()`]: adapters::Spline::with_extrapolation
+    /// [`try_spline()`]: Generator::try_spline
+    /// [`spline_with()`]: Generator::spline_with
+    /// [`NaturalCubicSpline`]: adapters::NaturalCubicSpline
+    /// [`LinearSpline`]: adapters::LinearSpline
+    /// [`CatmullRomSpline`]: adapters::CatmullRomSpline
+    /// [`MonotoneCubicSpline`]: adapters::MonotoneCubicSpline
+    /// [`HermiteSpline`]: adapters::HermiteSpline
     #[inline]
     fn spline<S>(self, knot_vector: &[f64], knots: &[f64]) -> adapters::Spline<D, Self, S>
     where
+        Self: Sized,
         S: adapters::SplineImpl,
     {
         adapters::Spline::new(self, knot_vector, knots)
     }
+
+    /// Create a generator applying [`spline()`], after validating the knots.
+    ///
+    /// This adapter is equivalent to [`spline()`], except it returns an error instead of panicking
+    /// if the spline could not be constructed from the given knots.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, NaturalCubicSpline, SplineError};
+    /// let generator = Source::<2>::simplex(42)
+    ///     .try_spline::<NaturalCubicSpline>(&[-1.0, 0.0, 1.0], &[0.0, 0.5, 1.0]);
+    ///
+    /// assert!(matches!(generator, Err(SplineError::NotEnoughKnots { .. })));
+    /// ```
+    ///
+    /// [`spline()`]: Generator::spline
+    #[inline]
+    fn try_spline<S>(
+        self,
+        knot_vector: &[f64],
+        knots: &[f64],
+    ) -> Result<adapters::Spline<D, Self, S>, adapters::SplineError>
+    where
+        Self: Sized,
+        S: adapters::SplineImpl,
+    {
+        adapters::Spline::try_new(self, knot_vector, knots)
+    }
+
+    /// Create a generator returning the function value of an already constructed spline given the
+    /// result of the underlying generator as input.
+    ///
+    /// This adapter is equivalent to [`spline()`], except the spline is passed directly instead of
+    /// being constructed from knots. This is required for splines which need further parameters,
+    /// such as [`HermiteSpline`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, HermiteSpline};
+    /// let point = [0.2, 0.5];
+    ///
+    /// let spline = HermiteSpline::with_tangents(
+    ///     &[-1.0, 0.0, 1.0],
+    ///     &[0.0, 0.5, 1.0],
+    ///     &[0.0, 1.0, 0.0],
+    /// ).unwrap();
+    ///
+    /// let generator = Source::simplex(42)     // build a generator
+    ///     .spline_with(spline);               // apply the adapter
+    ///
+    /// let value = generator.sample(point);    // sample the generator
+    /// ```
+    /// [`spline()`]: Generator::spline
+    /// [`HermiteSpline`]: adapters::HermiteSpline
+    #[inline]
+    fn spline_with<S>(self, spline: S) -> adapters::Spline<D, Self, S>
+    where
+        Self: Sized,
+        S: adapters::SplineImpl,
+    {
+        adapters::Spline::with_spline(self, spline)
+    }
+
+    /// Create a generator which remembers the last point sampled from the underlying generator and
+    /// the corresponding value.
+    ///
+    /// Creates a generator which is exactly the same as the underlying generator, except sampling
+    /// the same point repeatedly evaluates the underlying generator only once. The last sample is
+    /// remembered separately for each thread. Clones of a cached generator share the remembered
+    /// sample. This allows using an expensive generator in several places of a pipeline, such as
+    /// the control generator of both a [`select()`] and a [`blend()`], while evaluating it only
+    /// once per point. This adapter is only available when the `std` feature is enabled.
+    ///
+    /// <p style="background:rgba(122,186,255,0.16);padding:0.75em;">
+    /// <strong>Note:</strong>
+    /// Looking up the remembered sample adds a small overhead to each sample. Caching is thus only
+    /// beneficial for generators which are more expensive than the lookup and are actually sampled
+    /// repeatedly at the same point.
+    /// </p>
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let point = [0.2, 0.5];
+    ///
+    /// let control = Source::simplex(42)       // build a generator
+    ///     .fbm(5, 0.013, 2.0, 0.5)            // apply an expensive adapter
+    ///     .cached();                          // apply the adapter
+    ///
+    /// let generator = Source::perlin(43)
+    ///     .select(Source::worley(44), control.clone(), -0.2, 0.2)
+    ///     .blend(Source::value(45), control); // the control is evaluated once per point
+    ///
+    /// let value = generator.sample(point);    // sample the generator
+    /// ```
+    ///
+    /// [`select()`]: Generator::select
+    /// [`blend()`]: Generator::blend
+    #[cfg(feature = "std")]
+    #[inline]
+    fn cached(self) -> adapters::Cached<D, Self>
+    where
+        Self: Sized,
+    {
+        adapters::Cached::new(self)
+    }
+
+    /// Create a reference-counted handle to the generator, which can be cloned cheaply.
+    ///
+    /// Adapters take ownership of the generators they are composed of. Using the same generator in
+    /// several places of a pipeline thus requires cloning it, which duplicates the entire
+    /// underlying pipeline. Cloning the returned handle instead only increments a reference
+    /// count, while all clones share the same underlying generator. Combine this with [`cached()`]
+    /// to also avoid evaluating the shared generator more than once per point.
+    ///
+    /// <p style="background:rgba(122,186,255,0.16);padding:0.75em;">
+    /// <strong>Note:</strong>
+    /// Like boxed generators, shared generators do not support the arithmetic operators, as their
+    /// type does not determine the dimensionality. Use the corresponding adapters such as
+    /// <code>sum()</code> instead.
+    /// </p>
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let point = [0.2, 0.5];
+    ///
+    /// let base = Source::simplex(42)          // build a generator
+    ///     .fbm(5, 0.013, 2.0, 0.5)            // apply an adapter
+    ///     .shared();                          // apply the adapter
+    ///
+    /// let generator = base
+    ///     .clone()
+    ///     .select(Source::worley(43), base.clone(), -0.2, 0.2);
+    ///
+    /// let value = generator.sample(point);    // sample the generator
+    /// ```
+    ///
+    /// [`cached()`]: Generator::cached
+    #[inline]
+    fn shared(self) -> adapters::Shared<Self>
+    where
+        Self: Sized,
+    {
+        adapters::Shared::new(self)
+    }
+
+    /// Create a boxed trait object from the generator.
+    ///
+    /// This erases the concrete type of the generator, which allows storing generators of different
+    /// types in the same collection, for example to be used with [`selector()`]. Boxed generators
+    /// implement [`Generator`] themselves, and thus support all adapters. Note that sampling a boxed
+    /// generator involves dynamic dispatch, which prevents some compile time optimizations.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let point = [0.2, 0.5];
+    ///
+    /// let generators = vec![
+    ///     Source::simplex(42).boxed(),
+    ///     Source::worley(43).scale([0.5, 0.5]).boxed(),
+    /// ];
+    ///
+    /// assert_eq!(generators[0].sample(point), Source::simplex(42).sample(point));
+    /// ```
+    ///
+    /// [`selector()`]: Generator::selector
+    #[inline]
+    fn boxed<'a>(self) -> Box<dyn Generator<D> + 'a>
+    where
+        Self: Sized + 'a,
+    {
+        Box::new(self)
+    }
+}
+
+impl<const D: usize, G> Generator<D> for Box<G>
+where
+    G: Generator<D> + ?Sized,
+{
+    #[inline]
+    fn sample(&self, point: [f64; D]) -> f64 {
+        (**self).sample(point)
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        (**self).output_range()
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        (**self).describe()
+    }
 }
 
 /// A trait representing the specialization of [`Generator<D>`] for 1-dimensional input spaces.
@@ -910,6 +2163,7 @@ pub trait Generator1D: Generator<1> {
     #[inline]
     fn displace_x<GA>(self, displacement_generator: GA) -> adapters::Displace<1, 0, Self, GA>
     where
+        Self: Sized,
         GA: Generator<1>,
     {
         adapters::Displace::new(self, displacement_generator)
@@ -927,13 +2181,15 @@ pub trait Generator2D: Generator<2> {
     ///
     /// Takes an angle in radians for each unique pair of axes in the input space and crates a
     /// generator which rotates each input point for the provided angle on the plane spanned by each
-    /// axis pair, before passing it to the underlying generator. The specific plane of rotation
-    /// for each angle is as follows:
+    /// axis pair, before passing it to the underlying generator. The rotation is counterclockwise.
+    /// The specific plane of rotation for each angle is as follows:
     ///
     /// | plane of rotation | corresponding angle |
     /// |-------------------|---------------------|
     /// | `xy`-plane        | `rotation[0]`       |
     ///
+    /// The rotation matrix is computed once when creating the generator.
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -948,7 +2204,10 @@ pub trait Generator2D: Generator<2> {
     /// let value = generator.sample(point);    // sample the generator
     /// ```
     #[inline]
-    fn rotate(self, rotation: [f64; 1]) -> adapters::Rotate<2, 1, Self> {
+    fn rotate(self, rotation: [f64; 1]) -> adapters::Rotate<2, 1, Self>
+    where
+        Self: Sized,
+    {
         adapters::Rotate::new(self, rotation)
     }
 
@@ -978,6 +2237,7 @@ pub trait Generator2D: Generator<2> {
     #[inline]
     fn displace_x<GA>(self, displacement_generator: GA) -> adapters::Displace<2, 0, Self, GA>
     where
+        Self: Sized,
         GA: Generator<2>,
     {
         adapters::Displace::new(self, displacement_generator)
@@ -1009,6 +2269,7 @@ pub trait Generator2D: Generator<2> {
     #[inline]
     fn displace_y<GA>(self, displacement_generator: GA) -> adapters::Displace<2, 1, Self, GA>
     where
+        Self: Sized,
         GA: Generator<2>,
     {
         adapters::Displace::new(self, displacement_generator)
@@ -1026,14 +2287,17 @@ pub trait Generator3D: Generator<3> {
     ///
     /// Takes an angle in radians for each unique pair of axes in the input space and crates a
     /// generator which rotates each input point for the provided angle on the plane spanned by each
-    /// axis pair, before passing it to the underlying generator. The specific plane of rotation
-    /// for each angle is as follows:
+    /// axis pair, before passing it to the underlying generator. Each rotation is clockwise, that is,
+    /// by the negated angle, and the rotations are applied to the input point in the following order:
     ///
-    /// | plane of rotation | corresponding angle |
-    /// |-------------------|---------------------|
-    /// | `xy`-plane        | `rotation[0]`       |
-    /// | `yz`-plane        | `rotation[1]`       |
-    /// | `zx`-plane        | `rotation[2]`       |
+    /// | order | plane of rotation | corresponding angle |
+    /// |-------|-------------------|---------------------|
+    /// | 1     | `xy`-plane        | `rotation[2]`       |
+    /// | 2     | `zx`-plane        | `rotation[1]`       |
+    /// | 3     | `yz`-plane        | `rotation[0]`       |
+    ///
+    /// The rotation matrix is computed once when creating the generator. To rotate around an
+    /// arbitrary axis, see [`rotate_quaternion()`] and the constructors of [`Rotate`].
     ///
     /// # Examples
     ///
@@ -1048,11 +2312,57 @@ pub trait Generator3D: Generator<3> {
     ///
     /// let value = generator.sample(point);    // sample the generator
     /// ```
+    ///
+    /// [`rotate_quaternion()`]: Generator3D::rotate_quaternion
+    /// [`Rotate`]: adapters::Rotate
     #[inline]
-    fn rotate(self, rotation: [f64; 3]) -> adapters::Rotate<3, 3, Self> {
+    fn rotate(self, rotation: [f64; 3]) -> adapters::Rotate<3, 3, Self>
+    where
+        Self: Sized,
+    {
         adapters::Rotate::new(self, rotation)
     }
 
+    /// Create a generator which rotates input points by a quaternion before passing them to the
+    /// underlying generator.
+    ///
+    /// Takes a quaternion `[x, y, z, w]`, where `w` is the real part, and creates a generator which
+    /// rotates each input point by the rotation the quaternion represents, before passing it to the
+    /// underlying generator. The quaternion is normalized, so it needs not be of unit length. The
+    /// rotation matrix is computed once when creating the generator.
+    ///
+    /// <p style="background:rgba(255,181,77,0.16);padding:0.75em;">
+    /// <strong>Warning:</strong>
+    /// A quaternion of length zero does not represent a rotation. The generator created by this
+    /// adapter will produce NaN, if this contract is violated.
+    /// </p>
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, Generator3D};
+    /// let point = [0.2, 0.5, 0.3];
+    ///
+    /// // rotate by a quarter turn around the z-axis
+    /// let half_angle = std::f64::consts::FRAC_PI_4;
+    /// let generator = Source::simplex(42)     // build a generator
+    ///     .rotate_quaternion([0.0, 0.0, half_angle.sin(), half_angle.cos()]); // apply the adapter
+    ///
+    /// let value = generator.sample(point);    // sample the generator
+    ///
+    /// // [-0.5, 0.2, 0.3] is equivalent to [0.2, 0.5, 0.3] rotated by 90 degrees around z
+    /// assert!((value - Source::simplex(42).sample([-0.5, 0.2, 0.3])).abs() < 1e-12)
+    /// ```
+    #[inline]
+    fn rotate_quaternion(self, quaternion: [f64; 4]) -> adapters::Rotate<3, 3, Self>
+    where
+        Self: Sized,
+    {
+        adapters::Rotate::from_matrix(self, quaternion_to_matrix(quaternion))
+    }
+
     /// Create a generator providing the results of the underlying generator after displacing the
     /// x-coordinate by the result of the provided generator.
     ///
@@ -1079,6 +2389,7 @@ pub trait Generator3D: Generator<3> {
     #[inline]
     fn displace_x<GA>(self, displacement_generator: GA) -> adapters::Displace<3, 0, Self, GA>
     where
+        Self: Sized,
         GA: Generator<3>,
     {
         adapters::Displace::new(self, displacement_generator)
@@ -1110,6 +2421,7 @@ pub trait Generator3D: Generator<3> {
     #[inline]
     fn displace_y<GA>(self, displacement_generator: GA) -> adapters::Displace<3, 1, Self, GA>
     where
+        Self: Sized,
         GA: Generator<3>,
     {
         adapters::Displace::new(self, displacement_generator)
@@ -1141,6 +2453,7 @@ pub trait Generator3D: Generator<3> {
     #[inline]
     fn displace_z<GA>(self, displacement_generator: GA) -> adapters::Displace<3, 2, Self, GA>
     where
+        Self: Sized,
         GA: Generator<3>,
     {
         adapters::Displace::new(self, displacement_generator)
@@ -1158,7 +2471,10 @@ pub trait Generator4D: Generator<4> {
     ///
     /// <p style="background:rgba(255,181,77,0.16);padding:0.75em;">
     /// <strong>Warning:</strong>
-    /// The correctness of this adapter is untested. It may not work as expected.
+    /// The transformation applied by this adapter is not a proper rotation for all angles. For
+    /// example, it does not preserve the length of input points if only `rotation[3]` is nonzero.
+    /// It is kept unchanged for compatibility with existing generators. For a proper rotation, use
+    /// [`Rotate::from_matrix()`] instead.
     /// </p>
     ///
     /// Takes an angle in radians for each unique pair of axes in the input space and crates a
@@ -1175,6 +2491,8 @@ pub trait Generator4D: Generator<4> {
     /// | `yz`-plane        | `rotation[4]`       |
     /// | `xy`-plane        | `rotation[5]`       |
     ///
+    /// The rotation matrix is computed once when creating the generator.
+    ///
     /// # Examples
     ///
     /// Basic usage:
@@ -1188,8 +2506,13 @@ pub trait Generator4D: Generator<4> {
     ///
     /// let value = generator.sample(point);            // sample the generator
     /// ```
+    ///
+    /// [`Rotate::from_matrix()`]: adapters::Rotate::from_matrix
     #[inline]
-    fn rotate(self, rotation: [f64; 6]) -> adapters::Rotate<4, 6, Self> {
+    fn rotate(self, rotation: [f64; 6]) -> adapters::Rotate<4, 6, Self>
+    where
+        Self: Sized,
+    {
         adapters::Rotate::new(self, rotation)
     }
 
@@ -1219,6 +2542,7 @@ pub trait Generator4D: Generator<4> {
     #[inline]
     fn displace_x<GA>(self, displacement_generator: GA) -> adapters::Displace<4, 0, Self, GA>
     where
+        Self: Sized,
         GA: Generator<4>,
     {
         adapters::Displace::new(self, displacement_generator)
@@ -1250,6 +2574,7 @@ pub trait Generator4D: Generator<4> {
     #[inline]
     fn displace_y<GA>(self, displacement_generator: GA) -> adapters::Displace<4, 1, Self, GA>
     where
+        Self: Sized,
         GA: Generator<4>,
     {
         adapters::Displace::new(self, displacement_generator)
@@ -1281,6 +2606,7 @@ pub trait Generator4D: Generator<4> {
     #[inline]
     fn displace_z<GA>(self, displacement_generator: GA) -> adapters::Displace<4, 2, Self, GA>
     where
+        Self: Sized,
         GA: Generator<4>,
     {
         adapters::Displace::new(self, displacement_generator)
@@ -1312,8 +2638,14 @@ pub trait Generator4D: Generator<4> {
     #[inline]
     fn displace_w<GA>(self, displacement_generator: GA) -> adapters::Displace<4, 3, Self, GA>
     where
+        Self: Sized,
         GA: Generator<4>,
     {
         adapters::Displace::new(self, displacement_generator)
     }
 }
+
+impl<G: Generator<1> + ?Sized> Generator1D for Box<G> {}
+impl<G: Generator<2> + ?Sized> Generator2D for Box<G> {}
+impl<G: Generator<3> + ?Sized> Generator3D for Box<G> {}
+impl<G: Generator<4> + ?Sized> Generator4D for Box<G> {}
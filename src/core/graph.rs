@@ -0,0 +1,182 @@
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::{self, Write};
+
+/// A node of the tree describing a generator pipeline.
+///
+/// This struct is returned by [`describe()`] and represents a single generator, such as a source
+/// or an adapter, together with its parameters and the generators it is composed of. Unlike the
+/// [`Debug`] representation of composite generators, the tree is meant to be read by humans. It
+/// can be printed as an indented tree using its [`Display`] implementation, or exported for
+/// rendering with Graphviz using [`to_dot()`].
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{Source, Generator};
+/// let generator = Source::<2>::simplex(42)
+///     .fbm(3, 0.013, 2.0, 0.5)
+///     .blend(Source::worley(42), Source::constant(0.5));
+///
+/// assert_eq!(
+///     generator.describe().to_string(),
+///     "\
+/// Blend (clamp_control: false)
+/// ├── a: Fbm (octaves: 3, frequency: 0.013, lacunarity: 2.0, persistence: 0.5)
+/// │   └── Simplex (hasher: PermutationTable, seed: 0x86cc7763222724a2, table_size: 256)
+/// ├── b: Worley (hasher: PermutationTable, seed: 0x86cc7763222724a2, table_size: 256)
+/// └── control: Constant (value: 0.5)
+/// "
+/// );
+/// ```
+///
+/// [`describe()`]: crate::Generator::describe
+/// [`Display`]: fmt::Display
+/// [`to_dot()`]: GraphNode::to_dot
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GraphNode {
+    /// The name of the generator, such as `Fbm`.
+    pub name: String,
+    /// The parameters of the generator as pairs of name and formatted value.
+    pub parameters: Vec<(String, String)>,
+    /// The generators this generator is composed of, each with a label describing its role, which
+    /// may be empty.
+    pub children: Vec<(String, GraphNode)>,
+}
+
+impl GraphNode {
+    /// Creates a node with the given name, without parameters or children.
+    #[inline]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            parameters: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Creates a node named after the type `T`, omitting module paths and generic parameters.
+    #[inline]
+    pub fn of_type<T: ?Sized>() -> Self {
+        let name = core::any::type_name::<T>();
+        let name = name.split('<').next().unwrap_or(name);
+        Self::new(name.rsplit("::").next().unwrap_or(name))
+    }
+
+    /// Adds a parameter with the given name, where the value is formatted using its [`Debug`]
+    /// implementation.
+    #[inline]
+    pub fn with_parameter(mut self, name: impl Into<String>, value: impl fmt::Debug) -> Self {
+        self.parameters.push((name.into(), format!("{value:?}")));
+        self
+    }
+
+    /// Adds a child node without a label.
+    #[inline]
+    pub fn with_child(self, child: GraphNode) -> Self {
+        self.with_labeled_child("", child)
+    }
+
+    /// Adds a child node with a label describing its role, such as `control`.
+    #[inline]
+    pub fn with_labeled_child(mut self, label: impl Into<String>, child: GraphNode) -> Self {
+        self.children.push((label.into(), child));
+        self
+    }
+
+    /// Exports the tree in the DOT language, which can be rendered using Graphviz.
+    ///
+    /// Each generator is represented by a box listing its name and parameters, and edges point
+    /// from each generator to the generators it is composed of.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::<2>::checkerboard().add(0.5);
+    ///
+    /// assert_eq!(
+    ///     generator.describe().to_dot(),
+    ///     "\
+    /// digraph {
+    ///     node [shape=box];
+    ///     n0 [label=\"Add\\noffset: 0.5\"];
+    ///     n1 [label=\"Checkerboard\"];
+    ///     n0 -> n1;
+    /// }
+    /// "
+    /// );
+    /// ```
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph {\n    node [shape=box];\n");
+        self.write_dot(&mut dot, &mut 0);
+        dot.push_str("}\n");
+        dot
+    }
+
+    fn write_dot(&self, dot: &mut String, next_id: &mut usize) -> usize {
+        let id = *next_id;
+        *next_id += 1;
+        let label = core::iter::once(self.name.clone())
+            .chain(self.parameters.iter().map(|(k, v)| format!("{k}: {v}")))
+            .map(|line| escape_dot(&line))
+            .collect::<Vec<String>>()
+            .join("\\n");
+        writeln!(dot, "    n{id} [label=\"{label}\"];").unwrap();
+        for (label, child) in &self.children {
+            let child_id = child.write_dot(dot, next_id);
+            match label.is_empty() {
+                true => writeln!(dot, "    n{id} -> n{child_id};").unwrap(),
+                false => writeln!(
+                    dot,
+                    "    n{id} -> n{child_id} [label=\"{}\"];",
+                    escape_dot(label)
+                )
+                .unwrap(),
+            }
+        }
+        id
+    }
+
+    fn fmt_tree(&self, f: &mut fmt::Formatter<'_>, prefix: &str) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if !self.parameters.is_empty() {
+            let parameters = self
+                .parameters
+                .iter()
+                .map(|(k, v)| format!("{k}: {v}"))
+                .collect::<Vec<String>>();
+            write!(f, " ({})", parameters.join(", "))?;
+        }
+        writeln!(f)?;
+        for (i, (label, child)) in self.children.iter().enumerate() {
+            let last = i + 1 == self.children.len();
+            let (branch, indent) = match last {
+                true => ("└── ", "    "),
+                false => ("├── ", "│   "),
+            };
+            write!(f, "{prefix}{branch}")?;
+            if !label.is_empty() {
+                write!(f, "{label}: ")?;
+            }
+            child.fmt_tree(f, &format!("{prefix}{indent}"))?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for GraphNode {
+    /// Formats the tree with one line per generator, where children are indented below their
+    /// parent.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_tree(f, "")
+    }
+}
+
+#[inline]
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
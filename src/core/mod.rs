@@ -1,7 +1,12 @@
 pub mod adapters;
 #[cfg(feature = "dev-tools")]
 pub mod devtools;
+pub mod error;
 pub mod generator;
+pub mod graph;
+mod ops;
+#[cfg(feature = "profile")]
+pub mod profile;
 pub mod source;
 pub mod sources;
 pub mod utils;
@@ -0,0 +1,247 @@
+#[cfg(feature = "std")]
+use crate::core::adapters::Cached;
+#[cfg(feature = "profile")]
+use crate::core::adapters::Instrument;
+use crate::core::{
+    adapters::{
+        Abs, Add, Billow, Blend, Clamp, Difference, Displace, Exp, Fbm, Lambda, Lerp, Max, MaxAll,
+        Min, MinAll, Mul, Neg, Overlay, Pow, Power, Product, ProductAll, Quotient, RidgedMulti,
+        Rotate, Scale, ScaleBias, Screen, Select, Selector, SmoothMax, SmoothMaxAll, SmoothMin,
+        SmoothMinAll, Spline, SplineImpl, Subtraction, Sum, SumAll, Transform, Translate,
+        WeightedSum,
+    },
+    generator::Generator,
+    sources::{Checkerboard, Constant, Custom, ImprovedPerlin, Perlin, Simplex, Value, Worley},
+};
+use core::ops;
+
+/// Implement the arithmetic operators for the given generator types.
+///
+/// Operators between two generators build the corresponding binary adapter. Operators between a
+/// generator and an `f64` build the corresponding scalar adapter, or wrap the `f64` in a
+/// [`Constant`] where no scalar adapter exists.
+macro_rules! impl_ops {
+    ($([$($generics:tt)*] $ty:ty;)*) => {$(
+        impl<const D: usize, $($generics)* Rhs> ops::Add<Rhs> for $ty
+        where
+            Self: Generator<D>,
+            Rhs: Generator<D>,
+        {
+            type Output = Sum<D, Self, Rhs>;
+
+            #[inline]
+            fn add(self, rhs: Rhs) -> Self::Output {
+                Sum::new(self, rhs)
+            }
+        }
+
+        impl<const D: usize, $($generics)* Rhs> ops::Sub<Rhs> for $ty
+        where
+            Self: Generator<D>,
+            Rhs: Generator<D>,
+        {
+            type Output = Subtraction<D, Self, Rhs>;
+
+            #[inline]
+            fn sub(self, rhs: Rhs) -> Self::Output {
+                Subtraction::new(self, rhs)
+            }
+        }
+
+        impl<const D: usize, $($generics)* Rhs> ops::Mul<Rhs> for $ty
+        where
+            Self: Generator<D>,
+            Rhs: Generator<D>,
+        {
+            type Output = Product<D, Self, Rhs>;
+
+            #[inline]
+            fn mul(self, rhs: Rhs) -> Self::Output {
+                Product::new(self, rhs)
+            }
+        }
+
+        impl<const D: usize, $($generics)* Rhs> ops::Div<Rhs> for $ty
+        where
+            Self: Generator<D>,
+            Rhs: Generator<D>,
+        {
+            type Output = Quotient<D, Self, Rhs>;
+
+            #[inline]
+            fn div(self, rhs: Rhs) -> Self::Output {
+                Quotient::new(self, rhs)
+            }
+        }
+
+        impl<const D: usize, $($generics)*> ops::Add<f64> for $ty
+        where
+            Self: Generator<D>,
+        {
+            type Output = Add<D, Self>;
+
+            #[inline]
+            fn add(self, rhs: f64) -> Self::Output {
+                Add::new(self, rhs)
+            }
+        }
+
+        impl<const D: usize, $($generics)*> ops::Sub<f64> for $ty
+        where
+            Self: Generator<D>,
+        {
+            type Output = Add<D, Self>;
+
+            #[inline]
+            fn sub(self, rhs: f64) -> Self::Output {
+                Add::new(self, -rhs)
+            }
+        }
+
+        impl<const D: usize, $($generics)*> ops::Mul<f64> for $ty
+        where
+            Self: Generator<D>,
+        {
+            type Output = Mul<D, Self>;
+
+            #[inline]
+            fn mul(self, rhs: f64) -> Self::Output {
+                Mul::new(self, rhs)
+            }
+        }
+
+        impl<const D: usize, $($generics)*> ops::Div<f64> for $ty
+        where
+            Self: Generator<D>,
+        {
+            type Output = Quotient<D, Self, Constant<D>>;
+
+            #[inline]
+            fn div(self, rhs: f64) -> Self::Output {
+                Quotient::new(self, Constant::new(rhs))
+            }
+        }
+
+        impl<const D: usize, $($generics)*> ops::Add<$ty> for f64
+        where
+            $ty: Generator<D>,
+        {
+            type Output = Add<D, $ty>;
+
+            #[inline]
+            fn add(self, rhs: $ty) -> Self::Output {
+                Add::new(rhs, self)
+            }
+        }
+
+        impl<const D: usize, $($generics)*> ops::Sub<$ty> for f64
+        where
+            $ty: Generator<D>,
+        {
+            type Output = Subtraction<D, Constant<D>, $ty>;
+
+            #[inline]
+            fn sub(self, rhs: $ty) -> Self::Output {
+                Subtraction::new(Constant::new(self), rhs)
+            }
+        }
+
+        impl<const D: usize, $($generics)*> ops::Mul<$ty> for f64
+        where
+            $ty: Generator<D>,
+        {
+            type Output = Mul<D, $ty>;
+
+            #[inline]
+            fn mul(self, rhs: $ty) -> Self::Output {
+                Mul::new(rhs, self)
+            }
+        }
+
+        impl<const D: usize, $($generics)*> ops::Div<$ty> for f64
+        where
+            $ty: Generator<D>,
+        {
+            type Output = Quotient<D, Constant<D>, $ty>;
+
+            #[inline]
+            fn div(self, rhs: $ty) -> Self::Output {
+                Quotient::new(Constant::new(self), rhs)
+            }
+        }
+
+        impl<const D: usize, $($generics)*> ops::Neg for $ty
+        where
+            Self: Generator<D>,
+        {
+            type Output = Neg<D, Self>;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                Neg::new(self)
+            }
+        }
+    )*};
+}
+
+impl_ops! {
+    [] Checkerboard<D>;
+    [] Constant<D>;
+    [N,] Custom<D, N>;
+    [H,] ImprovedPerlin<D, H>;
+    [H,] Perlin<D, H>;
+    [H,] Simplex<D, H>;
+    [H,] Value<D, H>;
+    [H,] Worley<D, H>;
+    [G,] Abs<D, G>;
+    [G,] Add<D, G>;
+    [G,] Billow<D, G>;
+    [GA, GB, GC,] Blend<D, GA, GB, GC>;
+    [G,] Clamp<D, G>;
+    [GA, GB,] Difference<D, GA, GB>;
+    [const A: usize, G, GA,] Displace<D, A, G, GA>;
+    [G,] Exp<D, G>;
+    [G,] Fbm<D, G>;
+    [G, L,] Lambda<D, G, L>;
+    [GA, GB,] Lerp<D, GA, GB>;
+    [GA, GB,] Max<D, GA, GB>;
+    [G,] MaxAll<D, G>;
+    [GA, GB,] Min<D, GA, GB>;
+    [G,] MinAll<D, G>;
+    [G,] Mul<D, G>;
+    [G,] Neg<D, G>;
+    [GA, GB,] Overlay<D, GA, GB>;
+    [G, T,] Pow<D, G, T>;
+    [GA, GB,] Power<D, GA, GB>;
+    [GA, GB,] Product<D, GA, GB>;
+    [G,] ProductAll<D, G>;
+    [GA, GB,] Quotient<D, GA, GB>;
+    [G,] RidgedMulti<D, G>;
+    [const P: usize, G,] Rotate<D, P, G>;
+    [G,] Scale<D, G>;
+    [G,] ScaleBias<D, G>;
+    [GA, GB,] Screen<D, GA, GB>;
+    [GA, GB, GC,] Select<D, GA, GB, GC>;
+    [GC, G,] Selector<D, GC, G>;
+    [GA, GB,] SmoothMax<D, GA, GB>;
+    [G,] SmoothMaxAll<D, G>;
+    [GA, GB,] SmoothMin<D, GA, GB>;
+    [G,] SmoothMinAll<D, G>;
+    [G, S: SplineImpl,] Spline<D, G, S>;
+    [GA, GB,] Subtraction<D, GA, GB>;
+    [GA, GB,] Sum<D, GA, GB>;
+    [G,] SumAll<D, G>;
+    [G,] Transform<D, G>;
+    [G,] Translate<D, G>;
+    [G,] WeightedSum<D, G>;
+}
+
+#[cfg(feature = "std")]
+impl_ops! {
+    [G,] Cached<D, G>;
+}
+
+#[cfg(feature = "profile")]
+impl_ops! {
+    [G,] Instrument<D, G>;
+}
@@ -0,0 +1,213 @@
+//! Profiling of individual parts of generator pipelines.
+//!
+//! This module is only available when the `profile` feature is enabled. Parts of a pipeline can
+//! be marked using [`instrument()`], which records the number of samples and the accumulated
+//! wall time spent in the underlying generator. Measurements are accumulated per thread, such that
+//! sampling on several threads does not contend for a lock, and merged when building a
+//! [`report()`]. Instrumented generators nested within each other are recorded as a tree, where
+//! the time of a node includes the time of its children.
+//!
+//! ```
+//! # use libnoise::{Source, Generator, profile};
+//! let generator = Source::<2>::simplex(42)
+//!     .fbm(5, 0.013, 2.0, 0.5)
+//!     .instrument("mountains")
+//!     .blend(
+//!         Source::worley(42).instrument("caves"),
+//!         Source::constant(0.2),
+//!     )
+//!     .instrument("terrain");
+//!
+//! for x in 0..100 {
+//!     generator.sample([x as f64, 0.0]);
+//! }
+//!
+//! // print a breakdown of where time was spent
+//! println!("{}", profile::report());
+//! ```
+//!
+//! [`instrument()`]: crate::Generator::instrument
+
+use std::{
+    cell::RefCell,
+    collections::BTreeMap,
+    fmt,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::{Duration, Instant},
+};
+
+type Measurements = BTreeMap<Vec<&'static str>, Measurement>;
+
+/// The measurements of all threads which have sampled an instrumented generator. Measurements of
+/// threads which have exited are kept until they are [`reset()`].
+static THREADS: Mutex<Vec<Arc<Mutex<Measurements>>>> = Mutex::new(Vec::new());
+
+thread_local! {
+    /// The names of the instrumented generators currently being sampled on this thread.
+    static CURRENT_PATH: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+
+    /// The measurements of this thread. The lock is only contended while building a report.
+    static MEASUREMENTS: Arc<Mutex<Measurements>> = {
+        let measurements = Arc::default();
+        lock(&THREADS).push(Arc::clone(&measurements));
+        measurements
+    };
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+struct Measurement {
+    samples: u64,
+    time: Duration,
+}
+
+/// Pops the innermost name from the current path when dropped, including when sampling panics.
+struct PathGuard;
+
+impl Drop for PathGuard {
+    fn drop(&mut self) {
+        CURRENT_PATH.with_borrow_mut(|path| path.pop());
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Samples the given closure while recording its wall time for the instrumented generator `name`.
+#[inline]
+pub(crate) fn record(name: &'static str, sample: impl FnOnce() -> f64) -> f64 {
+    CURRENT_PATH.with_borrow_mut(|path| path.push(name));
+    let _guard = PathGuard;
+    let start = Instant::now();
+    let value = sample();
+    let time = start.elapsed();
+    CURRENT_PATH.with_borrow(|path| {
+        MEASUREMENTS.with(|measurements| {
+            let mut measurements = lock(measurements);
+            // avoid allocating a key for every sample
+            if !measurements.contains_key(path.as_slice()) {
+                measurements.insert(path.clone(), Measurement::default());
+            }
+            let measurement = measurements.get_mut(path.as_slice()).unwrap();
+            measurement.samples += 1;
+            measurement.time += time;
+        })
+    });
+    value
+}
+
+/// Returns a snapshot of all measurements recorded so far.
+pub fn report() -> ProfileReport {
+    let mut registry = Measurements::new();
+    for measurements in lock(&THREADS).iter() {
+        for (path, measurement) in lock(measurements).iter() {
+            let merged = registry.entry(path.clone()).or_default();
+            merged.samples += measurement.samples;
+            merged.time += measurement.time;
+        }
+    }
+    let entries = registry
+        .iter()
+        .map(|(path, measurement)| {
+            // children are direct successors in the ordering of the registry
+            let children_time = registry
+                .range(path.clone()..)
+                .skip(1)
+                .take_while(|(other, _)| other.starts_with(path))
+                .filter(|(other, _)| other.len() == path.len() + 1)
+                .map(|(_, child)| child.time)
+                .sum::<Duration>();
+            ProfileEntry {
+                path: path.iter().map(|name| name.to_string()).collect(),
+                samples: measurement.samples,
+                total_time: measurement.time,
+                self_time: measurement.time.saturating_sub(children_time),
+            }
+        })
+        .collect();
+    ProfileReport { entries }
+}
+
+/// Discards all measurements recorded so far.
+pub fn reset() {
+    let mut threads = lock(&THREADS);
+    // drop the measurements of threads which have exited
+    threads.retain(|measurements| Arc::strong_count(measurements) > 1);
+    for measurements in threads.iter() {
+        lock(measurements).clear();
+    }
+}
+
+/// Measurements of a single instrumented generator within the tree of instrumented generators.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProfileEntry {
+    /// The names of the enclosing instrumented generators, starting at the outermost, followed by
+    /// the name of this instrumented generator.
+    pub path: Vec<String>,
+    /// The number of samples taken.
+    pub samples: u64,
+    /// The accumulated wall time of all samples, including nested instrumented generators.
+    pub total_time: Duration,
+    /// The accumulated wall time of all samples, excluding nested instrumented generators.
+    pub self_time: Duration,
+}
+
+/// A snapshot of the measurements of all instrumented generators.
+///
+/// The [`Display`] implementation prints a breakdown of the measurements as a tree, similar to
+/// a flame graph. For visualization with external flame graph tools, see [`folded()`].
+///
+/// [`Display`]: fmt::Display
+/// [`folded()`]: ProfileReport::folded
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProfileReport {
+    /// The measurements of all instrumented generators in depth-first order.
+    pub entries: Vec<ProfileEntry>,
+}
+
+impl ProfileReport {
+    /// Exports the self time of each instrumented generator in microseconds in the folded stack
+    /// format used by flame graph tools such as `inferno` or `flamegraph.pl`.
+    pub fn folded(&self) -> String {
+        self.entries
+            .iter()
+            .map(|entry| {
+                let micros = entry.self_time.as_micros();
+                format!("{} {micros}\n", entry.path.join(";"))
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for ProfileReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let total = self
+            .entries
+            .iter()
+            .filter(|entry| entry.path.len() == 1)
+            .map(|entry| entry.total_time)
+            .sum::<Duration>();
+        writeln!(
+            f,
+            "{:>12} {:>12} {:>7} {:>10}  name",
+            "total", "self", "%", "samples"
+        )?;
+        for entry in &self.entries {
+            let share = match total.is_zero() {
+                true => 0.0,
+                false => entry.total_time.as_secs_f64() / total.as_secs_f64() * 100.0,
+            };
+            writeln!(
+                f,
+                "{:>12} {:>12} {:>6.1}% {:>10}  {}{}",
+                format!("{:.3?}", entry.total_time),
+                format!("{:.3?}", entry.self_time),
+                share,
+                entry.samples,
+                "  ".repeat(entry.path.len() - 1),
+                entry.path.last().unwrap(),
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -1,6 +1,15 @@
-use super::utils::ptable::Seed;
-use crate::core::sources::{
-    Checkerboard, Constant, Custom, ImprovedPerlin, Perlin, Simplex, Value, Worley,
+use super::utils::{
+    hasher::{IntegerHasher, LatticeHasher},
+    ptable::{PermutationTable, Seed},
+};
+use crate::core::{
+    adapters::{MaxAll, MinAll, ProductAll, SmoothMaxAll, SmoothMinAll, SumAll, WeightedSum},
+    error::NoiseError,
+    generator::Generator,
+    sources::{
+        Checkerboard, Constant, Custom, ImprovedPerlin, Perlin, Simplex, Value, Worley,
+        functional::constants::PERMUTATION_TABLE_SIZE,
+    },
 };
 
 /// A struct serving as entry point for building generators.
@@ -40,6 +49,57 @@ use crate::core::sources::{
 /// [`sample()`]: crate::Generator::sample
 pub struct Source<const D: usize>;
 
+/// Options for creating lattice-based noise sources with permutation tables.
+///
+/// These options are passed to the functions of [`Source`] ending in `_with`, such as
+/// [`perlin_with()`]. Options which are not given explicitly can be set to their default using
+/// the struct update syntax.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{Source, SourceOptions, Generator};
+/// // repeat every 4096 instead of every 256 units
+/// let options = SourceOptions {
+///     table_size: 4096,
+///     ..Default::default()
+/// };
+/// let generator = Source::perlin_with(42, options);
+/// let value = generator.sample([0.2, 0.5]);
+/// ```
+///
+/// [`perlin_with()`]: Source::perlin_with
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SourceOptions {
+    /// The number of indices in the permutation table, which is the period after which the noise
+    /// repeats. Larger tables use more memory. Must be within [1, 65536], and defaults to 256.
+    /// Sources created with an invalid size panic, or return [`NoiseError::InvalidTableSize`] when
+    /// created using the `try_*_with()` functions of [`Source`].
+    pub table_size: usize,
+}
+
+impl Default for SourceOptions {
+    fn default() -> Self {
+        Self {
+            table_size: PERMUTATION_TABLE_SIZE,
+        }
+    }
+}
+
+impl SourceOptions {
+    #[inline]
+    fn permutation_table(self, seed: impl Seed) -> PermutationTable {
+        PermutationTable::new(seed, self.table_size, true)
+    }
+
+    #[inline]
+    fn try_permutation_table(self, seed: impl Seed) -> Result<PermutationTable, NoiseError> {
+        PermutationTable::try_new(seed, self.table_size, true)
+    }
+}
+
 impl<const D: usize> Source<D> {
     /// Create a generator which produces the supplied value for every input point.
     ///
@@ -71,7 +131,8 @@ impl<const D: usize> Source<D> {
     ///
     /// <p style="background:rgba(122,186,255,0.16);padding:0.75em;">
     /// <strong>Note:</strong>
-    /// Simplex noise is expected to return a value in the range [-1, 1]. However, for sufficiently
+    /// Simplex noise is expected to return a value in the range [-1, 1]. In 1 dimension, values may
+    /// exceed this range by up to 2e-6, which is reflected by [`output_range()`]. For sufficiently
     /// large inputs (which typically are unreasonable), certain computations may overflow, resulting
     /// in the generator returning NaN instead.
     /// </p>
@@ -85,10 +146,90 @@ impl<const D: usize> Source<D> {
     /// let generator = Source::simplex(42);
     /// let value = generator.sample([0.2, 0.5]);
     /// ```
+    ///
+    /// [`output_range()`]: Generator::output_range
     pub fn simplex(seed: impl Seed) -> Simplex<D> {
         Simplex::new(seed)
     }
 
+    /// Create a generator which produces n-dimensional simplex noise without repetition.
+    ///
+    /// The created generator behaves like the one created by [`simplex()`], except that lattice
+    /// points are hashed using an [`IntegerHasher`] instead of a permutation table. As such, the
+    /// noise does not repeat every 256 units, and the generator is cheap to copy. The noise differs
+    /// from that of [`simplex()`] for the same seed.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::simplex_hashed(42);
+    /// let value = generator.sample([1_000_000.2, -1_000_000.5]);
+    /// ```
+    ///
+    /// [`simplex()`]: Source::simplex
+    /// [`IntegerHasher`]: crate::IntegerHasher
+    pub fn simplex_hashed(seed: impl Seed) -> Simplex<D, IntegerHasher> {
+        Simplex::with_hasher(IntegerHasher::from_seed(seed))
+    }
+
+    /// Create a generator which produces n-dimensional simplex noise using the given options.
+    ///
+    /// The created generator behaves like the one created by [`simplex()`], except that lattice
+    /// points are hashed using a permutation table as configured in `options`. With the default
+    /// options, the generator is identical to the one created by [`simplex()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the table size given in `options` is not within [1, 65536]. See
+    /// [`try_simplex_with()`] for a fallible alternative.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, SourceOptions, Generator};
+    /// let options = SourceOptions { table_size: 1024 };
+    /// let generator = Source::simplex_with(42, options);
+    /// let value = generator.sample([0.2, 0.5]);
+    /// ```
+    ///
+    /// [`simplex()`]: Source::simplex
+    /// [`try_simplex_with()`]: Source::try_simplex_with
+    pub fn simplex_with(seed: impl Seed, options: SourceOptions) -> Simplex<D> {
+        Simplex::with_hasher(options.permutation_table(seed))
+    }
+
+    /// Create a generator which produces n-dimensional simplex noise using the given options,
+    /// after validating the options.
+    ///
+    /// This function is equivalent to [`simplex_with()`], except it returns an error instead of
+    /// panicking if the table size given in `options` is not within [1, 65536].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, SourceOptions, NoiseError};
+    /// let generator = Source::<2>::try_simplex_with(42, SourceOptions { table_size: 1024 });
+    /// assert!(generator.is_ok());
+    ///
+    /// let generator = Source::<2>::try_simplex_with(42, SourceOptions { table_size: 0 });
+    /// assert_eq!(generator.unwrap_err(), NoiseError::InvalidTableSize(0));
+    /// ```
+    ///
+    /// [`simplex_with()`]: Source::simplex_with
+    pub fn try_simplex_with(
+        seed: impl Seed,
+        options: SourceOptions,
+    ) -> Result<Simplex<D>, NoiseError> {
+        Ok(Simplex::with_hasher(options.try_permutation_table(seed)?))
+    }
+
     /// Create a generator which produces n-dimensional value noise.
     ///
     /// The created generator returns n-dimensional value noise. Value noise subdivides the input
@@ -115,6 +256,81 @@ impl<const D: usize> Source<D> {
         Value::new(seed)
     }
 
+    /// Create a generator which produces n-dimensional value noise without repetition.
+    ///
+    /// The created generator behaves like the one created by [`value()`], except that lattice
+    /// points are hashed using an [`IntegerHasher`] instead of a permutation table. As such, the
+    /// noise does not repeat every 256 units, and the generator is cheap to copy. The noise differs
+    /// from that of [`value()`] for the same seed.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::value_hashed(42);
+    /// let value = generator.sample([1_000_000.2, -1_000_000.5]);
+    /// ```
+    ///
+    /// [`value()`]: Source::value
+    /// [`IntegerHasher`]: crate::IntegerHasher
+    pub fn value_hashed(seed: impl Seed) -> Value<D, IntegerHasher> {
+        Value::with_hasher(IntegerHasher::from_seed(seed))
+    }
+
+    /// Create a generator which produces n-dimensional value noise using the given options.
+    ///
+    /// The created generator behaves like the one created by [`value()`], except that lattice
+    /// points are hashed using a permutation table as configured in `options`. With the default
+    /// options, the generator is identical to the one created by [`value()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the table size given in `options` is not within [1, 65536]. See
+    /// [`try_value_with()`] for a fallible alternative.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, SourceOptions, Generator};
+    /// let options = SourceOptions { table_size: 1024 };
+    /// let generator = Source::value_with(42, options);
+    /// let value = generator.sample([0.2, 0.5]);
+    /// ```
+    ///
+    /// [`value()`]: Source::value
+    /// [`try_value_with()`]: Source::try_value_with
+    pub fn value_with(seed: impl Seed, options: SourceOptions) -> Value<D> {
+        Value::with_hasher(options.permutation_table(seed))
+    }
+
+    /// Create a generator which produces n-dimensional value noise using the given options,
+    /// after validating the options.
+    ///
+    /// This function is equivalent to [`value_with()`], except it returns an error instead of
+    /// panicking if the table size given in `options` is not within [1, 65536].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, SourceOptions, NoiseError};
+    /// let generator = Source::<2>::try_value_with(42, SourceOptions { table_size: 1024 });
+    /// assert!(generator.is_ok());
+    ///
+    /// let generator = Source::<2>::try_value_with(42, SourceOptions { table_size: 0 });
+    /// assert_eq!(generator.unwrap_err(), NoiseError::InvalidTableSize(0));
+    /// ```
+    ///
+    /// [`value_with()`]: Source::value_with
+    pub fn try_value_with(seed: impl Seed, options: SourceOptions) -> Result<Value<D>, NoiseError> {
+        Ok(Value::with_hasher(options.try_permutation_table(seed)?))
+    }
+
     /// Create a generator which produces n-dimensional perlin noise.
     ///
     /// The created generator returns n-dimensional perlin noise. Perlin noise is a commonly used
@@ -142,6 +358,84 @@ impl<const D: usize> Source<D> {
         Perlin::new(seed)
     }
 
+    /// Create a generator which produces n-dimensional perlin noise without repetition.
+    ///
+    /// The created generator behaves like the one created by [`perlin()`], except that lattice
+    /// points are hashed using an [`IntegerHasher`] instead of a permutation table. As such, the
+    /// noise does not repeat every 256 units, and the generator is cheap to copy. The noise differs
+    /// from that of [`perlin()`] for the same seed.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::perlin_hashed(42);
+    /// let value = generator.sample([1_000_000.2, -1_000_000.5]);
+    /// ```
+    ///
+    /// [`perlin()`]: Source::perlin
+    /// [`IntegerHasher`]: crate::IntegerHasher
+    pub fn perlin_hashed(seed: impl Seed) -> Perlin<D, IntegerHasher> {
+        Perlin::with_hasher(IntegerHasher::from_seed(seed))
+    }
+
+    /// Create a generator which produces n-dimensional perlin noise using the given options.
+    ///
+    /// The created generator behaves like the one created by [`perlin()`], except that lattice
+    /// points are hashed using a permutation table as configured in `options`. With the default
+    /// options, the generator is identical to the one created by [`perlin()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the table size given in `options` is not within [1, 65536]. See
+    /// [`try_perlin_with()`] for a fallible alternative.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, SourceOptions, Generator};
+    /// let options = SourceOptions { table_size: 1024 };
+    /// let generator = Source::perlin_with(42, options);
+    /// let value = generator.sample([0.2, 0.5]);
+    /// ```
+    ///
+    /// [`perlin()`]: Source::perlin
+    /// [`try_perlin_with()`]: Source::try_perlin_with
+    pub fn perlin_with(seed: impl Seed, options: SourceOptions) -> Perlin<D> {
+        Perlin::with_hasher(options.permutation_table(seed))
+    }
+
+    /// Create a generator which produces n-dimensional perlin noise using the given options,
+    /// after validating the options.
+    ///
+    /// This function is equivalent to [`perlin_with()`], except it returns an error instead of
+    /// panicking if the table size given in `options` is not within [1, 65536].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, SourceOptions, NoiseError};
+    /// let generator = Source::<2>::try_perlin_with(42, SourceOptions { table_size: 1024 });
+    /// assert!(generator.is_ok());
+    ///
+    /// let generator = Source::<2>::try_perlin_with(42, SourceOptions { table_size: 0 });
+    /// assert_eq!(generator.unwrap_err(), NoiseError::InvalidTableSize(0));
+    /// ```
+    ///
+    /// [`perlin_with()`]: Source::perlin_with
+    pub fn try_perlin_with(
+        seed: impl Seed,
+        options: SourceOptions,
+    ) -> Result<Perlin<D>, NoiseError> {
+        Ok(Perlin::with_hasher(options.try_permutation_table(seed)?))
+    }
+
     /// Create a generator which produces n-dimensional improved perlin noise.
     ///
     /// The created generator returns n-dimensional improved perlin noise. Improved perlin noise is a
@@ -157,7 +451,8 @@ impl<const D: usize> Source<D> {
     ///
     /// <p style="background:rgba(122,186,255,0.16);padding:0.75em;">
     /// <strong>Note:</strong>
-    /// Improved perlin noise is expected to return a value in the range [-1, 1].
+    /// Improved perlin noise is expected to return a value in the range [-1, 1]. In 2 dimensions,
+    /// values may exceed this range by up to 5e-6, which is reflected by [`output_range()`].
     /// </p>
     ///
     /// # Examples
@@ -169,10 +464,92 @@ impl<const D: usize> Source<D> {
     /// let generator = Source::improved_perlin(42);
     /// let value = generator.sample([0.2, 0.5]);
     /// ```
+    ///
+    /// [`output_range()`]: Generator::output_range
     pub fn improved_perlin(seed: impl Seed) -> ImprovedPerlin<D> {
         ImprovedPerlin::new(seed)
     }
 
+    /// Create a generator which produces n-dimensional improved perlin noise without repetition.
+    ///
+    /// The created generator behaves like the one created by [`improved_perlin()`], except that lattice
+    /// points are hashed using an [`IntegerHasher`] instead of a permutation table. As such, the
+    /// noise does not repeat every 256 units, and the generator is cheap to copy. The noise differs
+    /// from that of [`improved_perlin()`] for the same seed.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::improved_perlin_hashed(42);
+    /// let value = generator.sample([1_000_000.2, -1_000_000.5]);
+    /// ```
+    ///
+    /// [`improved_perlin()`]: Source::improved_perlin
+    /// [`IntegerHasher`]: crate::IntegerHasher
+    pub fn improved_perlin_hashed(seed: impl Seed) -> ImprovedPerlin<D, IntegerHasher> {
+        ImprovedPerlin::with_hasher(IntegerHasher::from_seed(seed))
+    }
+
+    /// Create a generator which produces n-dimensional improved perlin noise using the given options.
+    ///
+    /// The created generator behaves like the one created by [`improved_perlin()`], except that lattice
+    /// points are hashed using a permutation table as configured in `options`. With the default
+    /// options, the generator is identical to the one created by [`improved_perlin()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the table size given in `options` is not within [1, 65536]. See
+    /// [`try_improved_perlin_with()`] for a fallible alternative.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, SourceOptions, Generator};
+    /// let options = SourceOptions { table_size: 1024 };
+    /// let generator = Source::improved_perlin_with(42, options);
+    /// let value = generator.sample([0.2, 0.5]);
+    /// ```
+    ///
+    /// [`improved_perlin()`]: Source::improved_perlin
+    /// [`try_improved_perlin_with()`]: Source::try_improved_perlin_with
+    pub fn improved_perlin_with(seed: impl Seed, options: SourceOptions) -> ImprovedPerlin<D> {
+        ImprovedPerlin::with_hasher(options.permutation_table(seed))
+    }
+
+    /// Create a generator which produces n-dimensional improved perlin noise using the given
+    /// options, after validating the options.
+    ///
+    /// This function is equivalent to [`improved_perlin_with()`], except it returns an error
+    /// instead of panicking if the table size given in `options` is not within [1, 65536].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, SourceOptions, NoiseError};
+    /// let generator = Source::<2>::try_improved_perlin_with(42, SourceOptions { table_size: 1024 });
+    /// assert!(generator.is_ok());
+    ///
+    /// let generator = Source::<2>::try_improved_perlin_with(42, SourceOptions { table_size: 0 });
+    /// assert_eq!(generator.unwrap_err(), NoiseError::InvalidTableSize(0));
+    /// ```
+    ///
+    /// [`improved_perlin_with()`]: Source::improved_perlin_with
+    pub fn try_improved_perlin_with(
+        seed: impl Seed,
+        options: SourceOptions,
+    ) -> Result<ImprovedPerlin<D>, NoiseError> {
+        Ok(ImprovedPerlin::with_hasher(
+            options.try_permutation_table(seed)?,
+        ))
+    }
+
     /// Create a generator which produces n-dimensional worley noise.
     ///
     /// The created generator returns n-dimensional worley noise (also called cell noise, cellular
@@ -207,6 +584,84 @@ impl<const D: usize> Source<D> {
         Worley::new(seed)
     }
 
+    /// Create a generator which produces n-dimensional worley noise without repetition.
+    ///
+    /// The created generator behaves like the one created by [`worley()`], except that lattice
+    /// points are hashed using an [`IntegerHasher`] instead of a permutation table. As such, the
+    /// noise does not repeat every 256 units, and the generator is cheap to copy. The noise differs
+    /// from that of [`worley()`] for the same seed.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let generator = Source::worley_hashed(42);
+    /// let value = generator.sample([1_000_000.2, -1_000_000.5]);
+    /// ```
+    ///
+    /// [`worley()`]: Source::worley
+    /// [`IntegerHasher`]: crate::IntegerHasher
+    pub fn worley_hashed(seed: impl Seed) -> Worley<D, IntegerHasher> {
+        Worley::with_hasher(IntegerHasher::from_seed(seed))
+    }
+
+    /// Create a generator which produces n-dimensional worley noise using the given options.
+    ///
+    /// The created generator behaves like the one created by [`worley()`], except that lattice
+    /// points are hashed using a permutation table as configured in `options`. With the default
+    /// options, the generator is identical to the one created by [`worley()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the table size given in `options` is not within [1, 65536]. See
+    /// [`try_worley_with()`] for a fallible alternative.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, SourceOptions, Generator};
+    /// let options = SourceOptions { table_size: 1024 };
+    /// let generator = Source::worley_with(42, options);
+    /// let value = generator.sample([0.2, 0.5]);
+    /// ```
+    ///
+    /// [`worley()`]: Source::worley
+    /// [`try_worley_with()`]: Source::try_worley_with
+    pub fn worley_with(seed: impl Seed, options: SourceOptions) -> Worley<D> {
+        Worley::with_hasher(options.permutation_table(seed))
+    }
+
+    /// Create a generator which produces n-dimensional worley noise using the given options,
+    /// after validating the options.
+    ///
+    /// This function is equivalent to [`worley_with()`], except it returns an error instead of
+    /// panicking if the table size given in `options` is not within [1, 65536].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, SourceOptions, NoiseError};
+    /// let generator = Source::<2>::try_worley_with(42, SourceOptions { table_size: 1024 });
+    /// assert!(generator.is_ok());
+    ///
+    /// let generator = Source::<2>::try_worley_with(42, SourceOptions { table_size: 0 });
+    /// assert_eq!(generator.unwrap_err(), NoiseError::InvalidTableSize(0));
+    /// ```
+    ///
+    /// [`worley_with()`]: Source::worley_with
+    pub fn try_worley_with(
+        seed: impl Seed,
+        options: SourceOptions,
+    ) -> Result<Worley<D>, NoiseError> {
+        Ok(Worley::with_hasher(options.try_permutation_table(seed)?))
+    }
+
     /// Create a generator which produces an n-dimensional checkerboard pattern.
     ///
     /// The created generator returns n-dimensional checkerboard pattern. That is, the input space
@@ -245,4 +700,255 @@ impl<const D: usize> Source<D> {
     pub fn custom<F: Fn([f64; D]) -> f64>(f: F) -> Custom<D, F> {
         Custom::new(f)
     }
+
+    /// Create a generator adding the results of all generators of a collection.
+    ///
+    /// The created generator returns the sum of the results of all `generators` for the same input
+    /// point, or 0 if the collection is empty. All generators must be of the same type. Use
+    /// [`boxed()`] to combine generators of different types, such as when building the collection at
+    /// runtime. Unlike chaining the binary adapter, this does not nest a generic type per generator.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let point = [0.2, 0.5];
+    ///
+    /// let generator = Source::sum_all([Source::simplex(42), Source::simplex(43)]);
+    ///
+    /// assert_eq!(
+    ///     generator.sample(point),
+    ///     Source::simplex(42).sample(point) + Source::simplex(43).sample(point)
+    /// );
+    /// ```
+    ///
+    /// [`boxed()`]: crate::Generator::boxed
+    pub fn sum_all<G, I>(generators: I) -> SumAll<D, G>
+    where
+        G: Generator<D>,
+        I: IntoIterator<Item = G>,
+    {
+        SumAll::new(generators)
+    }
+
+    /// Create a generator multiplying the results of all generators of a collection.
+    ///
+    /// The created generator returns the product of the results of all `generators` for the same input
+    /// point, or 1 if the collection is empty. All generators must be of the same type. Use
+    /// [`boxed()`] to combine generators of different types, such as when building the collection at
+    /// runtime. Unlike chaining the binary adapter, this does not nest a generic type per generator.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let point = [0.2, 0.5];
+    ///
+    /// let generator = Source::product_all([Source::simplex(42), Source::simplex(43)]);
+    ///
+    /// assert_eq!(
+    ///     generator.sample(point),
+    ///     Source::simplex(42).sample(point) * Source::simplex(43).sample(point)
+    /// );
+    /// ```
+    ///
+    /// [`boxed()`]: crate::Generator::boxed
+    pub fn product_all<G, I>(generators: I) -> ProductAll<D, G>
+    where
+        G: Generator<D>,
+        I: IntoIterator<Item = G>,
+    {
+        ProductAll::new(generators)
+    }
+
+    /// Create a generator producing the minimum of the results of all generators of a collection.
+    ///
+    /// The created generator returns the minimum of the results of all `generators` for the same input
+    /// point, or positive infinity if the collection is empty. All generators must be of the same
+    /// type. Use [`boxed()`] to combine generators of different types, such as when building the
+    /// collection at runtime. Unlike chaining the binary adapter, this does not nest a generic type
+    /// per generator.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let point = [0.2, 0.5];
+    ///
+    /// let generator = Source::min_all([
+    ///     Source::simplex(42).boxed(),
+    ///     Source::worley(43).boxed(),
+    ///     Source::constant(0.5).boxed(),
+    /// ]);
+    ///
+    /// let value = generator.sample(point);
+    /// assert!(value <= 0.5);
+    /// ```
+    ///
+    /// [`boxed()`]: crate::Generator::boxed
+    pub fn min_all<G, I>(generators: I) -> MinAll<D, G>
+    where
+        G: Generator<D>,
+        I: IntoIterator<Item = G>,
+    {
+        MinAll::new(generators)
+    }
+
+    /// Create a generator producing the maximum of the results of all generators of a collection.
+    ///
+    /// The created generator returns the maximum of the results of all `generators` for the same input
+    /// point, or negative infinity if the collection is empty. All generators must be of the same
+    /// type. Use [`boxed()`] to combine generators of different types, such as when building the
+    /// collection at runtime. Unlike chaining the binary adapter, this does not nest a generic type
+    /// per generator.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let point = [0.2, 0.5];
+    ///
+    /// let generator = Source::max_all([
+    ///     Source::simplex(42).boxed(),
+    ///     Source::worley(43).boxed(),
+    ///     Source::constant(0.5).boxed(),
+    /// ]);
+    ///
+    /// let value = generator.sample(point);
+    /// assert!(value >= 0.5);
+    /// ```
+    ///
+    /// [`boxed()`]: crate::Generator::boxed
+    pub fn max_all<G, I>(generators: I) -> MaxAll<D, G>
+    where
+        G: Generator<D>,
+        I: IntoIterator<Item = G>,
+    {
+        MaxAll::new(generators)
+    }
+
+    /// Create a generator adding the weighted results of all generators of a collection.
+    ///
+    /// The created generator returns the sum of the results of all generators for the same input
+    /// point, each multiplied by its weight, or 0 if the collection is empty. `weighted_generators` is
+    /// a collection of pairs of a weight and a generator. All generators must be of the same type. Use
+    /// [`boxed()`] to combine generators of different types, such as when building the collection at
+    /// runtime. Unlike chaining the binary adapter, this does not nest a generic type per generator.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let point = [0.2, 0.5];
+    ///
+    /// let generator = Source::weighted_sum([
+    ///     (0.75, Source::simplex(42).boxed()),
+    ///     (0.25, Source::simplex(43).scale([4.0, 4.0]).boxed()),
+    /// ]);
+    ///
+    /// let value = generator.sample(point);
+    /// ```
+    ///
+    /// [`boxed()`]: crate::Generator::boxed
+    pub fn weighted_sum<G, I>(weighted_generators: I) -> WeightedSum<D, G>
+    where
+        G: Generator<D>,
+        I: IntoIterator<Item = (f64, G)>,
+    {
+        WeightedSum::new(weighted_generators)
+    }
+
+    /// Create a generator producing the smooth minimum of the results of all generators of a
+    /// collection.
+    ///
+    /// The created generator returns the smooth minimum of the results of all `generators` for the
+    /// same input point, or positive infinity if the collection is empty. Where results differ by less
+    /// than `radius`, they are blended using a quadratic polynomial, removing the creases produced by
+    /// a hard minimum. Elsewhere, the result equals the minimum. A `radius` of 0 or less is equivalent
+    /// to [`min_all()`]. All generators must be of the same type. Use [`boxed()`] to combine
+    /// generators of different types, such as when building the collection at runtime. Unlike chaining
+    /// the binary adapter, this does not nest a generic type per generator.
+    ///
+    /// <p style="background:rgba(122,186,255,0.16);padding:0.75em;">
+    /// <strong>Note:</strong>
+    /// The smooth minimum is applied pairwise in the order of the collection. As it is not
+    /// associative, reordering the collection may slightly change the result within the blending
+    /// radius.
+    /// </p>
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let point = [0.2, 0.5];
+    ///
+    /// let generator = Source::smooth_min_all([Source::simplex(42), Source::simplex(43)], 0.25);
+    ///
+    /// let value = generator.sample(point);
+    /// assert!(value <= Source::min_all([Source::simplex(42), Source::simplex(43)]).sample(point));
+    /// ```
+    ///
+    /// [`boxed()`]: crate::Generator::boxed
+    /// [`min_all()`]: Source::min_all
+    pub fn smooth_min_all<G, I>(generators: I, radius: f64) -> SmoothMinAll<D, G>
+    where
+        G: Generator<D>,
+        I: IntoIterator<Item = G>,
+    {
+        SmoothMinAll::new(generators, radius)
+    }
+
+    /// Create a generator producing the smooth maximum of the results of all generators of a
+    /// collection.
+    ///
+    /// The created generator returns the smooth maximum of the results of all `generators` for the
+    /// same input point, or negative infinity if the collection is empty. Where results differ by less
+    /// than `radius`, they are blended using a quadratic polynomial, removing the creases produced by
+    /// a hard maximum. Elsewhere, the result equals the maximum. A `radius` of 0 or less is equivalent
+    /// to [`max_all()`]. All generators must be of the same type. Use [`boxed()`] to combine
+    /// generators of different types, such as when building the collection at runtime. Unlike chaining
+    /// the binary adapter, this does not nest a generic type per generator.
+    ///
+    /// <p style="background:rgba(122,186,255,0.16);padding:0.75em;">
+    /// <strong>Note:</strong>
+    /// The smooth maximum is applied pairwise in the order of the collection. As it is not
+    /// associative, reordering the collection may slightly change the result within the blending
+    /// radius.
+    /// </p>
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator};
+    /// let point = [0.2, 0.5];
+    ///
+    /// let generator = Source::smooth_max_all([Source::simplex(42), Source::simplex(43)], 0.25);
+    ///
+    /// let value = generator.sample(point);
+    /// assert!(value >= Source::max_all([Source::simplex(42), Source::simplex(43)]).sample(point));
+    /// ```
+    ///
+    /// [`boxed()`]: crate::Generator::boxed
+    /// [`max_all()`]: Source::max_all
+    pub fn smooth_max_all<G, I>(generators: I, radius: f64) -> SmoothMaxAll<D, G>
+    where
+        G: Generator<D>,
+        I: IntoIterator<Item = G>,
+    {
+        SmoothMaxAll::new(generators, radius)
+    }
 }
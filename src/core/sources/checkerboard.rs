@@ -1,5 +1,8 @@
 use super::functional;
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+};
 
 /// A generator which produces an n-dimensional checkerboard pattern.
 ///
@@ -50,6 +53,16 @@ impl Generator<1> for Checkerboard<1> {
     fn sample(&self, point: [f64; 1]) -> f64 {
         functional::checkerboard::noise1d(point)
     }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        Some((-1.0, 1.0))
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Checkerboard")
+    }
 }
 
 impl Generator<2> for Checkerboard<2> {
@@ -57,6 +70,16 @@ impl Generator<2> for Checkerboard<2> {
     fn sample(&self, point: [f64; 2]) -> f64 {
         functional::checkerboard::noise2d(point)
     }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        Some((-1.0, 1.0))
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Checkerboard")
+    }
 }
 
 impl Generator<3> for Checkerboard<3> {
@@ -64,6 +87,16 @@ impl Generator<3> for Checkerboard<3> {
     fn sample(&self, point: [f64; 3]) -> f64 {
         functional::checkerboard::noise3d(point)
     }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        Some((-1.0, 1.0))
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Checkerboard")
+    }
 }
 
 impl Generator<4> for Checkerboard<4> {
@@ -71,4 +104,14 @@ impl Generator<4> for Checkerboard<4> {
     fn sample(&self, point: [f64; 4]) -> f64 {
         functional::checkerboard::noise4d(point)
     }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        Some((-1.0, 1.0))
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Checkerboard")
+    }
 }
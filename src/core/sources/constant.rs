@@ -1,4 +1,8 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+    utils::math::interval,
+};
 
 /// A generator which produces the supplied value for every input point.
 ///
@@ -41,4 +45,14 @@ impl<const D: usize> Generator<D> for Constant<D> {
     fn sample(&self, _point: [f64; D]) -> f64 {
         self.value
     }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        interval::new(self.value, self.value)
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Constant").with_parameter("value", self.value)
+    }
 }
@@ -1,4 +1,7 @@
-use crate::core::generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D};
+use crate::core::{
+    generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
+    graph::GraphNode,
+};
 
 /// A generator which produces n-dimensional values based on the provided closure.
 ///
@@ -41,4 +44,9 @@ impl<const D: usize, N: Fn([f64; D]) -> f64> Generator<D> for Custom<D, N> {
     fn sample(&self, point: [f64; D]) -> f64 {
         (self.noise)(point)
     }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        GraphNode::new("Custom")
+    }
 }
@@ -1,20 +1,25 @@
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
 pub(crate) fn noise1d(point: [f64; 1]) -> f64 {
-    ((point[0] as isize & 1) as f64).mul_add(2.0, -1.0)
+    parity(point[0]) as f64 * 2.0 - 1.0
 }
 
 pub(crate) fn noise2d(point: [f64; 2]) -> f64 {
-    (((point[0] as isize & 1) ^ (point[1] as isize & 1)) as f64).mul_add(2.0, -1.0)
+    (parity(point[0]) ^ parity(point[1])) as f64 * 2.0 - 1.0
 }
 
 pub(crate) fn noise3d(point: [f64; 3]) -> f64 {
-    (((point[0] as isize & 1) ^ (point[1] as isize & 1) ^ (point[2] as isize & 1)) as f64)
-        .mul_add(2.0, -1.0)
+    (parity(point[0]) ^ parity(point[1]) ^ parity(point[2])) as f64 * 2.0 - 1.0
 }
 
 pub(crate) fn noise4d(point: [f64; 4]) -> f64 {
-    (((point[0] as isize & 1)
-        ^ (point[1] as isize & 1)
-        ^ (point[2] as isize & 1)
-        ^ (point[3] as isize & 1)) as f64)
-        .mul_add(2.0, -1.0)
+    (parity(point[0]) ^ parity(point[1]) ^ parity(point[2]) ^ parity(point[3])) as f64 * 2.0 - 1.0
+}
+
+#[inline]
+fn parity(x: f64) -> i64 {
+    // floor before casting, as casting truncates toward zero, and use 64-bit lattice coordinates
+    // regardless of the target
+    x.floor() as i64 & 1
 }
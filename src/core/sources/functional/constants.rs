@@ -103,6 +103,8 @@ pub(crate) const CORNERPOINT_GRADIENT_LUT_4D: [[f64; 4]; CORNERPOINT_GRADIENT_LU
 
 pub(crate) const SIMPLEX_R_SQUARED: f64 = 0.5;
 pub(crate) const SIMPLEX_NORMALIZATION_FACTOR_1D: f64 = 13.591804446852795;
+// the 1D normalization factor slightly overshoots, so peaks exceed [-1, 1] by about 1.8e-6
+pub(crate) const SIMPLEX_OUTPUT_BOUND_1D: f64 = 1.000002;
 pub(crate) const SIMPLEX_SKEW_FACTOR_2D: f64 = 0.3660254037844386;
 pub(crate) const SIMPLEX_UNSKEW_FACTOR_2D: f64 = 0.21132486540518713;
 pub(crate) const SIMPLEX_NORMALIZATION_FACTOR_2D: f64 = 99.83685446303647;
@@ -188,3 +190,6 @@ pub(crate) const SIMPLEX_TRAVERSAL_LUT_4D: [[usize; 12]; 64] = [
     [1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 0, 1], // 62: xywz
     [1, 0, 0, 0, 1, 1, 0, 0, 1, 1, 1, 0], // 63: xyzw
 ];
+
+// the 2D scale of improved perlin noise slightly overshoots, so peaks exceed [-1, 1] by about 4.7e-6
+pub(crate) const IMPROVED_PERLIN_OUTPUT_BOUND_2D: f64 = 1.000005;
@@ -1,10 +1,12 @@
 use super::constants::*;
 use crate::core::utils::{
+    hasher::LatticeHasher,
     math::{Vec2, Vec3, Vec4},
-    ptable::PermutationTable,
 };
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
 
-pub(crate) fn noise1d(perm: &PermutationTable, point: [f64; 1]) -> f64 {
+pub(crate) fn noise1d(perm: &impl LatticeHasher, point: [f64; 1]) -> f64 {
     let x = point[0];
     // origin of hypercube in which input lies
     let x0 = x.floor();
@@ -12,9 +14,9 @@ pub(crate) fn noise1d(perm: &PermutationTable, point: [f64; 1]) -> f64 {
     let dx = x - x0;
     let dxs = smoothstep_5(dx);
     // get sign from hashes
-    let x0 = x0.rem_euclid(PERMUTATION_TABLE_SIZE as f64) as usize;
-    let sign0 = ((unsafe { perm.hash1d(x0) } % 2) as f64).mul_add(2.0, -1.0);
-    let sign1 = ((unsafe { perm.hash1d(x0 + 1) } % 2) as f64).mul_add(2.0, -1.0);
+    let x0 = perm.index(x0);
+    let sign0 = ((unsafe { perm.hash1d(x0) } % 2) as f64) * 2.0 - 1.0;
+    let sign1 = ((unsafe { perm.hash1d(x0 + 1) } % 2) as f64) * 2.0 - 1.0;
     // compute contributions
     let n0 = sign0 * dx;
     let n1 = sign1 * (dx - 1.0);
@@ -22,7 +24,7 @@ pub(crate) fn noise1d(perm: &PermutationTable, point: [f64; 1]) -> f64 {
     lerp(n0, n1, dxs) * 2.0
 }
 
-pub(crate) fn noise2d(perm: &PermutationTable, point: [f64; 2]) -> f64 {
+pub(crate) fn noise2d(perm: &impl LatticeHasher, point: [f64; 2]) -> f64 {
     let x = Vec2::from(point);
     // origin of hypercube in which input lies
     let x0 = x.floor();
@@ -30,7 +32,7 @@ pub(crate) fn noise2d(perm: &PermutationTable, point: [f64; 2]) -> f64 {
     let dx = x - x0;
     let dxs = dx.map(smoothstep_5);
     // hashed gradient indices
-    let x0 = x0.cast().rem_euclid(PERMUTATION_TABLE_SIZE);
+    let x0 = x0.map_into(|x| perm.index(x));
     let gi00 = unsafe { perm.hash2d(x0.x, x0.y) } % MIDPOINT_GRADIENT_LUT_2D_SIZE;
     let gi01 = unsafe { perm.hash2d(x0.x, x0.y + 1) } % MIDPOINT_GRADIENT_LUT_2D_SIZE;
     let gi10 = unsafe { perm.hash2d(x0.x + 1, x0.y) } % MIDPOINT_GRADIENT_LUT_2D_SIZE;
@@ -45,7 +47,7 @@ pub(crate) fn noise2d(perm: &PermutationTable, point: [f64; 2]) -> f64 {
     lerp(xn0, xn1, dxs.y) * 1.868202396614395
 }
 
-pub(crate) fn noise3d(perm: &PermutationTable, point: [f64; 3]) -> f64 {
+pub(crate) fn noise3d(perm: &impl LatticeHasher, point: [f64; 3]) -> f64 {
     let x = Vec3::from(point);
     // origin of hypercube in which input lies
     let x0 = x.floor();
@@ -53,7 +55,7 @@ pub(crate) fn noise3d(perm: &PermutationTable, point: [f64; 3]) -> f64 {
     let dx = x - x0;
     let dxs = dx.map(smoothstep_5);
     // hashed gradient indices
-    let x0 = x0.cast().rem_euclid(PERMUTATION_TABLE_SIZE);
+    let x0 = x0.map_into(|x| perm.index(x));
     let gi000 = unsafe { perm.hash3d(x0.x, x0.y, x0.z) } % MIDPOINT_GRADIENT_LUT_3D_SIZE;
     let gi001 = unsafe { perm.hash3d(x0.x, x0.y, x0.z + 1) } % MIDPOINT_GRADIENT_LUT_3D_SIZE;
     let gi010 = unsafe { perm.hash3d(x0.x, x0.y + 1, x0.z) } % MIDPOINT_GRADIENT_LUT_3D_SIZE;
@@ -82,7 +84,7 @@ pub(crate) fn noise3d(perm: &PermutationTable, point: [f64; 3]) -> f64 {
     lerp(yn0, yn1, dxs.z) * 0.9714130038529027
 }
 
-pub(crate) fn noise4d(perm: &PermutationTable, point: [f64; 4]) -> f64 {
+pub(crate) fn noise4d(perm: &impl LatticeHasher, point: [f64; 4]) -> f64 {
     let x = Vec4::from(point);
     // origin of hypercube in which input lies
     let x0 = x.floor();
@@ -90,7 +92,7 @@ pub(crate) fn noise4d(perm: &PermutationTable, point: [f64; 4]) -> f64 {
     let dx = x - x0;
     let dxs = dx.map(smoothstep_5);
     // hashed gradient indices
-    let x0 = x0.cast().rem_euclid(PERMUTATION_TABLE_SIZE);
+    let x0 = x0.map_into(|x| perm.index(x));
     let gi0000 = unsafe { perm.hash4d(x0.x, x0.y, x0.z, x0.w) } % MIDPOINT_GRADIENT_LUT_4D_SIZE;
     let gi0001 = unsafe { perm.hash4d(x0.x, x0.y, x0.z, x0.w + 1) } % MIDPOINT_GRADIENT_LUT_4D_SIZE;
     let gi0010 = unsafe { perm.hash4d(x0.x, x0.y, x0.z + 1, x0.w) } % MIDPOINT_GRADIENT_LUT_4D_SIZE;
@@ -1,10 +1,12 @@
 use super::constants::*;
 use crate::core::utils::{
+    hasher::{LatticeHasher, LatticeHasherExt},
     math::{Vec2, Vec3, Vec4},
-    ptable::PermutationTable,
 };
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
 
-pub(crate) fn noise1d(perm: &PermutationTable, point: [f64; 1]) -> f64 {
+pub(crate) fn noise1d(perm: &impl LatticeHasher, point: [f64; 1]) -> f64 {
     let x = point[0];
     // no transformation into lattice space required, get cube origin
     let i0 = x.floor();
@@ -12,7 +14,7 @@ pub(crate) fn noise1d(perm: &PermutationTable, point: [f64; 1]) -> f64 {
     let x0 = x - i0;
     let x1 = x0 - 1.0;
     // hashed gradient (-1 or 1) directly
-    let i0 = i0.rem_euclid(PERMUTATION_TABLE_SIZE as f64) as usize;
+    let i0 = perm.index(i0);
     let gi0 = unsafe { perm.hash1d(i0) % GRADIENT_LUT_1D_SIZE };
     let gi1 = unsafe { perm.hash1d(i0 + 1) % GRADIENT_LUT_1D_SIZE };
     // compute contributions
@@ -22,7 +24,7 @@ pub(crate) fn noise1d(perm: &PermutationTable, point: [f64; 1]) -> f64 {
     (n0 + n1) * SIMPLEX_NORMALIZATION_FACTOR_1D
 }
 
-pub(crate) fn noise2d(perm: &PermutationTable, point: [f64; 2]) -> f64 {
+pub(crate) fn noise2d(perm: &impl LatticeHasher, point: [f64; 2]) -> f64 {
     let x = Vec2::from(point);
     // transform into lattice space and floor for cube origin
     let is = (x + x.sum() * SIMPLEX_SKEW_FACTOR_2D).floor();
@@ -38,7 +40,7 @@ pub(crate) fn noise2d(perm: &PermutationTable, point: [f64; 2]) -> f64 {
     let x1 = x0 - i1.cast() + SIMPLEX_UNSKEW_FACTOR_2D;
     let x2 = x0 - 1.0 + 2.0 * SIMPLEX_UNSKEW_FACTOR_2D;
     // hashed gradient indices
-    let is = is.rem_euclid(PERMUTATION_TABLE_SIZE as f64).cast();
+    let is = is.map_into(|x| perm.index(x));
     let gi0 = unsafe { perm.hash2d(is.x, is.y) } % MIDPOINT_GRADIENT_LUT_2D_SIZE;
     let gi1 = unsafe { perm.hash2d(is.x + i1.x, is.y + i1.y) } % MIDPOINT_GRADIENT_LUT_2D_SIZE;
     let gi2 = unsafe { perm.hash2d(is.x + 1, is.y + 1) } % MIDPOINT_GRADIENT_LUT_2D_SIZE;
@@ -50,7 +52,7 @@ pub(crate) fn noise2d(perm: &PermutationTable, point: [f64; 2]) -> f64 {
     (n0 + n1 + n2) * SIMPLEX_NORMALIZATION_FACTOR_2D
 }
 
-pub(crate) fn noise3d(perm: &PermutationTable, point: [f64; 3]) -> f64 {
+pub(crate) fn noise3d(perm: &impl LatticeHasher, point: [f64; 3]) -> f64 {
     let x = Vec3::from(point);
     // transform into lattice space and floor for cube origin
     let is = (x + x.sum() * SIMPLEX_SKEW_FACTOR_3D).floor();
@@ -73,7 +75,7 @@ pub(crate) fn noise3d(perm: &PermutationTable, point: [f64; 3]) -> f64 {
     let x2 = x0 - i2.cast() + 2.0 * SIMPLEX_UNSKEW_FACTOR_3D;
     let x3 = x0 - 1.0 + 3.0 * SIMPLEX_UNSKEW_FACTOR_3D;
     // hashed gradient indices
-    let is = is.cast().rem_euclid(PERMUTATION_TABLE_SIZE);
+    let is = is.map_into(|x| perm.index(x));
     let gi0 = unsafe { perm.hash3d_vec(is) } % MIDPOINT_GRADIENT_LUT_3D_SIZE;
     let gi1 = unsafe { perm.hash3d_vec(is + i1) } % MIDPOINT_GRADIENT_LUT_3D_SIZE;
     let gi2 = unsafe { perm.hash3d_vec(is + i2) } % MIDPOINT_GRADIENT_LUT_3D_SIZE;
@@ -87,7 +89,7 @@ pub(crate) fn noise3d(perm: &PermutationTable, point: [f64; 3]) -> f64 {
     (n0 + n1 + n2 + n3) * SIMPLEX_NORMALIZATION_FACTOR_3D
 }
 
-pub(crate) fn noise4d(perm: &PermutationTable, point: [f64; 4]) -> f64 {
+pub(crate) fn noise4d(perm: &impl LatticeHasher, point: [f64; 4]) -> f64 {
     let x = Vec4::from(point);
     // transform into lattice space and floor for cube origin
     let is = (x + x.sum() * SIMPLEX_SKEW_FACTOR_4D).floor();
@@ -124,7 +126,7 @@ pub(crate) fn noise4d(perm: &PermutationTable, point: [f64; 4]) -> f64 {
     let x3 = x0 - i3.cast() + 3.0 * SIMPLEX_UNSKEW_FACTOR_4D;
     let x4 = x0 - 1.0 + 4.0 * SIMPLEX_UNSKEW_FACTOR_4D;
     // hashed gradient indices
-    let is = is.cast().rem_euclid(PERMUTATION_TABLE_SIZE);
+    let is = is.map_into(|x| perm.index(x));
     let gi0 = unsafe { perm.hash4d_vec(is) } % MIDPOINT_GRADIENT_LUT_4D_SIZE;
     let gi1 = unsafe { perm.hash4d_vec(is + i1) } % MIDPOINT_GRADIENT_LUT_4D_SIZE;
     let gi2 = unsafe { perm.hash4d_vec(is + i2) } % MIDPOINT_GRADIENT_LUT_4D_SIZE;
@@ -142,7 +144,7 @@ pub(crate) fn noise4d(perm: &PermutationTable, point: [f64; 4]) -> f64 {
 
 unsafe fn contribution1d(x: f64, gi: usize) -> f64 {
     unsafe {
-        if x.abs() >= std::f64::consts::FRAC_1_SQRT_2 {
+        if x.abs() >= core::f64::consts::FRAC_1_SQRT_2 {
             0.0
         } else {
             let mut t = SIMPLEX_R_SQUARED - x * x;
@@ -1,32 +1,33 @@
-use super::constants::PERMUTATION_TABLE_SIZE;
 use crate::core::utils::{
+    hasher::LatticeHasher,
     math::{Vec2, Vec3, Vec4},
-    ptable::PermutationTable,
 };
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
 
-pub(crate) fn noise1d(perm: &PermutationTable, point: [f64; 1]) -> f64 {
+pub(crate) fn noise1d(perm: &impl LatticeHasher, point: [f64; 1]) -> f64 {
     let x = point[0];
     // origin of hypercube in which input lies
     let x0 = x.floor();
     // smoothed distance from hypercube origin
     let dxs = smoothstep_3(x - x0);
     // get values from hypercube corners
-    let x0 = x0.rem_euclid(PERMUTATION_TABLE_SIZE as f64) as usize;
+    let x0 = perm.index(x0);
     let f0 = unsafe { perm.hash1d(x0) } as f64;
     let f1 = unsafe { perm.hash1d(x0 + 1) } as f64;
     // interpolate values from hypercube corners
     let xf = lerp(f0, f1, dxs);
-    normalize(xf)
+    normalize(xf, perm.hash_range())
 }
 
-pub(crate) fn noise2d(perm: &PermutationTable, point: [f64; 2]) -> f64 {
+pub(crate) fn noise2d(perm: &impl LatticeHasher, point: [f64; 2]) -> f64 {
     let x = Vec2::from(point);
     // origin of hypercube in which input lies
     let x0 = x.floor();
     // smoothed distance from hypercube origin
     let dxs = (x - x0).map(smoothstep_3);
     // get values from hypercube corners
-    let x0 = x0.cast().rem_euclid(PERMUTATION_TABLE_SIZE);
+    let x0 = x0.map_into(|x| perm.index(x));
     let f00 = unsafe { perm.hash2d(x0.x, x0.y) } as f64;
     let f01 = unsafe { perm.hash2d(x0.x, x0.y + 1) } as f64;
     let f10 = unsafe { perm.hash2d(x0.x + 1, x0.y) } as f64;
@@ -35,17 +36,17 @@ pub(crate) fn noise2d(perm: &PermutationTable, point: [f64; 2]) -> f64 {
     let xf0 = lerp(f00, f10, dxs.x);
     let xf1 = lerp(f01, f11, dxs.x);
     let yf = lerp(xf0, xf1, dxs.y);
-    normalize(yf)
+    normalize(yf, perm.hash_range())
 }
 
-pub(crate) fn noise3d(perm: &PermutationTable, point: [f64; 3]) -> f64 {
+pub(crate) fn noise3d(perm: &impl LatticeHasher, point: [f64; 3]) -> f64 {
     let x = Vec3::from(point);
     // origin of hypercube in which input lies
     let x0 = x.floor();
     // smoothed distance from hypercube origin
     let dxs = (x - x0).map(smoothstep_3);
     // get values from hypercube corners
-    let x0 = x0.cast().rem_euclid(PERMUTATION_TABLE_SIZE);
+    let x0 = x0.map_into(|x| perm.index(x));
     let f000 = unsafe { perm.hash3d(x0.x, x0.y, x0.z) } as f64;
     let f001 = unsafe { perm.hash3d(x0.x, x0.y, x0.z + 1) } as f64;
     let f010 = unsafe { perm.hash3d(x0.x, x0.y + 1, x0.z) } as f64;
@@ -62,17 +63,17 @@ pub(crate) fn noise3d(perm: &PermutationTable, point: [f64; 3]) -> f64 {
     let yf0 = lerp(xf00, xf10, dxs.y);
     let yf1 = lerp(xf01, xf11, dxs.y);
     let zf = lerp(yf0, yf1, dxs.z);
-    normalize(zf)
+    normalize(zf, perm.hash_range())
 }
 
-pub(crate) fn noise4d(perm: &PermutationTable, point: [f64; 4]) -> f64 {
+pub(crate) fn noise4d(perm: &impl LatticeHasher, point: [f64; 4]) -> f64 {
     let x = Vec4::from(point);
     // origin of hypercube in which input lies
     let x0 = x.floor();
     // smoothed distance from hypercube origin
     let dxs = (x - x0).map(smoothstep_3);
     // get values from hypercube corners
-    let x0 = x0.cast().rem_euclid(PERMUTATION_TABLE_SIZE);
+    let x0 = x0.map_into(|x| perm.index(x));
     let f0000 = unsafe { perm.hash4d(x0.x, x0.y, x0.z, x0.w) } as f64;
     let f0001 = unsafe { perm.hash4d(x0.x, x0.y, x0.z, x0.w + 1) } as f64;
     let f0010 = unsafe { perm.hash4d(x0.x, x0.y, x0.z + 1, x0.w) } as f64;
@@ -105,12 +106,12 @@ pub(crate) fn noise4d(perm: &PermutationTable, point: [f64; 4]) -> f64 {
     let zf0 = lerp(yf00, yf10, dxs.z);
     let zf1 = lerp(yf01, yf11, dxs.z);
     let wf = lerp(zf0, zf1, dxs.w);
-    normalize(wf)
+    normalize(wf, perm.hash_range())
 }
 
 #[inline]
-fn normalize(x: f64) -> f64 {
-    2.0 / PERMUTATION_TABLE_SIZE as f64 * x - 1.0
+fn normalize(x: f64, hash_range: usize) -> f64 {
+    2.0 / hash_range as f64 * x - 1.0
 }
 
 #[inline]
@@ -1,10 +1,11 @@
-use super::constants::PERMUTATION_TABLE_SIZE;
 use crate::core::utils::{
+    hasher::{LatticeHasher, LatticeHasherExt},
     math::{Vec2, Vec3, Vec4},
-    ptable::PermutationTable,
 };
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
 
-pub(crate) fn noise1d(perm: &PermutationTable, point: [f64; 1]) -> f64 {
+pub(crate) fn noise1d(perm: &impl LatticeHasher, point: [f64; 1]) -> f64 {
     let x = point[0];
     // origin of hypercube in which input lies and relative input position
     let x0 = x.floor();
@@ -20,7 +21,7 @@ pub(crate) fn noise1d(perm: &PermutationTable, point: [f64; 1]) -> f64 {
     min_dist * 2.0 - 1.0
 }
 
-pub(crate) fn noise2d(perm: &PermutationTable, point: [f64; 2]) -> f64 {
+pub(crate) fn noise2d(perm: &impl LatticeHasher, point: [f64; 2]) -> f64 {
     let x = Vec2::from(point);
     // origin of hypercube in which input lies and relative input position
     let x0 = x.floor();
@@ -39,7 +40,7 @@ pub(crate) fn noise2d(perm: &PermutationTable, point: [f64; 2]) -> f64 {
     min_dist_sq.sqrt().clamp(0.0, 1.0) * 2.0 - 1.0
 }
 
-pub(crate) fn noise3d(perm: &PermutationTable, point: [f64; 3]) -> f64 {
+pub(crate) fn noise3d(perm: &impl LatticeHasher, point: [f64; 3]) -> f64 {
     let x = Vec3::from(point);
     // origin of hypercube in which input lies and relative input position
     let x0 = x.floor();
@@ -60,7 +61,7 @@ pub(crate) fn noise3d(perm: &PermutationTable, point: [f64; 3]) -> f64 {
     min_dist_sq.sqrt().clamp(0.0, 1.0) * 2.0 - 1.0
 }
 
-pub(crate) fn noise4d(perm: &PermutationTable, point: [f64; 4]) -> f64 {
+pub(crate) fn noise4d(perm: &impl LatticeHasher, point: [f64; 4]) -> f64 {
     let x = Vec4::from(point);
     // origin of hypercube in which input lies and relative input position
     let x0 = x.floor();
@@ -84,31 +85,31 @@ pub(crate) fn noise4d(perm: &PermutationTable, point: [f64; 4]) -> f64 {
 }
 
 #[inline]
-fn point1d(perm: &PermutationTable, x0: f64) -> f64 {
-    let x = unsafe { perm.hash1d((x0 as usize).rem_euclid(PERMUTATION_TABLE_SIZE)) };
-    x as f64 / PERMUTATION_TABLE_SIZE as f64
+fn point1d(perm: &impl LatticeHasher, x0: f64) -> f64 {
+    let x = unsafe { perm.hash1d(perm.index(x0)) };
+    x as f64 / perm.hash_range() as f64
 }
 
 #[inline]
-fn point2d(perm: &PermutationTable, x0: Vec2<f64>) -> Vec2<f64> {
-    let x = unsafe { perm.hash2d_vec(x0.cast().rem_euclid(PERMUTATION_TABLE_SIZE)) };
+fn point2d(perm: &impl LatticeHasher, x0: Vec2<f64>) -> Vec2<f64> {
+    let x = unsafe { perm.hash2d_vec(x0.map_into(|x| perm.index(x))) };
     let y = unsafe { perm.hash1d(x) };
-    Vec2::from([x, y]).cast() / PERMUTATION_TABLE_SIZE as f64
+    Vec2::from([x, y]).cast() / perm.hash_range() as f64
 }
 
 #[inline]
-fn point3d(perm: &PermutationTable, x0: Vec3<f64>) -> Vec3<f64> {
-    let x = unsafe { perm.hash3d_vec(x0.cast().rem_euclid(PERMUTATION_TABLE_SIZE)) };
+fn point3d(perm: &impl LatticeHasher, x0: Vec3<f64>) -> Vec3<f64> {
+    let x = unsafe { perm.hash3d_vec(x0.map_into(|x| perm.index(x))) };
     let y = unsafe { perm.hash1d(x) };
     let z = unsafe { perm.hash1d(y) };
-    Vec3::from([x, y, z]).cast() / PERMUTATION_TABLE_SIZE as f64
+    Vec3::from([x, y, z]).cast() / perm.hash_range() as f64
 }
 
 #[inline]
-fn point4d(perm: &PermutationTable, x0: Vec4<f64>) -> Vec4<f64> {
-    let x = unsafe { perm.hash4d_vec(x0.cast().rem_euclid(PERMUTATION_TABLE_SIZE)) };
+fn point4d(perm: &impl LatticeHasher, x0: Vec4<f64>) -> Vec4<f64> {
+    let x = unsafe { perm.hash4d_vec(x0.map_into(|x| perm.index(x))) };
     let y = unsafe { perm.hash1d(x) };
     let z = unsafe { perm.hash1d(y) };
     let w = unsafe { perm.hash1d(z) };
-    Vec4::from([x, y, z, w]).cast() / PERMUTATION_TABLE_SIZE as f64
+    Vec4::from([x, y, z, w]).cast() / perm.hash_range() as f64
 }
@@ -1,7 +1,11 @@
-use super::functional::{self, constants::PERMUTATION_TABLE_SIZE};
+use super::functional;
 use crate::core::{
     generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
-    utils::ptable::{PermutationTable, Seed},
+    graph::GraphNode,
+    utils::{
+        hasher::LatticeHasher,
+        ptable::{PermutationTable, Seed},
+    },
 };
 
 /// A generator which produces n-dimensional improved perlin noise.
@@ -10,6 +14,9 @@ use crate::core::{
 /// to be used directly. Instead, [`improved_perlin()`] implemented by [`Source`], should be used to
 /// create an improved perlin noise generator.
 ///
+/// Lattice points are hashed using `H`, which defaults to [`PermutationTable`]. For other
+/// hashers, see [`LatticeHasher`].
+///
 /// # Direct usage of this struct
 ///
 /// Direct instantiation of this struct:
@@ -22,49 +29,101 @@ use crate::core::{
 ///
 /// [`improved_perlin()`]: crate::Source::improved_perlin
 /// [`Source`]: crate::Source
-#[derive(Clone, Debug)]
-pub struct ImprovedPerlin<const D: usize> {
-    permutation_table: PermutationTable,
+#[derive(Clone, Copy, Debug)]
+pub struct ImprovedPerlin<const D: usize, H = PermutationTable> {
+    hasher: H,
 }
 
-impl Generator1D for ImprovedPerlin<1> {}
-impl Generator2D for ImprovedPerlin<2> {}
-impl Generator3D for ImprovedPerlin<3> {}
-impl Generator4D for ImprovedPerlin<4> {}
+impl<H: LatticeHasher> Generator1D for ImprovedPerlin<1, H> {}
+impl<H: LatticeHasher> Generator2D for ImprovedPerlin<2, H> {}
+impl<H: LatticeHasher> Generator3D for ImprovedPerlin<3, H> {}
+impl<H: LatticeHasher> Generator4D for ImprovedPerlin<4, H> {}
 
 impl<const D: usize> ImprovedPerlin<D> {
     /// Create a new improved perlin noise generator.
     #[inline]
     pub fn new(seed: impl Seed) -> Self {
-        let permutation_table = PermutationTable::new(seed, PERMUTATION_TABLE_SIZE, true);
-        Self { permutation_table }
+        Self::with_hasher(PermutationTable::from_seed(seed))
+    }
+}
+
+impl<const D: usize, H: LatticeHasher> ImprovedPerlin<D, H> {
+    /// Create a new improved perlin noise generator which hashes lattice points using the given hasher.
+    #[inline]
+    pub fn with_hasher(hasher: H) -> Self {
+        Self { hasher }
     }
 }
 
-impl Generator<1> for ImprovedPerlin<1> {
+impl<H: LatticeHasher> Generator<1> for ImprovedPerlin<1, H> {
     #[inline]
     fn sample(&self, point: [f64; 1]) -> f64 {
-        functional::improved_perlin::noise1d(&self.permutation_table, point)
+        functional::improved_perlin::noise1d(&self.hasher, point)
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        Some((-1.0, 1.0))
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        self.hasher
+            .describe_parameters(GraphNode::new("ImprovedPerlin"))
     }
 }
 
-impl Generator<2> for ImprovedPerlin<2> {
+impl<H: LatticeHasher> Generator<2> for ImprovedPerlin<2, H> {
     #[inline]
     fn sample(&self, point: [f64; 2]) -> f64 {
-        functional::improved_perlin::noise2d(&self.permutation_table, point)
+        functional::improved_perlin::noise2d(&self.hasher, point)
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        let bound = functional::constants::IMPROVED_PERLIN_OUTPUT_BOUND_2D;
+        Some((-bound, bound))
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        self.hasher
+            .describe_parameters(GraphNode::new("ImprovedPerlin"))
     }
 }
 
-impl Generator<3> for ImprovedPerlin<3> {
+impl<H: LatticeHasher> Generator<3> for ImprovedPerlin<3, H> {
     #[inline]
     fn sample(&self, point: [f64; 3]) -> f64 {
-        functional::improved_perlin::noise3d(&self.permutation_table, point)
+        functional::improved_perlin::noise3d(&self.hasher, point)
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        Some((-1.0, 1.0))
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        self.hasher
+            .describe_parameters(GraphNode::new("ImprovedPerlin"))
     }
 }
 
-impl Generator<4> for ImprovedPerlin<4> {
+impl<H: LatticeHasher> Generator<4> for ImprovedPerlin<4, H> {
     #[inline]
     fn sample(&self, point: [f64; 4]) -> f64 {
-        functional::improved_perlin::noise4d(&self.permutation_table, point)
+        functional::improved_perlin::noise4d(&self.hasher, point)
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        Some((-1.0, 1.0))
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        self.hasher
+            .describe_parameters(GraphNode::new("ImprovedPerlin"))
     }
 }
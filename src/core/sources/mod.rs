@@ -1,7 +1,7 @@
 mod checkerboard;
 mod constant;
 mod custom;
-mod functional;
+pub(crate) mod functional;
 mod improved_perlin;
 mod perlin;
 mod simplex;
@@ -1,7 +1,11 @@
-use super::functional::{self, constants::PERMUTATION_TABLE_SIZE};
+use super::functional;
 use crate::core::{
     generator::{Generator, Generator1D, Generator2D, Generator3D, Generator4D},
-    utils::ptable::{PermutationTable, Seed},
+    graph::GraphNode,
+    utils::{
+        hasher::LatticeHasher,
+        ptable::{PermutationTable, Seed},
+    },
 };
 
 /// A generator which produces n-dimensional worley noise.
@@ -10,6 +14,9 @@ use crate::core::{
 /// to be used directly. Instead, [`worley()`] implemented by [`Source`], should be used to
 /// create a worley noise generator.
 ///
+/// Lattice points are hashed using `H`, which defaults to [`PermutationTable`]. For other
+/// hashers, see [`LatticeHasher`].
+///
 /// # Direct usage of this struct
 ///
 /// Direct instantiation of this struct:
@@ -22,49 +29,96 @@ use crate::core::{
 ///
 /// [`worley()`]: crate::Source::worley
 /// [`Source`]: crate::Source
-#[derive(Clone, Debug)]
-pub struct Worley<const D: usize> {
-    permutation_table: PermutationTable,
+#[derive(Clone, Copy, Debug)]
+pub struct Worley<const D: usize, H = PermutationTable> {
+    hasher: H,
 }
 
-impl Generator1D for Worley<1> {}
-impl Generator2D for Worley<2> {}
-impl Generator3D for Worley<3> {}
-impl Generator4D for Worley<4> {}
+impl<H: LatticeHasher> Generator1D for Worley<1, H> {}
+impl<H: LatticeHasher> Generator2D for Worley<2, H> {}
+impl<H: LatticeHasher> Generator3D for Worley<3, H> {}
+impl<H: LatticeHasher> Generator4D for Worley<4, H> {}
 
 impl<const D: usize> Worley<D> {
     /// Create a new worley noise generator.
     #[inline]
     pub fn new(seed: impl Seed) -> Self {
-        let permutation_table = PermutationTable::new(seed, PERMUTATION_TABLE_SIZE, true);
-        Self { permutation_table }
+        Self::with_hasher(PermutationTable::from_seed(seed))
+    }
+}
+
+impl<const D: usize, H: LatticeHasher> Worley<D, H> {
+    /// Create a new worley noise generator which hashes lattice points using the given hasher.
+    #[inline]
+    pub fn with_hasher(hasher: H) -> Self {
+        Self { hasher }
     }
 }
 
-impl Generator<1> for Worley<1> {
+impl<H: LatticeHasher> Generator<1> for Worley<1, H> {
     #[inline]
     fn sample(&self, point: [f64; 1]) -> f64 {
-        functional::worley::noise1d(&self.permutation_table, point)
+        functional::worley::noise1d(&self.hasher, point)
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        Some((-1.0, 1.0))
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        self.hasher.describe_parameters(GraphNode::new("Worley"))
     }
 }
 
-impl Generator<2> for Worley<2> {
+impl<H: LatticeHasher> Generator<2> for Worley<2, H> {
     #[inline]
     fn sample(&self, point: [f64; 2]) -> f64 {
-        functional::worley::noise2d(&self.permutation_table, point)
+        functional::worley::noise2d(&self.hasher, point)
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        Some((-1.0, 1.0))
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        self.hasher.describe_parameters(GraphNode::new("Worley"))
     }
 }
 
-impl Generator<3> for Worley<3> {
+impl<H: LatticeHasher> Generator<3> for Worley<3, H> {
     #[inline]
     fn sample(&self, point: [f64; 3]) -> f64 {
-        functional::worley::noise3d(&self.permutation_table, point)
+        functional::worley::noise3d(&self.hasher, point)
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        Some((-1.0, 1.0))
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        self.hasher.describe_parameters(GraphNode::new("Worley"))
     }
 }
 
-impl Generator<4> for Worley<4> {
+impl<H: LatticeHasher> Generator<4> for Worley<4, H> {
     #[inline]
     fn sample(&self, point: [f64; 4]) -> f64 {
-        functional::worley::noise4d(&self.permutation_table, point)
+        functional::worley::noise4d(&self.hasher, point)
+    }
+
+    #[inline]
+    fn output_range(&self) -> Option<(f64, f64)> {
+        Some((-1.0, 1.0))
+    }
+
+    #[inline]
+    fn describe(&self) -> GraphNode {
+        self.hasher.describe_parameters(GraphNode::new("Worley"))
     }
 }
@@ -0,0 +1,128 @@
+use crate::core::{
+    generator::Generator,
+    utils::noisebuf::{NoiseBuffer, lattice_coordinate},
+};
+use std::array;
+
+/// A struct for filling fixed-size chunks of a larger world with noise, keyed by chunk coordinates.
+///
+/// The world is an infinite lattice of points placed at an origin plus the integral index of the
+/// point multiplied by a spacing along each dimension. Chunks tile this lattice, such that the
+/// chunk at chunk coordinates `c` covers the indices from `c * chunk_shape` up to, but excluding,
+/// `(c + 1) * chunk_shape`. Each chunk is returned as a [`NoiseBuffer`].
+///
+/// Each point is computed directly from its index in the world rather than from the position of
+/// the chunk, such that a point contained in several buffers, for example due to padding, has the
+/// exact same value in each of them. Chunks thus join without seams, and the generator does not
+/// need to be wrapped in adapters such as [`scale()`] or [`translate()`] for each chunk.
+///
+/// # Padding
+///
+/// Optionally, a number of padding cells can be added on each side of every chunk using
+/// [`with_padding()`]. The buffer of a chunk then extends this many points beyond the chunk into
+/// the neighboring chunks along each dimension, and the index of a point within the chunk is
+/// offset by the padding in the buffer. This is useful for algorithms such as meshing or
+/// computing gradients, which require values of the neighbors of the points at the chunk border.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{Source, Generator, ChunkSampler};
+/// let sampler = ChunkSampler::new(Source::simplex(42), [32, 32, 32])
+///     .with_spacing([0.05, 0.05, 0.05])
+///     .with_padding(1);
+///
+/// let chunk = sampler.chunk([3, -1, 0]);
+/// let neighbor = sampler.chunk([4, -1, 0]);
+///
+/// assert_eq!(chunk.shape, [34, 34, 34]);
+/// // the last point of the chunk is the first point of the padding of the neighbor
+/// assert_eq!(chunk[[32, 5, 7]], neighbor[[0, 5, 7]]);
+/// ```
+///
+/// [`scale()`]: Generator::scale
+/// [`translate()`]: Generator::translate
+/// [`with_padding()`]: ChunkSampler::with_padding
+#[derive(Clone, Debug)]
+pub struct ChunkSampler<const D: usize, G> {
+    generator: G,
+    chunk_shape: [usize; D],
+    origin: [f64; D],
+    spacing: [f64; D],
+    padding: usize,
+}
+
+impl<const D: usize, G> ChunkSampler<D, G>
+where
+    G: Generator<D>,
+{
+    /// Creates a new chunk sampler for the given `generator` with chunks of the given
+    /// `chunk_shape`. The world is placed at the origin with a spacing of 1, such that its points
+    /// coincide with those sampled by [`NoiseBuffer::new()`], and chunks have no padding.
+    #[inline]
+    pub fn new(generator: G, chunk_shape: [usize; D]) -> Self {
+        Self {
+            generator,
+            chunk_shape,
+            origin: [0.0; D],
+            spacing: [1.0; D],
+            padding: 0,
+        }
+    }
+
+    /// Sets the coordinates of the point at index zero of the world.
+    #[inline]
+    pub fn with_origin(mut self, origin: [f64; D]) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// Sets the distance between adjacent points of the world along each dimension.
+    #[inline]
+    pub fn with_spacing(mut self, spacing: [f64; D]) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the number of padding cells added on each side of every chunk. For further detail
+    /// see the [Padding](#padding) section.
+    #[inline]
+    pub fn with_padding(mut self, padding: usize) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Creates a new noise buffer filled with noise for the chunk at the given chunk coordinates,
+    /// including its padding.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index of any point of the chunk, including its padding, does not fit into an
+    /// `i64`. Along each dimension, the indices range from `chunk * chunk_shape - padding` up to,
+    /// but excluding, `(chunk + 1) * chunk_shape + padding`. Also panics if the size of the buffer
+    /// along any dimension, `chunk_shape + 2 * padding`, overflows.
+    pub fn chunk(&self, chunk: [i64; D]) -> NoiseBuffer<D> {
+        let shape = self.chunk_shape.map(|size| {
+            self.padding
+                .checked_mul(2)
+                .and_then(|padding| size.checked_add(padding))
+                .unwrap_or_else(|| panic!("chunk size {size} with padding overflows"))
+        });
+        let start: [i64; D] = array::from_fn(|i| {
+            // compute the range of indices with sufficient precision to detect any overflow
+            let start = chunk[i] as i128 * self.chunk_shape[i] as i128 - self.padding as i128;
+            let last = start + shape[i] as i128 - 1;
+            match (i64::try_from(start), i64::try_from(last.max(start))) {
+                (Ok(start), Ok(_)) => start,
+                _ => panic!("indices of chunk {chunk:?} exceed the range of i64"),
+            }
+        });
+        NoiseBuffer::from_fn(shape, |index| {
+            self.generator.sample(array::from_fn(|i| {
+                lattice_coordinate(self.origin[i], self.spacing[i], start[i] + index[i] as i64)
+            }))
+        })
+    }
+}
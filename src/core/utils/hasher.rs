@@ -0,0 +1,196 @@
+use super::{
+    math::{Vec2, Vec3, Vec4},
+    ptable::Seed,
+};
+use crate::core::graph::GraphNode;
+use core::fmt;
+use rand_chacha::rand_core::RngCore;
+
+/// The period of the lattice indices used by [`IntegerHasher`], which is 2<sup>63</sup> on 64-bit
+/// targets.
+const INTEGER_HASHER_PERIOD: usize = (usize::MAX >> 1) + 1;
+
+const PRIME_1: u64 = 0x9E37_79B1_85EB_CA87;
+const PRIME_2: u64 = 0xC2B2_AE3D_27D4_EB4F;
+const PRIME_3: u64 = 0x1656_67B1_9E37_79F9;
+const PRIME_4: u64 = 0x85EB_CA77_C2B2_AE63;
+
+/// A trait for hashing lattice points, which lattice-based noise sources use to assign
+/// pseudorandom values or gradients to the corners of the cells of their lattice.
+///
+/// This trait is implemented by [`PermutationTable`], which is the default for all lattice-based
+/// noise sources and repeats every 256 units by default, and by [`IntegerHasher`], which does not
+/// repeat within any practical range of inputs.
+///
+/// [`PermutationTable`]: crate::PermutationTable
+pub trait LatticeHasher: Clone + fmt::Debug {
+    /// Creates a hasher from the given seed.
+    fn from_seed(seed: impl Seed) -> Self;
+
+    /// Returns the number of distinct values returned by the hash functions.
+    fn hash_range(&self) -> usize;
+
+    /// Maps the integral coordinate of a lattice point to an index accepted by the hash functions.
+    fn index(&self, x: f64) -> usize;
+
+    /// Hashes a lattice point to a value less than [`hash_range()`].
+    ///
+    /// # Safety
+    ///
+    /// The index must have been obtained from [`index()`], optionally incremented by one, or
+    /// must itself be a value returned by a hash function.
+    ///
+    /// [`hash_range()`]: LatticeHasher::hash_range
+    /// [`index()`]: LatticeHasher::index
+    unsafe fn hash1d(&self, i: usize) -> usize;
+
+    /// Hashes a lattice point to a value less than [`hash_range()`].
+    ///
+    /// # Safety
+    ///
+    /// See [`hash1d()`].
+    ///
+    /// [`hash_range()`]: LatticeHasher::hash_range
+    /// [`hash1d()`]: LatticeHasher::hash1d
+    unsafe fn hash2d(&self, i: usize, j: usize) -> usize;
+
+    /// Hashes a lattice point to a value less than [`hash_range()`].
+    ///
+    /// # Safety
+    ///
+    /// See [`hash1d()`].
+    ///
+    /// [`hash_range()`]: LatticeHasher::hash_range
+    /// [`hash1d()`]: LatticeHasher::hash1d
+    unsafe fn hash3d(&self, i: usize, j: usize, k: usize) -> usize;
+
+    /// Hashes a lattice point to a value less than [`hash_range()`].
+    ///
+    /// # Safety
+    ///
+    /// See [`hash1d()`].
+    ///
+    /// [`hash_range()`]: LatticeHasher::hash_range
+    /// [`hash1d()`]: LatticeHasher::hash1d
+    unsafe fn hash4d(&self, i: usize, j: usize, k: usize, l: usize) -> usize;
+
+    /// Adds the parameters of the hasher to the node describing a noise source which uses it.
+    ///
+    /// The default implementation adds the kind of the hasher, that is, its type name. The hashers
+    /// of this crate additionally add a fingerprint of their seed, which is the first output of the
+    /// RNG constructed from the seed, and their parameters such as the size of the table.
+    #[inline]
+    fn describe_parameters(&self, node: GraphNode) -> GraphNode {
+        node.with_parameter(
+            "hasher",
+            format_args!("{}", GraphNode::of_type::<Self>().name),
+        )
+    }
+}
+
+/// Hashing of lattice points given as vectors, for use within noise kernels.
+pub(crate) trait LatticeHasherExt: LatticeHasher {
+    #[inline]
+    unsafe fn hash2d_vec(&self, value: Vec2<usize>) -> usize {
+        unsafe { self.hash2d(value.x, value.y) }
+    }
+
+    #[inline]
+    unsafe fn hash3d_vec(&self, value: Vec3<usize>) -> usize {
+        unsafe { self.hash3d(value.x, value.y, value.z) }
+    }
+
+    #[inline]
+    unsafe fn hash4d_vec(&self, value: Vec4<usize>) -> usize {
+        unsafe { self.hash4d(value.x, value.y, value.z, value.w) }
+    }
+}
+
+impl<H: LatticeHasher> LatticeHasherExt for H {}
+
+/// A stateless hasher of lattice points keyed by a seed.
+///
+/// Unlike [`PermutationTable`], this hasher requires no memory beyond the seed and does not repeat
+/// every 256 units. Instead, lattice coordinates are hashed as 64-bit integers on 64-bit targets,
+/// such that noise does not repeat within any practical range of inputs. Lattice-based noise
+/// sources using this hasher can be created using, for example, [`simplex_hashed()`].
+///
+/// [`PermutationTable`]: crate::PermutationTable
+/// [`simplex_hashed()`]: crate::Source::simplex_hashed
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IntegerHasher {
+    seed: u64,
+}
+
+impl IntegerHasher {
+    #[inline]
+    fn finish(&self, hash: u64) -> usize {
+        // finalizer of murmur3, the high byte is used as it is mixed most thoroughly
+        let mut hash = hash ^ self.seed;
+        hash ^= hash >> 33;
+        hash = hash.wrapping_mul(0xFF51_AFD7_ED55_8CCD);
+        hash ^= hash >> 33;
+        hash = hash.wrapping_mul(0xC4CE_B9FE_1A85_EC53);
+        hash ^= hash >> 33;
+        (hash >> 56) as usize
+    }
+}
+
+#[inline]
+fn wrap(i: usize) -> u64 {
+    (i & (INTEGER_HASHER_PERIOD - 1)) as u64
+}
+
+impl LatticeHasher for IntegerHasher {
+    fn from_seed(seed: impl Seed) -> Self {
+        Self {
+            seed: seed.construct_rng().next_u64(),
+        }
+    }
+
+    #[inline]
+    fn hash_range(&self) -> usize {
+        256
+    }
+
+    #[inline]
+    fn index(&self, x: f64) -> usize {
+        // the two's complement representation is congruent modulo the period
+        x as i64 as usize & (INTEGER_HASHER_PERIOD - 1)
+    }
+
+    #[inline]
+    unsafe fn hash1d(&self, i: usize) -> usize {
+        self.finish(wrap(i).wrapping_mul(PRIME_1))
+    }
+
+    #[inline]
+    unsafe fn hash2d(&self, i: usize, j: usize) -> usize {
+        self.finish(wrap(i).wrapping_mul(PRIME_1) ^ wrap(j).wrapping_mul(PRIME_2))
+    }
+
+    #[inline]
+    unsafe fn hash3d(&self, i: usize, j: usize, k: usize) -> usize {
+        self.finish(
+            wrap(i).wrapping_mul(PRIME_1)
+                ^ wrap(j).wrapping_mul(PRIME_2)
+                ^ wrap(k).wrapping_mul(PRIME_3),
+        )
+    }
+
+    #[inline]
+    unsafe fn hash4d(&self, i: usize, j: usize, k: usize, l: usize) -> usize {
+        self.finish(
+            wrap(i).wrapping_mul(PRIME_1)
+                ^ wrap(j).wrapping_mul(PRIME_2)
+                ^ wrap(k).wrapping_mul(PRIME_3)
+                ^ wrap(l).wrapping_mul(PRIME_4),
+        )
+    }
+
+    #[inline]
+    fn describe_parameters(&self, node: GraphNode) -> GraphNode {
+        node.with_parameter("hasher", format_args!("IntegerHasher"))
+            .with_parameter("seed", format_args!("{:#018x}", self.seed))
+    }
+}
@@ -0,0 +1,9 @@
+#[inline]
+pub(crate) fn smoothstep_3(t: f64) -> f64 {
+    t * t * (t * (-2.0) + 3.0)
+}
+
+#[inline]
+pub(crate) fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + t * (b - a)
+}
@@ -0,0 +1,120 @@
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+
+/// A closed interval `(min, max)`, used for propagating the output ranges of generators.
+///
+/// All functions of this module return `None` if the result is not a valid interval, such as when
+/// a bound evaluates to NaN.
+pub(crate) type Interval = (f64, f64);
+
+/// Create the interval `[min, max]`, or `None` if it is empty or a bound is NaN.
+#[inline]
+pub(crate) fn new(min: f64, max: f64) -> Option<Interval> {
+    (min <= max).then_some((min, max))
+}
+
+/// Create the smallest interval containing all `values`.
+#[inline]
+pub(crate) fn hull(values: &[f64]) -> Option<Interval> {
+    if values.iter().any(|x| x.is_nan()) {
+        return None;
+    }
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    new(min, max)
+}
+
+#[inline]
+pub(crate) fn union(a: Interval, b: Interval) -> Interval {
+    (a.0.min(b.0), a.1.max(b.1))
+}
+
+#[inline]
+pub(crate) fn add(a: Interval, b: Interval) -> Option<Interval> {
+    new(a.0 + b.0, a.1 + b.1)
+}
+
+#[inline]
+pub(crate) fn sub(a: Interval, b: Interval) -> Option<Interval> {
+    new(a.0 - b.1, a.1 - b.0)
+}
+
+#[inline]
+pub(crate) fn mul(a: Interval, b: Interval) -> Option<Interval> {
+    hull(&[a.0 * b.0, a.0 * b.1, a.1 * b.0, a.1 * b.1])
+}
+
+#[inline]
+pub(crate) fn div(a: Interval, b: Interval) -> Option<Interval> {
+    // division by an interval containing 0 is unbounded
+    if b.0 <= 0.0 && 0.0 <= b.1 {
+        return None;
+    }
+    mul(a, (1.0 / b.1, 1.0 / b.0))
+}
+
+#[inline]
+pub(crate) fn scale(a: Interval, factor: f64) -> Option<Interval> {
+    mul(a, (factor, factor))
+}
+
+#[inline]
+pub(crate) fn neg(a: Interval) -> Interval {
+    (-a.1, -a.0)
+}
+
+#[inline]
+pub(crate) fn abs(a: Interval) -> Interval {
+    match a {
+        (min, _) if min >= 0.0 => a,
+        (_, max) if max <= 0.0 => neg(a),
+        (min, max) => (0.0, max.max(-min)),
+    }
+}
+
+/// Apply the monotonically increasing function `f` to the interval.
+#[inline]
+pub(crate) fn map_increasing(a: Interval, f: impl Fn(f64) -> f64) -> Option<Interval> {
+    new(f(a.0), f(a.1))
+}
+
+/// Raise all values of the interval to the power of `exponent` using `f`, where results which are
+/// NaN, such as for negative bases and non-integer exponents, are excluded.
+#[inline]
+pub(crate) fn pow(a: Interval, exponent: f64, f: impl Fn(f64) -> f64) -> Option<Interval> {
+    if exponent == 0.0 {
+        return Some((1.0, 1.0));
+    }
+    if exponent.fract() != 0.0 {
+        // negative bases produce NaN, and the function is monotonic for non-negative bases
+        let min = a.0.max(0.0);
+        if min > a.1 {
+            return None;
+        }
+        return hull(&[f(min), f(a.1)]);
+    }
+    if a.0 < 0.0 && 0.0 < a.1 {
+        return match exponent {
+            // the function has a pole at 0
+            e if e < 0.0 => None,
+            e if e % 2.0 == 0.0 => new(0.0, f(a.0).max(f(a.1))),
+            _ => new(f(a.0), f(a.1)),
+        };
+    }
+    // the function is monotonic for bases of the same sign
+    hull(&[f(a.0), f(a.1)])
+}
+
+/// Linearly interpolate between the intervals `a` and `b` by the interval `t`.
+#[inline]
+pub(crate) fn lerp(a: Interval, b: Interval, t: Interval) -> Option<Interval> {
+    // the interpolation is linear in each argument, so the extrema lie at the corners
+    let mut corners = [0.0; 8];
+    for (i, corner) in corners.iter_mut().enumerate() {
+        let a = if i & 1 == 0 { a.0 } else { a.1 };
+        let b = if i & 2 == 0 { b.0 } else { b.1 };
+        let t = if i & 4 == 0 { t.0 } else { t.1 };
+        *corner = a + t * (b - a);
+    }
+    hull(&corners)
+}
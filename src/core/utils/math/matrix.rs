@@ -0,0 +1,29 @@
+/// Multiply the square `matrix` with the column vector `vector`.
+#[inline]
+pub(crate) fn mat_vec_mul<const D: usize>(matrix: &[[f64; D]; D], vector: [f64; D]) -> [f64; D] {
+    core::array::from_fn(|i| matrix[i].iter().zip(vector).map(|(m, v)| m * v).sum())
+}
+
+/// Compute the rotation matrix of the quaternion `[x, y, z, w]`, where `w` is the real part. The
+/// quaternion is normalized first, so that any quaternion of non-zero length may be used.
+#[inline]
+pub(crate) fn quaternion_to_matrix([x, y, z, w]: [f64; 4]) -> [[f64; 3]; 3] {
+    let s = 2.0 / (x * x + y * y + z * z + w * w);
+    [
+        [
+            1.0 - s * (y * y + z * z),
+            s * (x * y - z * w),
+            s * (x * z + y * w),
+        ],
+        [
+            s * (x * y + z * w),
+            1.0 - s * (x * x + z * z),
+            s * (y * z - x * w),
+        ],
+        [
+            s * (x * z - y * w),
+            s * (y * z + x * w),
+            1.0 - s * (x * x + y * y),
+        ],
+    ]
+}
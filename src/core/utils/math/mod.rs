@@ -1,2 +1,9 @@
+mod interpolation;
+pub(crate) mod interval;
+mod matrix;
+mod smooth;
 mod vector;
+pub(crate) use interpolation::{lerp, smoothstep_3};
+pub(crate) use matrix::{mat_vec_mul, quaternion_to_matrix};
+pub(crate) use smooth::{smooth_max, smooth_min};
 pub(crate) use vector::{Vec2, Vec3, Vec4};
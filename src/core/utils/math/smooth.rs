@@ -0,0 +1,17 @@
+/// Polynomial smooth minimum of `a` and `b`, which equals the minimum wherever `a` and `b` differ
+/// by at least `radius`.
+#[inline]
+pub(crate) fn smooth_min(a: f64, b: f64, radius: f64) -> f64 {
+    if radius <= 0.0 {
+        return a.min(b);
+    }
+    let h = (radius - (a - b).abs()).max(0.0) / radius;
+    a.min(b) - h * h * radius * 0.25
+}
+
+/// Polynomial smooth maximum of `a` and `b`, which equals the maximum wherever `a` and `b` differ
+/// by at least `radius`.
+#[inline]
+pub(crate) fn smooth_max(a: f64, b: f64, radius: f64) -> f64 {
+    -smooth_min(-a, -b, radius)
+}
@@ -1,5 +1,5 @@
-use num_traits::{Euclid, Float, Pow, identities::Zero};
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use num_traits::{Float, Pow, identities::Zero};
 
 pub(crate) trait NumCast<T>: Sized {
     fn from(n: T) -> Self;
@@ -56,6 +56,11 @@ macro_rules! impl_vector {
                 $name::new($(T2::from(self.$x),)+)
             }
 
+            #[inline]
+            pub(crate) fn map_into<T2, F>(self, f: F) -> $name<T2> where F: Fn(T) -> T2 {
+                $name::new($(f(self.$x),)+)
+            }
+
             #[inline]
             pub(crate) fn map<F>(self, f: F) -> Self where F: Fn(T) -> T {
                 Self { $($x: f(self.$x)),+ }
@@ -66,11 +71,6 @@ macro_rules! impl_vector {
                 Self { $($x: self.$x.floor()),+ }
             }
 
-            #[inline]
-            pub(crate) fn rem_euclid(self, rhs: T) -> Self where T: Euclid {
-                Self { $($x: self.$x.rem_euclid(&rhs)),+ }
-            }
-
             #[inline]
             pub(crate) fn sum(self) -> T where T: Pow<i32, Output = T> + Zero + AddAssign {
                 let mut result = T::zero();
@@ -1,5 +1,11 @@
+#[cfg(feature = "std")]
+pub mod chunksampler;
+pub(crate) mod hasher;
 pub(super) mod math;
+#[cfg(feature = "std")]
 pub mod noisebuf;
 pub(crate) mod ptable;
+pub(crate) mod seed;
+pub mod stats;
 #[cfg(feature = "image")]
 pub mod visualizer;
@@ -1,6 +1,9 @@
-use crate::core::generator::Generator;
+use crate::core::{generator::Generator, utils::stats::NoiseStats};
 use itertools::Itertools;
-use std::ops::{Index, IndexMut};
+use std::{
+    array,
+    ops::{Index, IndexMut},
+};
 
 /// A struct for generating an n-dimensional array and efficiently filling it with noise values.
 ///
@@ -38,7 +41,27 @@ use std::ops::{Index, IndexMut};
 /// grid can be modified by calling adapters such as [`scale()`], [`translate()`], or [`rotate()`]
 /// on the generator before using it to create a [`NoiseBuffer`].
 ///
+/// # Sampling a region
+///
+/// Alternatively, the grid can be placed using the [`from_region()`] method, which samples the
+/// generator at the given origin plus the buffer index multiplied by the given spacing along each
+/// dimension. Each point is computed directly from its index, such that no error accumulates
+/// across the buffer:
+///
+/// ```
+/// # use libnoise::{Source, Generator, NoiseBuffer};
+/// let generator = Source::simplex(42);
+///
+/// let buf = NoiseBuffer::<2>::from_region([-10.0, 5.0], [0.5, 0.25], [40, 20], &generator);
+///
+/// assert_eq!(buf[[3, 8]], generator.sample([-10.0 + 3.0 * 0.5, 5.0 + 8.0 * 0.25]));
+/// ```
+///
+/// For filling buffers of a larger world chunk by chunk, see [`ChunkSampler`].
+///
 /// [`new()`]: NoiseBuffer::new
+/// [`from_region()`]: NoiseBuffer::from_region
+/// [`ChunkSampler`]: crate::ChunkSampler
 /// [`scale()`]: Generator::scale
 /// [`translate()`]: Generator::translate
 /// [`rotate()`]: crate::Generator2D::rotate
@@ -78,11 +101,24 @@ macro_rules! impl_new {
             /// by the given `generator`. For further detail see the
             /// [Creating a noise buffer](#creating-a-noise-buffer) section.
             pub fn new<G: Generator<$dim>>(shape: [usize; $dim], generator: &G) -> Self {
-                let mut noisebuf = Self::new_empty(shape);
-                for point in noisebuf.tensor_indices() {
-                    noisebuf[point] = generator.sample(point.map(|x| x as f64));
-                }
-                noisebuf
+                Self::from_region([0.0; $dim], [1.0; $dim], shape, generator)
+            }
+
+            /// Creates a new noise buffer with the given `shape` and filled with noise generated
+            /// by the given `generator` at the given `origin` plus the index multiplied by the
+            /// given `spacing`. For further detail see the
+            /// [Sampling a region](#sampling-a-region) section.
+            pub fn from_region<G: Generator<$dim>>(
+                origin: [f64; $dim],
+                spacing: [f64; $dim],
+                shape: [usize; $dim],
+                generator: &G,
+            ) -> Self {
+                Self::from_fn(shape, |index| {
+                    generator.sample(array::from_fn(|i| {
+                        lattice_coordinate(origin[i], spacing[i], index[i] as i64)
+                    }))
+                })
             }
         }
     };
@@ -99,6 +135,40 @@ impl_new!(3);
 impl_new!(4);
 
 impl<const D: usize> NoiseBuffer<D> {
+    /// Computes summary statistics of all values in the buffer, such as their range, mean, and
+    /// percentiles. See [`stats::estimate()`] for estimating the same statistics directly from a
+    /// generator.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # use libnoise::{Source, Generator, NoiseBuffer};
+    /// let buf = NoiseBuffer::<2>::new([100, 100], &Source::simplex(42).scale([0.05; 2]));
+    ///
+    /// let stats = buf.stats();
+    ///
+    /// assert_eq!(stats.count, 10000);
+    /// assert_eq!(stats.histogram.iter().sum::<usize>(), 10000);
+    /// ```
+    ///
+    /// [`stats::estimate()`]: crate::stats::estimate
+    pub fn stats(&self) -> NoiseStats {
+        NoiseStats::from_values(&self.buffer)
+    }
+
+    /// Creates a new noise buffer with the given `shape` and filled with the values returned by
+    /// the given function for each index.
+    pub(crate) fn from_fn(shape: [usize; D], mut f: impl FnMut([usize; D]) -> f64) -> Self {
+        let mut noisebuf = Self::new_empty(shape);
+        for point in noisebuf.tensor_indices() {
+            let idx = noisebuf.flat_index(point);
+            noisebuf.buffer[idx] = f(point);
+        }
+        noisebuf
+    }
+
     fn new_empty(shape: [usize; D]) -> Self {
         let bufsize = shape.iter().product();
         Self {
@@ -125,6 +195,13 @@ impl<const D: usize> NoiseBuffer<D> {
     }
 }
 
+/// Computes the coordinate of a lattice point from its integral index, such that the same index
+/// always yields the same coordinate regardless of the buffer containing it.
+#[inline]
+pub(crate) fn lattice_coordinate(origin: f64, spacing: f64, index: i64) -> f64 {
+    origin + index as f64 * spacing
+}
+
 pub(crate) fn precompute_flat_index_offsets(shape: &[usize]) -> Vec<usize> {
     let offsets = shape
         .iter()
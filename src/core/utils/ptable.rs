@@ -1,11 +1,23 @@
-use super::math::{Vec2, Vec3, Vec4};
-use rand::seq::SliceRandom;
-use rand_chacha::{ChaCha12Rng, rand_core::SeedableRng};
+use super::hasher::LatticeHasher;
+use crate::core::{
+    error::{NoiseError, validate_table_size},
+    graph::GraphNode,
+    sources::functional::constants::PERMUTATION_TABLE_SIZE,
+};
+use alloc::{sync::Arc, vec::Vec};
+use rand_chacha::{
+    ChaCha12Rng,
+    rand_core::{RngCore, SeedableRng},
+};
 
 /// A trait attached to valid seed types for noise sources.
 ///
 /// This trait is implemented for `u64` and `[u8; 32]`. In doing so, both
-/// approaches to seed the underlying RNG are exposed.
+/// approaches to seed the underlying RNG are exposed. It is also implemented
+/// for [`SeedSource`], which derives seeds for individual sources from a single
+/// seed.
+///
+/// [`SeedSource`]: crate::SeedSource
 pub trait Seed {
     fn construct_rng(self) -> ChaCha12Rng;
 }
@@ -22,60 +34,136 @@ impl Seed for [u8; 32] {
     }
 }
 
+/// The maximum number of indices in a permutation table, such that indices fit into 16 bits.
+pub(crate) const MAX_PERMUTATION_TABLE_SIZE: usize = 1 << 16;
+
+/// A table of pseudorandom permutations of indices used by lattice-based noise sources.
+///
+/// This is the default [`LatticeHasher`] of all lattice-based noise sources. As lattice points
+/// are hashed by looking up their coordinates modulo the size of the table in the table, noise
+/// generated using it repeats every 256 units by default. The size can be changed using
+/// [`SourceOptions`]. For noise which does not repeat, see [`IntegerHasher`].
+///
+/// Indices are stored as 16-bit integers, such that a doubled up table of 256 indices occupies
+/// 1 KiB. The table is reference-counted, such that cloning noise sources does not copy it. Sources
+/// constructed separately each build their own table, even if they use the same seed. To share a
+/// table between many sources, clone a source or the table passed to [`with_hasher()`].
+///
+/// As the table is stored on the heap rather than inline, sources using it are not [`Copy`]. This
+/// allows tables of sizes other than 256, but means they are not as cheap to copy as sources using
+/// [`IntegerHasher`], which are [`Copy`].
+///
+/// [`SourceOptions`]: crate::SourceOptions
+/// [`IntegerHasher`]: crate::IntegerHasher
+/// [`with_hasher()`]: crate::Simplex::with_hasher
 #[derive(Clone, Debug)]
-pub(crate) struct PermutationTable {
-    pub(crate) table: Vec<usize>,
+pub struct PermutationTable {
+    pub(crate) table: Arc<[u16]>,
+    /// The first output of the RNG constructed from the seed, identifying the seed in descriptions.
+    fingerprint: u64,
 }
 
 impl PermutationTable {
+    /// # Panics
+    ///
+    /// Panics if `w` is not within [1, 65536]. See [`try_new()`] for a fallible alternative.
+    ///
+    /// [`try_new()`]: PermutationTable::try_new
     pub(crate) fn new(seed: impl Seed, w: usize, doubleup: bool) -> Self {
-        let mut table = Vec::from_iter(0..w);
-        let mut rng = seed.construct_rng();
-        table.shuffle(&mut rng);
-        if doubleup {
-            table.extend_from_within(..);
+        match Self::try_new(seed, w, doubleup) {
+            Ok(table) => table,
+            Err(err) => panic!("{err}"),
         }
-        table.shrink_to_fit();
-        Self { table }
+    }
+
+    pub(crate) fn try_new(seed: impl Seed, w: usize, doubleup: bool) -> Result<Self, NoiseError> {
+        validate_table_size(w)?;
+        let rng = seed.construct_rng();
+        Ok(Self {
+            fingerprint: rng.clone().next_u64(),
+            table: build_table(rng, w, doubleup),
+        })
     }
 
     #[inline]
-    pub(crate) unsafe fn get(&self, i: usize) -> usize {
-        unsafe { *self.table.get_unchecked(i) }
+    unsafe fn get(&self, i: usize) -> usize {
+        unsafe { *self.table.get_unchecked(i) as usize }
+    }
+}
+
+fn build_table(mut rng: ChaCha12Rng, w: usize, doubleup: bool) -> Arc<[u16]> {
+    let mut table = Vec::from_iter((0..w).map(|i| i as u16));
+    shuffle(&mut table, &mut rng);
+    if doubleup {
+        table.extend_from_within(..);
+    }
+    table.into()
+}
+
+/// Shuffles the given slice using the Fisher-Yates algorithm.
+///
+/// This reproduces the shuffle of `rand` 0.8 while only relying on the output of the RNG, such
+/// that permutation tables do not change with the version of `rand`.
+fn shuffle<T>(slice: &mut [T], rng: &mut ChaCha12Rng) {
+    for i in (1..slice.len()).rev() {
+        slice.swap(i, sample_index(rng, i as u32 + 1) as usize);
+    }
+}
+
+/// Samples an integer uniformly from [0, bound) using widening multiplication with rejection.
+fn sample_index(rng: &mut ChaCha12Rng, bound: u32) -> u32 {
+    let zone = (bound << bound.leading_zeros()).wrapping_sub(1);
+    loop {
+        let product = rng.next_u32() as u64 * bound as u64;
+        if product as u32 <= zone {
+            return (product >> 32) as u32;
+        }
+    }
+}
+
+impl LatticeHasher for PermutationTable {
+    fn from_seed(seed: impl Seed) -> Self {
+        Self::new(seed, PERMUTATION_TABLE_SIZE, true)
     }
 
     #[inline]
-    pub(crate) unsafe fn hash1d(&self, i: usize) -> usize {
-        unsafe { self.get(i) }
+    fn index(&self, x: f64) -> usize {
+        // the table is doubled up, such that incremented indices remain within bounds. This is
+        // equivalent to rem_euclid(), which is not available without `std`.
+        let period = (self.table.len() / 2) as f64;
+        let x = x % period;
+        (if x < 0.0 { x + period } else { x }) as usize
     }
 
     #[inline]
-    pub(crate) unsafe fn hash2d(&self, i: usize, j: usize) -> usize {
-        unsafe { self.get(j + self.get(i)) }
+    fn hash_range(&self) -> usize {
+        self.table.len() / 2
     }
 
     #[inline]
-    pub(crate) unsafe fn hash3d(&self, i: usize, j: usize, k: usize) -> usize {
-        unsafe { self.get(k + self.get(j + self.get(i))) }
+    unsafe fn hash1d(&self, i: usize) -> usize {
+        unsafe { self.get(i) }
     }
 
     #[inline]
-    pub(crate) unsafe fn hash4d(&self, i: usize, j: usize, k: usize, l: usize) -> usize {
-        unsafe { self.get(l + self.get(k + self.get(j + self.get(i)))) }
+    unsafe fn hash2d(&self, i: usize, j: usize) -> usize {
+        unsafe { self.get(j + self.get(i)) }
     }
 
     #[inline]
-    pub(crate) unsafe fn hash2d_vec(&self, value: Vec2<usize>) -> usize {
-        unsafe { self.get(value.y + self.get(value.x)) }
+    unsafe fn hash3d(&self, i: usize, j: usize, k: usize) -> usize {
+        unsafe { self.get(k + self.get(j + self.get(i))) }
     }
 
     #[inline]
-    pub(crate) unsafe fn hash3d_vec(&self, value: Vec3<usize>) -> usize {
-        unsafe { self.get(value.z + self.get(value.y + self.get(value.x))) }
+    unsafe fn hash4d(&self, i: usize, j: usize, k: usize, l: usize) -> usize {
+        unsafe { self.get(l + self.get(k + self.get(j + self.get(i)))) }
     }
 
     #[inline]
-    pub(crate) unsafe fn hash4d_vec(&self, value: Vec4<usize>) -> usize {
-        unsafe { self.get(value.w + self.get(value.z + self.get(value.y + self.get(value.x)))) }
+    fn describe_parameters(&self, node: GraphNode) -> GraphNode {
+        node.with_parameter("hasher", format_args!("PermutationTable"))
+            .with_parameter("seed", format_args!("{:#018x}", self.fingerprint))
+            .with_parameter("table_size", self.hash_range())
     }
 }
@@ -0,0 +1,147 @@
+use super::ptable::Seed;
+use alloc::string::String;
+use rand_chacha::{
+    ChaCha12Rng,
+    rand_core::{RngCore, SeedableRng},
+};
+
+const FNV_OFFSET_BASIS: u64 = 0xCBF2_9CE4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01B3;
+
+/// A seed from which child seeds can be derived deterministically by name or index.
+///
+/// This allows seeding every source of a generator pipeline from a single seed, without
+/// inventing offsets such as `seed + 1` for individual sources, which tend to collide when the
+/// pipeline is changed. Deriving a child seed only depends on the seed and the given key, such
+/// that the seed of a source remains the same when other sources are added or removed.
+///
+/// [`SeedSource`] implements [`Seed`] and can thus be passed directly to sources. A [`SeedSource`]
+/// created from a seed seeds sources identically to the seed itself.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{Source, Generator, SeedSource};
+/// let world = SeedSource::new(42);
+/// let terrain = world.derive("terrain");
+///
+/// let generator = Source::simplex(terrain.derive("mountains"))
+///     .fbm(5, 0.013, 2.0, 0.5)
+///     .blend(
+///         Source::worley(terrain.derive("caves").derive(0)),
+///         Source::worley(terrain.derive("caves").derive(1)),
+///     );
+/// let value = generator.sample([0.2, 0.5]);
+///
+/// // deriving is deterministic
+/// assert_eq!(world.derive("terrain").derive(3), terrain.derive(3));
+/// assert_ne!(terrain.derive(3), terrain.derive(4));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SeedSource {
+    seed: [u8; 32],
+}
+
+impl SeedSource {
+    /// Creates a seed source from the given seed.
+    #[inline]
+    pub fn new(seed: impl Seed) -> Self {
+        Self {
+            seed: seed.construct_rng().get_seed(),
+        }
+    }
+
+    /// Derives a child seed from this seed and the given key, which is either a name or an index.
+    #[inline]
+    pub fn derive(self, key: impl SeedKey) -> Self {
+        let mut rng = ChaCha12Rng::from_seed(self.seed);
+        rng.set_stream(key.digest());
+        let mut seed = [0; 32];
+        rng.fill_bytes(&mut seed);
+        Self { seed }
+    }
+
+    /// Returns the seed as a byte array.
+    #[inline]
+    pub fn to_bytes(self) -> [u8; 32] {
+        self.seed
+    }
+}
+
+impl Seed for SeedSource {
+    fn construct_rng(self) -> ChaCha12Rng {
+        ChaCha12Rng::from_seed(self.seed)
+    }
+}
+
+impl From<u64> for SeedSource {
+    #[inline]
+    fn from(seed: u64) -> Self {
+        Self::new(seed)
+    }
+}
+
+impl From<[u8; 32]> for SeedSource {
+    #[inline]
+    fn from(seed: [u8; 32]) -> Self {
+        Self::new(seed)
+    }
+}
+
+/// A trait for keys from which child seeds are derived using [`SeedSource::derive()`].
+///
+/// This trait is implemented for string types, which are used as names, and for integer types,
+/// which are used as indices. Names and indices are hashed with distinct prefixes, such that a
+/// name and an index with the same textual representation yield different digests. Integers of
+/// different types representing the same value yield the same digest.
+pub trait SeedKey {
+    /// Returns a digest of the key which is stable across platforms and versions.
+    fn digest(&self) -> u64;
+}
+
+impl SeedKey for str {
+    #[inline]
+    fn digest(&self) -> u64 {
+        fnv1a(b'n', self.as_bytes())
+    }
+}
+
+impl SeedKey for String {
+    #[inline]
+    fn digest(&self) -> u64 {
+        self.as_str().digest()
+    }
+}
+
+impl<K: SeedKey + ?Sized> SeedKey for &K {
+    #[inline]
+    fn digest(&self) -> u64 {
+        (**self).digest()
+    }
+}
+
+macro_rules! impl_seed_key_for_integers {
+    ($($t:ty)*) => {$(
+        impl SeedKey for $t {
+            #[inline]
+            fn digest(&self) -> u64 {
+                // sign-extend, such that the same value yields the same digest regardless of type
+                fnv1a(b'i', &(*self as i128).to_le_bytes())
+            }
+        }
+    )*};
+}
+
+impl_seed_key_for_integers!(u8 u16 u32 u64 usize i8 i16 i32 i64 isize);
+
+/// Computes the 64-bit FNV-1a hash of the given bytes, prefixed by a tag separating kinds of keys.
+#[inline]
+fn fnv1a(tag: u8, bytes: &[u8]) -> u64 {
+    core::iter::once(&tag)
+        .chain(bytes)
+        .fold(FNV_OFFSET_BASIS, |hash, &byte| {
+            (hash ^ byte as u64).wrapping_mul(FNV_PRIME)
+        })
+}
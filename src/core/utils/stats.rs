@@ -0,0 +1,186 @@
+//! Empirical statistics of the values produced by generators.
+//!
+//! The analytical [`output_range()`] of a generator is a guaranteed, but often loose, bound. This
+//! module complements it by estimating the actual distribution of values, which is useful for
+//! choosing thresholds of adapters such as [`spline()`] or [`select()`]. The statistics of an
+//! already computed [`NoiseBuffer`] are available through [`NoiseBuffer::stats()`].
+//!
+//! ```
+//! # use libnoise::{Source, Generator, stats};
+//! let generator = Source::simplex(42).fbm(5, 0.013, 2.0, 0.5);
+//!
+//! let stats = stats::estimate(&generator, [(0.0, 1000.0); 2], 10000, 7);
+//!
+//! // half of all values lie below the median
+//! let median = stats.percentiles[50];
+//! assert!(stats.min <= median && median <= stats.max);
+//! ```
+//!
+//! [`output_range()`]: crate::Generator::output_range
+//! [`spline()`]: crate::Generator::spline
+//! [`select()`]: crate::Generator::select
+//! [`NoiseBuffer`]: crate::NoiseBuffer
+//! [`NoiseBuffer::stats()`]: crate::NoiseBuffer::stats
+
+use crate::core::{generator::Generator, utils::ptable::Seed};
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
+use num_traits::Float;
+use rand_chacha::rand_core::RngCore;
+
+/// The number of equal-width bins of the histogram of [`NoiseStats`].
+pub const HISTOGRAM_BINS: usize = 64;
+
+/// Summary statistics of a collection of noise values.
+///
+/// NaN values are counted separately and excluded from all other statistics. If there are no
+/// values other than NaN, all statistics are NaN and all bins of the histogram are empty.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NoiseStats {
+    /// The number of values other than NaN.
+    pub count: usize,
+    /// The number of NaN values.
+    pub nan_count: usize,
+    /// The smallest value.
+    pub min: f64,
+    /// The largest value.
+    pub max: f64,
+    /// The arithmetic mean of all values.
+    pub mean: f64,
+    /// The population variance of all values.
+    pub variance: f64,
+    /// The number of values falling into each of [`HISTOGRAM_BINS`] equal-width bins spanning
+    /// `[min, max]`, where the maximum is counted towards the last bin.
+    pub histogram: [usize; HISTOGRAM_BINS],
+    /// The percentiles from 0 to 100, such that `percentiles[p]` is the `p`-th percentile. Values
+    /// between ranks are linearly interpolated.
+    pub percentiles: [f64; 101],
+}
+
+impl NoiseStats {
+    /// Computes the statistics of the given values.
+    pub fn from_values(values: &[f64]) -> Self {
+        let mut sorted = values
+            .iter()
+            .copied()
+            .filter(|x| !x.is_nan())
+            .collect::<Vec<f64>>();
+        sorted.sort_unstable_by(f64::total_cmp);
+        let count = sorted.len();
+        let nan_count = values.len() - count;
+        let mut histogram = [0; HISTOGRAM_BINS];
+        let (Some(&min), Some(&max)) = (sorted.first(), sorted.last()) else {
+            return Self {
+                count,
+                nan_count,
+                min: f64::NAN,
+                max: f64::NAN,
+                mean: f64::NAN,
+                variance: f64::NAN,
+                histogram,
+                percentiles: [f64::NAN; 101],
+            };
+        };
+        // Welford's algorithm avoids overflowing intermediate sums
+        let (mean, m2) = sorted
+            .iter()
+            .enumerate()
+            .fold((0.0, 0.0), |(mean, m2), (i, &x)| {
+                let new_mean = mean + (x - mean) / (i + 1) as f64;
+                (new_mean, m2 + (x - mean) * (x - new_mean))
+            });
+        let variance = m2 / count as f64;
+        let bin_width = (max - min) / HISTOGRAM_BINS as f64;
+        for x in &sorted {
+            let bin = match bin_width {
+                0.0 => 0,
+                _ => (((x - min) / bin_width) as usize).min(HISTOGRAM_BINS - 1),
+            };
+            histogram[bin] += 1;
+        }
+        let percentiles = core::array::from_fn(|p| {
+            let rank = p as f64 / 100.0 * (count - 1) as f64;
+            let lower = sorted[rank.floor() as usize];
+            let upper = sorted[rank.ceil() as usize];
+            lower + rank.fract() * (upper - lower)
+        });
+        Self {
+            count,
+            nan_count,
+            min,
+            max,
+            mean,
+            variance,
+            histogram,
+            percentiles,
+        }
+    }
+
+    /// Returns the standard deviation of all values.
+    #[inline]
+    pub fn std_dev(&self) -> f64 {
+        self.variance.sqrt()
+    }
+}
+
+/// Estimates the statistics of the values produced by `generator` within a bounding box.
+///
+/// The generator is sampled at `samples` points of the axis-aligned box given by a `(min, max)`
+/// pair for each dimension. The points follow a low-discrepancy sequence, which covers the box
+/// more evenly than uniformly random points and thus yields more accurate estimates for the same
+/// number of samples. The sequence is randomly shifted based on `seed`, so that estimates are
+/// reproducible for a given seed.
+///
+/// # Examples
+///
+/// Basic usage:
+///
+/// ```
+/// # use libnoise::{Source, Generator, stats};
+/// let generator = Source::simplex(42).scale([0.05; 2]);
+///
+/// let stats = stats::estimate(&generator, [(-100.0, 100.0); 2], 5000, 1);
+///
+/// assert_eq!(stats.count, 5000);
+/// assert!(-1.0 <= stats.min && stats.max <= 1.0);
+/// ```
+pub fn estimate<const D: usize, G: Generator<D>>(
+    generator: &G,
+    region: [(f64, f64); D],
+    samples: usize,
+    seed: impl Seed,
+) -> NoiseStats {
+    let mut rng = seed.construct_rng();
+    // uniform in [0, 1) using the 53 most significant bits, independent of the version of `rand`
+    let shift: [f64; D] =
+        core::array::from_fn(|_| (rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64);
+    let alpha = r_sequence_basis::<D>();
+    let values = (1..=samples)
+        .map(|n| {
+            let point = core::array::from_fn(|i| {
+                let (min, max) = region[i];
+                let t = (shift[i] + n as f64 * alpha[i]).fract();
+                min + t * (max - min)
+            });
+            generator.sample(point)
+        })
+        .collect::<Vec<f64>>();
+    NoiseStats::from_values(&values)
+}
+
+/// Computes the basis of the additive recurrence known as R-sequence, which uses the powers of
+/// the inverse of the unique positive root of `x^(D + 1) = x + 1`.
+#[inline]
+fn r_sequence_basis<const D: usize>() -> [f64; D] {
+    // newton's method converges quickly for this polynomial, only basic arithmetic is used such
+    // that the sequence is identical across platforms
+    let phi = (0..32).fold(2.0_f64, |x, _| {
+        let power = (0..D).fold(1.0, |acc, _| acc * x);
+        x - (power * x - x - 1.0) / ((D + 1) as f64 * power - 1.0)
+    });
+    let mut inverse_power = 1.0;
+    core::array::from_fn(|_| {
+        inverse_power /= phi;
+        inverse_power.fract()
+    })
+}
@@ -59,16 +59,76 @@
     feature = "image",
     doc = "[`Visualizer`] allows us to get such a visual representation of a given generator when using the `image` feature."
 )]
+//!
+//! # Determinism
+//!
+//! Noise is a pure function of the seed, the parameters of a generator, and the input point.
+//! Sampling the same generator at the same point yields bit-identical results across runs,
+//! threads, and platforms, as sources and adapters only rely on basic arithmetic, square roots,
+//! and rounding, all of which are exactly specified by IEEE 754. In particular, fused
+//! multiply-add operations, whose use would depend on the target, are avoided, and permutation
+//! tables are shuffled by the crate itself rather than by an external random number library.
+//!
+//! The exceptions are adapters which apply functions of the platform math library, namely
+//! [`exp()`], [`powi()`], [`powf()`], [`power()`], [`rotate()`], and [`rotate_quaternion()`], as
+//! well as closures passed to adapters such as [`lambda()`]. Their results may differ in the last
+//! bits across platforms.
+//!
+//! Outputs are also stable across versions. Changes to the output of existing sources or adapters
+//! for the same seed and parameters are considered breaking and only happen in major releases,
+//! except to fix outputs which are clearly defective, such as NaN values or visible artifacts.
+//! Such fixes are mentioned in the release notes.
+//!
+//! [`exp()`]: Generator::exp
+//! [`powi()`]: Generator::powi
+//! [`powf()`]: Generator::powf
+//! [`power()`]: Generator::power
+//! [`rotate()`]: Generator3D::rotate
+//! [`rotate_quaternion()`]: Generator3D::rotate_quaternion
+//! [`lambda()`]: Generator::lambda
+//!
+//! # `no_std` support
+//!
+//! The crate depends on the standard library through the `std` feature, which is enabled by
+//! default. Disabling default features makes the crate `no_std`, while still requiring `alloc`:
+//!
+//! ```toml
+//! [dependencies]
+//! libnoise = { version = "1.2", default-features = false }
+//! ```
+//!
+//! All sources and adapters remain available, except [`cached()`], which relies on thread-local
+//! storage. [`NoiseBuffer`], [`ChunkSampler`], and the `image`, `dev-tools`, and `profile`
+//! features require `std`. Without `std`, functions such as `exp()` or `sin()` are provided by
+//! [`libm`]. As the functions which sources and adapters rely on for determinism are exactly
+//! specified, noise is identical with and without `std`.
+//!
+//! [`cached()`]: Generator::cached
+//! [`libm`]: https://docs.rs/libm
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 mod core;
 pub mod prelude;
 pub use crate::core::adapters::*;
 #[cfg(feature = "dev-tools")]
 pub use crate::core::devtools;
+pub use crate::core::error::NoiseError;
 pub use crate::core::generator::*;
-pub use crate::core::source::Source;
+pub use crate::core::graph::GraphNode;
+#[cfg(feature = "profile")]
+pub use crate::core::profile;
+pub use crate::core::source::{Source, SourceOptions};
 pub use crate::core::sources::*;
+#[cfg(feature = "std")]
+pub use crate::core::utils::chunksampler::ChunkSampler;
+pub use crate::core::utils::hasher::{IntegerHasher, LatticeHasher};
+#[cfg(feature = "std")]
 pub use crate::core::utils::noisebuf::NoiseBuffer;
-pub use crate::core::utils::ptable::Seed;
+pub use crate::core::utils::ptable::{PermutationTable, Seed};
+pub use crate::core::utils::seed::{SeedKey, SeedSource};
+pub use crate::core::utils::stats;
 #[cfg(feature = "image")]
 pub use crate::core::utils::visualizer::Visualizer;
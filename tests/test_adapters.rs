@@ -91,7 +91,7 @@ proptest! {
             expected += amp * (value.abs() * 2.0 - 1.0);
             amp *= persistence;
         }
-        expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| acc + persistence.powi(octave as i32));
+        expected *= 1.0 / (0..octaves).fold((0.0, 1.0), |(sum, amp), _| (sum + amp, amp * persistence)).0;
         prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
     }
 
@@ -104,7 +104,7 @@ proptest! {
             expected += amp * (value.abs() * 2.0 - 1.0);
             amp *= persistence;
         }
-        expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| acc + persistence.powi(octave as i32));
+        expected *= 1.0 / (0..octaves).fold((0.0, 1.0), |(sum, amp), _| (sum + amp, amp * persistence)).0;
         prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
     }
 
@@ -117,7 +117,7 @@ proptest! {
             expected += amp * (value.abs() * 2.0 - 1.0);
             amp *= persistence;
         }
-        expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| acc + persistence.powi(octave as i32));
+        expected *= 1.0 / (0..octaves).fold((0.0, 1.0), |(sum, amp), _| (sum + amp, amp * persistence)).0;
         prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
     }
 
@@ -130,7 +130,7 @@ proptest! {
             expected += amp * (value.abs() * 2.0 - 1.0);
             amp *= persistence;
         }
-        expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| acc + persistence.powi(octave as i32));
+        expected *= 1.0 / (0..octaves).fold((0.0, 1.0), |(sum, amp), _| (sum + amp, amp * persistence)).0;
         prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
     }
 
@@ -140,31 +140,143 @@ proptest! {
     #[test]
     fn test_blend_1d(value_a in strategy_float_numeric!(), value_b in strategy_float_numeric!(), value_control in -1_f64..1.0, point in strategy_array_float_numeric!()) {
         let n = Source::<1>::constant(value_a).blend(Source::<1>::constant(value_b), Source::<1>::constant(value_control)).sample(point);
-        let expected = value_a + (value_control * 0.5 + 0.5) * (value_b - value_a);
+        let expected = match value_control * 0.5 + 0.5 {
+            0.0 => value_a,
+            1.0 => value_b,
+            t => value_a + t * (value_b - value_a),
+        };
         prop_assert_eq!(n, expected, "expected value {}, instead: {}", expected, n);
     }
 
     #[test]
     fn test_blend_2d(value_a in strategy_float_numeric!(), value_b in strategy_float_numeric!(), value_control in -1_f64..1.0, point in strategy_array_float_numeric!()) {
         let n = Source::<2>::constant(value_a).blend(Source::<2>::constant(value_b), Source::<2>::constant(value_control)).sample(point);
-        let expected = value_a + (value_control * 0.5 + 0.5) * (value_b - value_a);
+        let expected = match value_control * 0.5 + 0.5 {
+            0.0 => value_a,
+            1.0 => value_b,
+            t => value_a + t * (value_b - value_a),
+        };
         prop_assert_eq!(n, expected, "expected value {}, instead: {}", expected, n);
     }
 
     #[test]
     fn test_blend_3d(value_a in strategy_float_numeric!(), value_b in strategy_float_numeric!(), value_control in -1_f64..1.0, point in strategy_array_float_numeric!()) {
         let n = Source::<3>::constant(value_a).blend(Source::<3>::constant(value_b), Source::<3>::constant(value_control)).sample(point);
-        let expected = value_a + (value_control * 0.5 + 0.5) * (value_b - value_a);
+        let expected = match value_control * 0.5 + 0.5 {
+            0.0 => value_a,
+            1.0 => value_b,
+            t => value_a + t * (value_b - value_a),
+        };
         prop_assert_eq!(n, expected, "expected value {}, instead: {}", expected, n);
     }
 
     #[test]
     fn test_blend_4d(value_a in strategy_float_numeric!(), value_b in strategy_float_numeric!(), value_control in -1_f64..1.0, point in strategy_array_float_numeric!()) {
         let n = Source::<4>::constant(value_a).blend(Source::<4>::constant(value_b), Source::<4>::constant(value_control)).sample(point);
-        let expected = value_a + (value_control * 0.5 + 0.5) * (value_b - value_a);
+        let expected = match value_control * 0.5 + 0.5 {
+            0.0 => value_a,
+            1.0 => value_b,
+            t => value_a + t * (value_b - value_a),
+        };
         prop_assert_eq!(n, expected, "expected value {}, instead: {}", expected, n);
     }
 
+    #[test]
+    fn test_blend_clamped(value_a in strategy_float_numeric!(), value_b in strategy_float_numeric!(), value_control in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::constant(value_a).blend_clamped(Source::<2>::constant(value_b), Source::<2>::constant(value_control)).sample(point);
+        let expected = match (value_control * 0.5 + 0.5).clamp(0.0, 1.0) {
+            0.0 => value_a,
+            1.0 => value_b,
+            t => value_a + t * (value_b - value_a),
+        };
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_blend_lazy(value in strategy_float_numeric!(), value_control in prop::sample::select(vec![-1.0, 1.0]), point in strategy_array_float_numeric!()) {
+        let nan = Source::<2>::constant(f64::NAN);
+        let n = match value_control {
+            t if t < 0.0 => Source::<2>::constant(value).blend(nan, Source::<2>::constant(t)).sample(point),
+            t => nan.blend(Source::<2>::constant(value), Source::<2>::constant(t)).sample(point),
+        };
+        prop_assert_eq!(n, value, "expected value {}, instead: {}", value, n);
+    }
+
+    #[test]
+    fn test_blend_exact_ends(value_a in strategy_float_numeric!(), value_b in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::constant(value_a).blend(Source::<2>::constant(value_b), Source::<2>::constant(-1.0)).sample(point);
+        prop_assert_eq!(n, value_a, "expected value {}, instead: {}", value_a, n);
+        let n = Source::<2>::constant(value_a).blend(Source::<2>::constant(value_b), Source::<2>::constant(1.0)).sample(point);
+        prop_assert_eq!(n, value_b, "expected value {}, instead: {}", value_b, n);
+        // a + (b - a) rounds away from b here
+        let n = Source::<2>::constant(1e16).blend(Source::<2>::constant(1.0), Source::<2>::constant(1.0)).sample(point);
+        prop_assert_eq!(n, 1.0, "expected value {}, instead: {}", 1.0, n);
+    }
+
+    // =================================================================
+    // test adapter: cached
+    // =================================================================
+    #[test]
+    fn test_cached_1d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let generator = Source::<1>::simplex(seed).cached();
+        let expected = Source::<1>::simplex(seed).sample(point);
+        for _ in 0..2 {
+            let n = generator.sample(point);
+            prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+        }
+    }
+
+    #[test]
+    fn test_cached_2d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let generator = Source::<2>::simplex(seed).cached();
+        let expected = Source::<2>::simplex(seed).sample(point);
+        for _ in 0..2 {
+            let n = generator.sample(point);
+            prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+        }
+    }
+
+    #[test]
+    fn test_cached_3d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let generator = Source::<3>::simplex(seed).cached();
+        let expected = Source::<3>::simplex(seed).sample(point);
+        for _ in 0..2 {
+            let n = generator.sample(point);
+            prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+        }
+    }
+
+    #[test]
+    fn test_cached_4d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let generator = Source::<4>::simplex(seed).cached();
+        let expected = Source::<4>::simplex(seed).sample(point);
+        for _ in 0..2 {
+            let n = generator.sample(point);
+            prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+        }
+    }
+
+    #[test]
+    fn test_cached_evaluations(point_a in strategy_array_float_numeric!(), point_b in strategy_array_float_numeric!()) {
+        let evaluations = std::sync::atomic::AtomicUsize::new(0);
+        let generator = Source::<2>::custom(|point: [f64; 2]| {
+            evaluations.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            point[0] - point[1]
+        });
+        let count = || evaluations.load(std::sync::atomic::Ordering::Relaxed);
+        let cached = generator.cached();
+        // copies share the remembered sample
+        let pipeline = cached.add(1.0).sum(cached.mul(2.0)).cached();
+        pipeline.sample(point_a);
+        pipeline.sample(point_a);
+        cached.sample(point_a);
+        prop_assert_eq!(count(), 1);
+        let n = pipeline.sample(point_b);
+        let expected = (point_b[0] - point_b[1]) + 1.0 + (point_b[0] - point_b[1]) * 2.0;
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+        prop_assert_eq!(count(), if point_a.map(f64::to_bits) == point_b.map(f64::to_bits) { 1 } else { 2 });
+    }
+
     // =================================================================
     // test adapter: clamp
     // =================================================================
@@ -208,6 +320,41 @@ proptest! {
         prop_assert_eq!(n, expected, "expected value {}, instead: {}", expected, n);
     }
 
+    // =================================================================
+    // test adapter: difference
+    // =================================================================
+    #[test]
+    fn test_difference_1d(value_a in -1_f64..1.0, value_b in -1_f64..1.0, point in strategy_array_float_numeric!()) {
+        let n = Source::<1>::constant(value_a).difference(Source::<1>::constant(value_b)).sample(point);
+        let (a, b) = (value_a * 0.5 + 0.5, value_b * 0.5 + 0.5);
+        let expected = (a - b).abs() * 2.0 - 1.0;
+        prop_assert!((n - expected).abs() <= 1e-12 && (-1.0..=1.0).contains(&n), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_difference_2d(value_a in -1_f64..1.0, value_b in -1_f64..1.0, point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::constant(value_a).difference(Source::<2>::constant(value_b)).sample(point);
+        let (a, b) = (value_a * 0.5 + 0.5, value_b * 0.5 + 0.5);
+        let expected = (a - b).abs() * 2.0 - 1.0;
+        prop_assert!((n - expected).abs() <= 1e-12 && (-1.0..=1.0).contains(&n), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_difference_3d(value_a in -1_f64..1.0, value_b in -1_f64..1.0, point in strategy_array_float_numeric!()) {
+        let n = Source::<3>::constant(value_a).difference(Source::<3>::constant(value_b)).sample(point);
+        let (a, b) = (value_a * 0.5 + 0.5, value_b * 0.5 + 0.5);
+        let expected = (a - b).abs() * 2.0 - 1.0;
+        prop_assert!((n - expected).abs() <= 1e-12 && (-1.0..=1.0).contains(&n), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_difference_4d(value_a in -1_f64..1.0, value_b in -1_f64..1.0, point in strategy_array_float_numeric!()) {
+        let n = Source::<4>::constant(value_a).difference(Source::<4>::constant(value_b)).sample(point);
+        let (a, b) = (value_a * 0.5 + 0.5, value_b * 0.5 + 0.5);
+        let expected = (a - b).abs() * 2.0 - 1.0;
+        prop_assert!((n - expected).abs() <= 1e-12 && (-1.0..=1.0).contains(&n), "expected value {}, instead: {}", expected, n);
+    }
+
     // =================================================================
     // test adapter: displace
     // =================================================================
@@ -324,7 +471,7 @@ proptest! {
             expected += amp * value;
             amp *= persistence;
         }
-        expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| acc + persistence.powi(octave as i32));
+        expected *= 1.0 / (0..octaves).fold((0.0, 1.0), |(sum, amp), _| (sum + amp, amp * persistence)).0;
         prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
     }
 
@@ -337,7 +484,7 @@ proptest! {
             expected += amp * value;
             amp *= persistence;
         }
-        expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| acc + persistence.powi(octave as i32));
+        expected *= 1.0 / (0..octaves).fold((0.0, 1.0), |(sum, amp), _| (sum + amp, amp * persistence)).0;
         prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
     }
 
@@ -350,7 +497,7 @@ proptest! {
             expected += amp * value;
             amp *= persistence;
         }
-        expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| acc + persistence.powi(octave as i32));
+        expected *= 1.0 / (0..octaves).fold((0.0, 1.0), |(sum, amp), _| (sum + amp, amp * persistence)).0;
         prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
     }
 
@@ -363,10 +510,20 @@ proptest! {
             expected += amp * value;
             amp *= persistence;
         }
-        expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| acc + persistence.powi(octave as i32));
+        expected *= 1.0 / (0..octaves).fold((0.0, 1.0), |(sum, amp), _| (sum + amp, amp * persistence)).0;
         prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
     }
 
+    #[test]
+    fn test_try_fbm(octaves in 0_u32..10, frequency in strategy_float_numeric!(), lacunarity in strategy_float_numeric!(), persistence in strategy_float_numeric!()) {
+        let result = Source::<2>::simplex(42).try_fbm(octaves, frequency, lacunarity, persistence);
+        match (octaves, lacunarity) {
+            (0, _) => prop_assert_eq!(result.unwrap_err(), NoiseError::ZeroOctaves),
+            (_, l) if l <= 0.0 => prop_assert_eq!(result.unwrap_err(), NoiseError::NonPositiveLacunarity(l)),
+            _ => prop_assert!(result.is_ok()),
+        };
+    }
+
     // =================================================================
     // test adapter: lambda
     // =================================================================
@@ -402,6 +559,61 @@ proptest! {
         prop_assert_eq!(n, expected, "expected value {}, instead: {}", expected, n);
     }
 
+    // =================================================================
+    // test adapter: lerp
+    // =================================================================
+    #[test]
+    fn test_lerp_1d(value_a in strategy_float_numeric!(), value_b in strategy_float_numeric!(), t in 0_f64..1.0, point in strategy_array_float_numeric!()) {
+        let n = Source::<1>::constant(value_a).lerp(Source::<1>::constant(value_b), t).sample(point);
+        let expected = match t {
+            0.0 => value_a,
+            1.0 => value_b,
+            t => value_a + t * (value_b - value_a),
+        };
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_lerp_2d(value_a in strategy_float_numeric!(), value_b in strategy_float_numeric!(), t in 0_f64..1.0, point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::constant(value_a).lerp(Source::<2>::constant(value_b), t).sample(point);
+        let expected = match t {
+            0.0 => value_a,
+            1.0 => value_b,
+            t => value_a + t * (value_b - value_a),
+        };
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_lerp_3d(value_a in strategy_float_numeric!(), value_b in strategy_float_numeric!(), t in 0_f64..1.0, point in strategy_array_float_numeric!()) {
+        let n = Source::<3>::constant(value_a).lerp(Source::<3>::constant(value_b), t).sample(point);
+        let expected = match t {
+            0.0 => value_a,
+            1.0 => value_b,
+            t => value_a + t * (value_b - value_a),
+        };
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_lerp_4d(value_a in strategy_float_numeric!(), value_b in strategy_float_numeric!(), t in 0_f64..1.0, point in strategy_array_float_numeric!()) {
+        let n = Source::<4>::constant(value_a).lerp(Source::<4>::constant(value_b), t).sample(point);
+        let expected = match t {
+            0.0 => value_a,
+            1.0 => value_b,
+            t => value_a + t * (value_b - value_a),
+        };
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_lerp_lazy(value_a in strategy_float_numeric!(), value_b in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::constant(value_a).lerp(Source::<2>::constant(value_b), 0.0).sample(point);
+        prop_assert_eq!(n, value_a, "expected value {}, instead: {}", value_a, n);
+        let n = Source::<2>::constant(value_a).lerp(Source::<2>::constant(value_b), 1.0).sample(point);
+        prop_assert_eq!(n, value_b, "expected value {}, instead: {}", value_b, n);
+    }
+
     // =================================================================
     // test adapter: max
     // =================================================================
@@ -433,6 +645,37 @@ proptest! {
         prop_assert_eq!(n, expected, "expected value {}, instead: {}", expected, n);
     }
 
+    // =================================================================
+    // test adapter: max_all
+    // =================================================================
+    #[test]
+    fn test_max_all_1d(values in prop::collection::vec(strategy_float_numeric!(), 0..16), point in strategy_array_float_numeric!()) {
+        let n = Source::<1>::max_all(values.iter().map(|&value| Source::constant(value))).sample(point);
+        let expected = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_max_all_2d(values in prop::collection::vec(strategy_float_numeric!(), 0..16), point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::max_all(values.iter().map(|&value| Source::constant(value))).sample(point);
+        let expected = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_max_all_3d(values in prop::collection::vec(strategy_float_numeric!(), 0..16), point in strategy_array_float_numeric!()) {
+        let n = Source::<3>::max_all(values.iter().map(|&value| Source::constant(value))).sample(point);
+        let expected = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_max_all_4d(values in prop::collection::vec(strategy_float_numeric!(), 0..16), point in strategy_array_float_numeric!()) {
+        let n = Source::<4>::max_all(values.iter().map(|&value| Source::constant(value))).sample(point);
+        let expected = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
     // =================================================================
     // test adapter: min
     // =================================================================
@@ -464,6 +707,37 @@ proptest! {
         prop_assert_eq!(n, expected, "expected value {}, instead: {}", expected, n);
     }
 
+    // =================================================================
+    // test adapter: min_all
+    // =================================================================
+    #[test]
+    fn test_min_all_1d(values in prop::collection::vec(strategy_float_numeric!(), 0..16), point in strategy_array_float_numeric!()) {
+        let n = Source::<1>::min_all(values.iter().map(|&value| Source::constant(value))).sample(point);
+        let expected = values.iter().copied().fold(f64::INFINITY, f64::min);
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_min_all_2d(values in prop::collection::vec(strategy_float_numeric!(), 0..16), point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::min_all(values.iter().map(|&value| Source::constant(value))).sample(point);
+        let expected = values.iter().copied().fold(f64::INFINITY, f64::min);
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_min_all_3d(values in prop::collection::vec(strategy_float_numeric!(), 0..16), point in strategy_array_float_numeric!()) {
+        let n = Source::<3>::min_all(values.iter().map(|&value| Source::constant(value))).sample(point);
+        let expected = values.iter().copied().fold(f64::INFINITY, f64::min);
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_min_all_4d(values in prop::collection::vec(strategy_float_numeric!(), 0..16), point in strategy_array_float_numeric!()) {
+        let n = Source::<4>::min_all(values.iter().map(|&value| Source::constant(value))).sample(point);
+        let expected = values.iter().copied().fold(f64::INFINITY, f64::min);
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
     // =================================================================
     // test adapter: mul
     // =================================================================
@@ -526,6 +800,125 @@ proptest! {
         prop_assert_eq!(n, expected, "expected value {}, instead: {}", expected, n);
     }
 
+    // =================================================================
+    // test adapter: normalize
+    // =================================================================
+    #[test]
+    fn test_normalize_known_range(seed in prop::num::u64::ANY, persistence in 0.1_f64..1.0, point in prop::array::uniform2(-1000_f64..1000.0)) {
+        let generator = Source::<2>::simplex(seed).fbm(4, 0.013, 2.0, persistence).normalize(0.0, 1.0);
+        let n = generator.sample(point);
+        prop_assert!((-1e-12..=1.0 + 1e-12).contains(&n), "value not in [0, 1] range, instead: {}", n);
+        let (min, max) = generator.output_range().unwrap();
+        prop_assert!(min.abs() <= 1e-12 && (max - 1.0).abs() <= 1e-12, "expected range [0, 1], instead: [{}, {}]", min, max);
+    }
+
+    #[test]
+    fn test_normalize_constant(value in strategy_float_numeric!(), to_min in strategy_float_numeric!(), to_max in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+        let n = Source::<3>::constant(value).normalize(to_min, to_max).sample(point);
+        let expected = (to_min + to_max) / 2.0;
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_normalize_estimated_range(seed in prop::num::u64::ANY, samples in 1_usize..1000, estimate_seed in prop::num::u64::ANY) {
+        let region = [(-100.0, 100.0); 2];
+        let generator = Source::<2>::simplex(seed).scale([0.01; 2]).lambda(|x| x * 10.0 + 3.0).normalize_estimated(region, samples, estimate_seed, -1.0, 1.0);
+        prop_assert_eq!(generator.output_range(), None);
+        let stats = stats::estimate(&generator, region, samples, estimate_seed);
+        prop_assert!((stats.min + 1.0).abs() <= 1e-9 || stats.min == stats.max, "expected minimum -1, instead: {}", stats.min);
+        prop_assert!((stats.max - 1.0).abs() <= 1e-9 || stats.min == stats.max, "expected maximum 1, instead: {}", stats.max);
+    }
+
+    #[test]
+    fn test_normalize_unknown_range(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let generator = Source::<2>::simplex(seed).lambda(|x| x * 10.0);
+        let result = std::panic::catch_unwind(|| generator.clone().normalize(0.0, 1.0));
+        prop_assert!(result.is_err(), "expected panic for unknown output range");
+        prop_assert_eq!(generator.clone().try_normalize(0.0, 1.0).err(), Some(NoiseError::UnknownOutputRange));
+        // the estimated range is available regardless
+        let n = generator.normalize_estimated([(-100.0, 100.0); 2], 100, seed, 0.0, 1.0).sample(point);
+        prop_assert!(!n.is_nan(), "expected value other than NaN, instead: {}", n);
+    }
+
+    #[test]
+    fn test_normalize_unbounded_range(seed in prop::num::u64::ANY) {
+        let generator = Source::<2>::simplex(seed).mul(f64::INFINITY);
+        let result = std::panic::catch_unwind(|| generator.clone().normalize(0.0, 1.0));
+        prop_assert!(result.is_err(), "expected panic for unbounded output range");
+        let err = generator.try_normalize(0.0, 1.0).err();
+        prop_assert!(matches!(err, Some(NoiseError::UnboundedOutputRange { .. })), "expected unbounded output range, instead: {:?}", err);
+    }
+
+    #[test]
+    fn test_normalize_estimated_no_samples(seed in prop::num::u64::ANY, estimate_seed in prop::num::u64::ANY) {
+        let region = [(-100.0, 100.0); 2];
+        let generator = Source::<2>::simplex(seed);
+        let result = std::panic::catch_unwind(|| generator.clone().normalize_estimated(region, 0, estimate_seed, 0.0, 1.0));
+        prop_assert!(result.is_err(), "expected panic for zero samples");
+        prop_assert_eq!(generator.try_normalize_estimated(region, 0, estimate_seed, 0.0, 1.0).err(), Some(NoiseError::NoValidSamples));
+    }
+
+    #[test]
+    fn test_normalize_estimated_nan_samples(samples in 0_usize..100, estimate_seed in prop::num::u64::ANY) {
+        let region = [(-100.0, 100.0); 2];
+        let generator = Source::<2>::constant(f64::NAN);
+        let result = std::panic::catch_unwind(|| generator.normalize_estimated(region, samples, estimate_seed, 0.0, 1.0));
+        prop_assert!(result.is_err(), "expected panic for NaN samples");
+        prop_assert_eq!(generator.try_normalize_estimated(region, samples, estimate_seed, 0.0, 1.0).err(), Some(NoiseError::NoValidSamples));
+    }
+
+    #[test]
+    fn test_normalize_estimated_infinite_samples(samples in 1_usize..100, estimate_seed in prop::num::u64::ANY) {
+        let region = [(-100.0, 100.0); 2];
+        let generator = Source::<2>::constant(f64::INFINITY);
+        let result = std::panic::catch_unwind(|| generator.normalize_estimated(region, samples, estimate_seed, 0.0, 1.0));
+        prop_assert!(result.is_err(), "expected panic for infinite samples");
+        let err = generator.try_normalize_estimated(region, samples, estimate_seed, 0.0, 1.0).err();
+        prop_assert!(matches!(err, Some(NoiseError::UnboundedOutputRange { .. })), "expected unbounded output range, instead: {:?}", err);
+    }
+
+    #[test]
+    fn test_normalize_ridgedmulti(seed in prop::num::u64::ANY, octaves in 1_u32..8, attenuation in 1_f64..4.0) {
+        // the range of ridged multifractal noise is tight for attenuations of at least 1
+        let (min, max) = Source::<2>::simplex(seed).ridgedmulti(octaves, 0.013, 2.0, attenuation).output_range().unwrap();
+        prop_assert!((min + 1.0).abs() <= 1e-12 && (max - 1.0).abs() <= 1e-12, "expected range [-1, 1], instead: [{}, {}]", min, max);
+    }
+
+    // =================================================================
+    // test adapter: overlay
+    // =================================================================
+    #[test]
+    fn test_overlay_1d(value_a in -1_f64..1.0, value_b in -1_f64..1.0, point in strategy_array_float_numeric!()) {
+        let n = Source::<1>::constant(value_a).overlay(Source::<1>::constant(value_b)).sample(point);
+        let (a, b) = (value_a * 0.5 + 0.5, value_b * 0.5 + 0.5);
+        let expected = if a < 0.5 { 2.0 * a * b } else { 1.0 - 2.0 * (1.0 - a) * (1.0 - b) } * 2.0 - 1.0;
+        prop_assert!((n - expected).abs() <= 1e-12 && (-1.0..=1.0).contains(&n), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_overlay_2d(value_a in -1_f64..1.0, value_b in -1_f64..1.0, point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::constant(value_a).overlay(Source::<2>::constant(value_b)).sample(point);
+        let (a, b) = (value_a * 0.5 + 0.5, value_b * 0.5 + 0.5);
+        let expected = if a < 0.5 { 2.0 * a * b } else { 1.0 - 2.0 * (1.0 - a) * (1.0 - b) } * 2.0 - 1.0;
+        prop_assert!((n - expected).abs() <= 1e-12 && (-1.0..=1.0).contains(&n), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_overlay_3d(value_a in -1_f64..1.0, value_b in -1_f64..1.0, point in strategy_array_float_numeric!()) {
+        let n = Source::<3>::constant(value_a).overlay(Source::<3>::constant(value_b)).sample(point);
+        let (a, b) = (value_a * 0.5 + 0.5, value_b * 0.5 + 0.5);
+        let expected = if a < 0.5 { 2.0 * a * b } else { 1.0 - 2.0 * (1.0 - a) * (1.0 - b) } * 2.0 - 1.0;
+        prop_assert!((n - expected).abs() <= 1e-12 && (-1.0..=1.0).contains(&n), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_overlay_4d(value_a in -1_f64..1.0, value_b in -1_f64..1.0, point in strategy_array_float_numeric!()) {
+        let n = Source::<4>::constant(value_a).overlay(Source::<4>::constant(value_b)).sample(point);
+        let (a, b) = (value_a * 0.5 + 0.5, value_b * 0.5 + 0.5);
+        let expected = if a < 0.5 { 2.0 * a * b } else { 1.0 - 2.0 * (1.0 - a) * (1.0 - b) } * 2.0 - 1.0;
+        prop_assert!((n - expected).abs() <= 1e-12 && (-1.0..=1.0).contains(&n), "expected value {}, instead: {}", expected, n);
+    }
+
     // =================================================================
     // test adapter: pow
     // =================================================================
@@ -647,6 +1040,81 @@ proptest! {
         prop_assert_eq!(n, expected, "expected value {}, instead: {}", expected, n);
     }
 
+    // =================================================================
+    // test adapter: product_all
+    // =================================================================
+    #[test]
+    fn test_product_all_1d(values in prop::collection::vec(strategy_float_numeric!(), 0..16), point in strategy_array_float_numeric!()) {
+        let n = Source::<1>::product_all(values.iter().map(|&value| Source::constant(value))).sample(point);
+        let expected = values.iter().product::<f64>();
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_product_all_2d(values in prop::collection::vec(strategy_float_numeric!(), 0..16), point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::product_all(values.iter().map(|&value| Source::constant(value))).sample(point);
+        let expected = values.iter().product::<f64>();
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_product_all_3d(values in prop::collection::vec(strategy_float_numeric!(), 0..16), point in strategy_array_float_numeric!()) {
+        let n = Source::<3>::product_all(values.iter().map(|&value| Source::constant(value))).sample(point);
+        let expected = values.iter().product::<f64>();
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_product_all_4d(values in prop::collection::vec(strategy_float_numeric!(), 0..16), point in strategy_array_float_numeric!()) {
+        let n = Source::<4>::product_all(values.iter().map(|&value| Source::constant(value))).sample(point);
+        let expected = values.iter().product::<f64>();
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    // =================================================================
+    // test adapter: remap
+    // =================================================================
+    #[test]
+    fn test_remap_1d(value in -1e6_f64..1e6, from_min in -1e6_f64..1e6, from_width in 1e-3_f64..1e6, to_min in -1e6_f64..1e6, to_max in -1e6_f64..1e6, point in strategy_array_float_numeric!()) {
+        let from_max = from_min + from_width;
+        let n = Source::<1>::constant(value).remap(from_min, from_max, to_min, to_max).sample(point);
+        let expected = to_min + (value - from_min) / (from_max - from_min) * (to_max - to_min);
+        prop_assert!((n - expected).abs() <= 1e-6 * (1.0 + expected.abs()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_remap_2d(value in -1e6_f64..1e6, from_min in -1e6_f64..1e6, from_width in 1e-3_f64..1e6, to_min in -1e6_f64..1e6, to_max in -1e6_f64..1e6, point in strategy_array_float_numeric!()) {
+        let from_max = from_min + from_width;
+        let n = Source::<2>::constant(value).remap(from_min, from_max, to_min, to_max).sample(point);
+        let expected = to_min + (value - from_min) / (from_max - from_min) * (to_max - to_min);
+        prop_assert!((n - expected).abs() <= 1e-6 * (1.0 + expected.abs()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_remap_3d(value in -1e6_f64..1e6, from_min in -1e6_f64..1e6, from_width in 1e-3_f64..1e6, to_min in -1e6_f64..1e6, to_max in -1e6_f64..1e6, point in strategy_array_float_numeric!()) {
+        let from_max = from_min + from_width;
+        let n = Source::<3>::constant(value).remap(from_min, from_max, to_min, to_max).sample(point);
+        let expected = to_min + (value - from_min) / (from_max - from_min) * (to_max - to_min);
+        prop_assert!((n - expected).abs() <= 1e-6 * (1.0 + expected.abs()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_remap_4d(value in -1e6_f64..1e6, from_min in -1e6_f64..1e6, from_width in 1e-3_f64..1e6, to_min in -1e6_f64..1e6, to_max in -1e6_f64..1e6, point in strategy_array_float_numeric!()) {
+        let from_max = from_min + from_width;
+        let n = Source::<4>::constant(value).remap(from_min, from_max, to_min, to_max).sample(point);
+        let expected = to_min + (value - from_min) / (from_max - from_min) * (to_max - to_min);
+        prop_assert!((n - expected).abs() <= 1e-6 * (1.0 + expected.abs()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_remap_bounds(from_min in -1e6_f64..1e6, from_width in 1e-3_f64..1e6, to_min in -1e6_f64..1e6, to_max in -1e6_f64..1e6, point in strategy_array_float_numeric!()) {
+        let from_max = from_min + from_width;
+        let n = Source::<2>::constant(from_min).remap(from_min, from_max, to_min, to_max).sample(point);
+        prop_assert!((n - to_min).abs() <= 1e-6 * (1.0 + to_min.abs()), "expected value {}, instead: {}", to_min, n);
+        let n = Source::<2>::constant(from_max).remap(from_min, from_max, to_min, to_max).sample(point);
+        prop_assert!((n - to_max).abs() <= 1e-6 * (1.0 + to_max.abs()), "expected value {}, instead: {}", to_max, n);
+    }
+
     // =================================================================
     // test adapter: ridgedmulti
     // =================================================================
@@ -662,9 +1130,9 @@ proptest! {
             expected += layer;
             amp = (layer / attenuation).clamp(0.0, 1.0);
         }
-        expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| {
-            acc + (1.0 / attenuation).powi(octave as i32)
-        });
+        expected *= 1.0 / (0..octaves).fold((0.0, 1.0), |(sum, amp), _| {
+            (sum + amp, amp * (1.0 / attenuation))
+        }).0;
         expected = expected * 2.0 - 1.0;
         prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
     }
@@ -681,9 +1149,9 @@ proptest! {
             expected += layer;
             amp = (layer / attenuation).clamp(0.0, 1.0);
         }
-        expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| {
-            acc + (1.0 / attenuation).powi(octave as i32)
-        });
+        expected *= 1.0 / (0..octaves).fold((0.0, 1.0), |(sum, amp), _| {
+            (sum + amp, amp * (1.0 / attenuation))
+        }).0;
         expected = expected * 2.0 - 1.0;
         prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
     }
@@ -700,9 +1168,9 @@ proptest! {
             expected += layer;
             amp = (layer / attenuation).clamp(0.0, 1.0);
         }
-        expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| {
-            acc + (1.0 / attenuation).powi(octave as i32)
-        });
+        expected *= 1.0 / (0..octaves).fold((0.0, 1.0), |(sum, amp), _| {
+            (sum + amp, amp * (1.0 / attenuation))
+        }).0;
         expected = expected * 2.0 - 1.0;
         prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
     }
@@ -719,9 +1187,9 @@ proptest! {
             expected += layer;
             amp = (layer / attenuation).clamp(0.0, 1.0);
         }
-        expected *= 1.0 / (0..octaves).fold(0.0, |acc, octave| {
-            acc + (1.0 / attenuation).powi(octave as i32)
-        });
+        expected *= 1.0 / (0..octaves).fold((0.0, 1.0), |(sum, amp), _| {
+            (sum + amp, amp * (1.0 / attenuation))
+        }).0;
         expected = expected * 2.0 - 1.0;
         prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
     }
@@ -744,6 +1212,97 @@ proptest! {
         Source::<4>::simplex(seed).rotate(rotation).sample(point);
     }
 
+    #[test]
+    fn test_rotate_2d_convention(angle in -10_f64..10.0, point in prop::array::uniform2(-10_f64..10.0)) {
+        let n = Source::<2>::custom(|p| p[0] + 3.0 * p[1]).rotate([angle]).sample(point);
+        let (sin, cos) = angle.sin_cos();
+        let expected = (point[0] * cos - point[1] * sin) + 3.0 * (point[0] * sin + point[1] * cos);
+        prop_assert!((n - expected).abs() <= 1e-9, "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_rotate_3d_convention(rotation in prop::array::uniform3(-10_f64..10.0), point in prop::array::uniform3(-10_f64..10.0)) {
+        let n = Source::<3>::custom(|p| p[0] + 3.0 * p[1] + 7.0 * p[2]).rotate(rotation).sample(point);
+        // rotate clockwise in the xy-, zx- and yz-plane, in that order
+        let rotate_plane = |p: [f64; 3], i: usize, j: usize, angle: f64| {
+            let (sin, cos) = angle.sin_cos();
+            let mut r = p;
+            r[i] = p[i] * cos + p[j] * sin;
+            r[j] = -p[i] * sin + p[j] * cos;
+            r
+        };
+        let p = rotate_plane(point, 0, 1, rotation[2]);
+        let p = rotate_plane(p, 2, 0, rotation[1]);
+        let p = rotate_plane(p, 1, 2, rotation[0]);
+        let expected = p[0] + 3.0 * p[1] + 7.0 * p[2];
+        prop_assert!((n - expected).abs() <= 1e-9, "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_rotate_4d_xw_angle(angle in -10_f64..10.0, point in prop::array::uniform4(-10_f64..10.0)) {
+        // only rotation[3] nonzero, including the case recorded when testing length preservation
+        for (angle, point) in [(angle, point), (-5.340879779905645, [0.0, 2.679077064936102, 0.0, 0.0])] {
+            let (sin, cos) = angle.sin_cos();
+            let expected = [point[0], point[1] * cos - point[2] * sin, point[2] * cos, point[3]];
+            for (axis, expected) in expected.into_iter().enumerate() {
+                let n = Source::<4>::custom(move |p| p[axis]).rotate([0.0, 0.0, 0.0, angle, 0.0, 0.0]).sample(point);
+                prop_assert!((n - expected).abs() <= 1e-9, "expected value {}, instead: {}", expected, n);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rotate_from_matrix(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Rotate::<3, 3, _>::from_matrix(Source::simplex(seed), [[0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [1.0, 0.0, 0.0]]).sample(point);
+        let expected = Source::<3>::simplex(seed).sample([point[1], point[2], point[0]]);
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_rotate_from_axis_angle(axis in prop::array::uniform3(-1_f64..1.0), angle in -10_f64..10.0, point in prop::array::uniform3(-10_f64..10.0)) {
+        let norm = (axis[0] * axis[0] + axis[1] * axis[1] + axis[2] * axis[2]).sqrt();
+        prop_assume!(norm > 1e-3);
+        let generator = Source::<3>::custom(|p| p[0] + 3.0 * p[1] + 7.0 * p[2]);
+        let n = Rotate::from_axis_angle(generator, axis, angle).sample(point);
+        let [x, y, z] = axis.map(|a| a / norm * (angle / 2.0).sin());
+        let expected = generator.rotate_quaternion([x, y, z, (angle / 2.0).cos()]).sample(point);
+        prop_assert!((n - expected).abs() <= 1e-9, "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_rotate_look_at(direction in prop::array::uniform3(-10_f64..10.0), up in prop::array::uniform3(-10_f64..10.0)) {
+        let norm = (direction[0] * direction[0] + direction[1] * direction[1] + direction[2] * direction[2]).sqrt();
+        let cross = [up[1] * direction[2] - up[2] * direction[1], up[2] * direction[0] - up[0] * direction[2], up[0] * direction[1] - up[1] * direction[0]];
+        prop_assume!(norm > 1e-3 && cross.iter().map(|c| c * c).sum::<f64>() > 1e-3);
+        let generator = Rotate::look_at(Source::<3>::custom(|p| p[0] + 3.0 * p[1] + 7.0 * p[2]), direction, up);
+        // points on the direction map onto the positive z-axis
+        let n = generator.sample(direction);
+        let expected = 7.0 * norm;
+        prop_assert!((n - expected).abs() <= 1e-9 * expected, "expected value {}, instead: {}", expected, n);
+        // the up vector maps into the yz-plane with a positive y-component
+        let n = Rotate::look_at(Source::<3>::custom(|p| p[0]), direction, up).sample(up);
+        prop_assert!(n.abs() <= 1e-9 * norm.max(1.0) * 10.0, "expected value {}, instead: {}", 0.0, n);
+        let n = Rotate::look_at(Source::<3>::custom(|p| p[1]), direction, up).sample(up);
+        prop_assert!(n > 0.0, "expected positive value, instead: {}", n);
+    }
+
+    #[test]
+    fn test_rotate_quaternion(axis in prop::array::uniform3(-1_f64..1.0), angle in -10_f64..10.0, point in prop::array::uniform3(-10_f64..10.0)) {
+        let norm = (axis[0] * axis[0] + axis[1] * axis[1] + axis[2] * axis[2]).sqrt();
+        prop_assume!(norm > 1e-3);
+        let [x, y, z] = axis.map(|a| a / norm * (angle / 2.0).sin());
+        let generator = Source::<3>::custom(|p| p[0] * p[0] + p[1] * p[1] + p[2] * p[2]);
+        // rotations preserve the length of vectors
+        let n = generator.rotate_quaternion([x, y, z, (angle / 2.0).cos()]).sample(point);
+        let expected = generator.sample(point);
+        prop_assert!((n - expected).abs() <= 1e-9 * expected.max(1.0), "expected value {}, instead: {}", expected, n);
+        // the rotation axis is invariant under the rotation
+        let on_axis = axis.map(|a| a / norm);
+        let generator = Source::<3>::custom(move |p| p[0] - on_axis[0] + p[1] - on_axis[1] + p[2] - on_axis[2]);
+        let n = generator.rotate_quaternion([x * 3.0, y * 3.0, z * 3.0, (angle / 2.0).cos() * 3.0]).sample(on_axis);
+        prop_assert!(n.abs() <= 1e-9, "expected value {}, instead: {}", 0.0, n);
+    }
+
     // =================================================================
     // test adapter: scale
     // =================================================================
@@ -775,6 +1334,72 @@ proptest! {
         prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
     }
 
+    // =================================================================
+    // test adapter: scale_bias
+    // =================================================================
+    #[test]
+    fn test_scale_bias_1d(value in strategy_float_numeric!(), scale in strategy_float_numeric!(), bias in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+        let n = Source::<1>::constant(value).scale_bias(scale, bias).sample(point);
+        let expected = value * scale + bias;
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_scale_bias_2d(value in strategy_float_numeric!(), scale in strategy_float_numeric!(), bias in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::constant(value).scale_bias(scale, bias).sample(point);
+        let expected = value * scale + bias;
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_scale_bias_3d(value in strategy_float_numeric!(), scale in strategy_float_numeric!(), bias in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+        let n = Source::<3>::constant(value).scale_bias(scale, bias).sample(point);
+        let expected = value * scale + bias;
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_scale_bias_4d(value in strategy_float_numeric!(), scale in strategy_float_numeric!(), bias in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+        let n = Source::<4>::constant(value).scale_bias(scale, bias).sample(point);
+        let expected = value * scale + bias;
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    // =================================================================
+    // test adapter: screen
+    // =================================================================
+    #[test]
+    fn test_screen_1d(value_a in -1_f64..1.0, value_b in -1_f64..1.0, point in strategy_array_float_numeric!()) {
+        let n = Source::<1>::constant(value_a).screen(Source::<1>::constant(value_b)).sample(point);
+        let (a, b) = (value_a * 0.5 + 0.5, value_b * 0.5 + 0.5);
+        let expected = (1.0 - (1.0 - a) * (1.0 - b)) * 2.0 - 1.0;
+        prop_assert!((n - expected).abs() <= 1e-12 && (-1.0..=1.0).contains(&n), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_screen_2d(value_a in -1_f64..1.0, value_b in -1_f64..1.0, point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::constant(value_a).screen(Source::<2>::constant(value_b)).sample(point);
+        let (a, b) = (value_a * 0.5 + 0.5, value_b * 0.5 + 0.5);
+        let expected = (1.0 - (1.0 - a) * (1.0 - b)) * 2.0 - 1.0;
+        prop_assert!((n - expected).abs() <= 1e-12 && (-1.0..=1.0).contains(&n), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_screen_3d(value_a in -1_f64..1.0, value_b in -1_f64..1.0, point in strategy_array_float_numeric!()) {
+        let n = Source::<3>::constant(value_a).screen(Source::<3>::constant(value_b)).sample(point);
+        let (a, b) = (value_a * 0.5 + 0.5, value_b * 0.5 + 0.5);
+        let expected = (1.0 - (1.0 - a) * (1.0 - b)) * 2.0 - 1.0;
+        prop_assert!((n - expected).abs() <= 1e-12 && (-1.0..=1.0).contains(&n), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_screen_4d(value_a in -1_f64..1.0, value_b in -1_f64..1.0, point in strategy_array_float_numeric!()) {
+        let n = Source::<4>::constant(value_a).screen(Source::<4>::constant(value_b)).sample(point);
+        let (a, b) = (value_a * 0.5 + 0.5, value_b * 0.5 + 0.5);
+        let expected = (1.0 - (1.0 - a) * (1.0 - b)) * 2.0 - 1.0;
+        prop_assert!((n - expected).abs() <= 1e-12 && (-1.0..=1.0).contains(&n), "expected value {}, instead: {}", expected, n);
+    }
+
     // =================================================================
     // test adapter: select
     // =================================================================
@@ -826,6 +1451,219 @@ proptest! {
         prop_assert_eq!(n, expected, "expected value {}, instead: {}", expected, n);
     }
 
+    #[test]
+    fn test_select_edge_falloff(value_a in strategy_float_numeric!(), value_b in strategy_float_numeric!(), value_control in -2_f64..2.0, edge_falloff in 0_f64..0.5, point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::constant(value_a).select(Source::<2>::constant(value_b), Source::<2>::constant(value_control), -0.5, 0.5).with_edge_falloff(edge_falloff).sample(point);
+        let smoothstep = |t: f64| t * t * (3.0 - 2.0 * t);
+        let expected = match value_control {
+            t if t <= -0.5 - edge_falloff || t >= 0.5 + edge_falloff => value_b,
+            t if t >= -0.5 + edge_falloff && t <= 0.5 - edge_falloff => value_a,
+            t if t < 0.0 => value_b + smoothstep((t + 0.5 + edge_falloff) / (2.0 * edge_falloff)) * (value_a - value_b),
+            t => value_a + smoothstep((t - 0.5 + edge_falloff) / (2.0 * edge_falloff)) * (value_b - value_a),
+        };
+        prop_assert!(n == expected || (n - expected).abs() <= 1e-9 * expected.abs().max(1.0) || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_select_edge_falloff_nan(value_a in strategy_float_numeric!(), value_b in strategy_float_numeric!(), value_control in -2_f64..2.0, point in strategy_array_float_numeric!()) {
+        let select = Source::<2>::constant(value_a).select(Source::<2>::constant(value_b), Source::<2>::constant(value_control), -0.5, 0.5);
+        let n = select.with_edge_falloff(f64::NAN).sample(point);
+        let expected = select.sample(point);
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+        prop_assert_eq!(select.try_with_edge_falloff(f64::NAN).unwrap_err(), NoiseError::NanEdgeFalloff);
+        prop_assert!(select.try_with_edge_falloff(0.1).is_ok());
+    }
+
+    #[test]
+    fn test_try_select(selection_min in strategy_float_numeric!(), selection_max in strategy_float_numeric!()) {
+        let result = Source::<2>::simplex(42).try_select(Source::simplex(43), Source::simplex(44), selection_min, selection_max);
+        match selection_min <= selection_max {
+            true => prop_assert!(result.is_ok()),
+            false => prop_assert_eq!(result.unwrap_err(), NoiseError::EmptySelectionRange { min: selection_min, max: selection_max }),
+        };
+    }
+
+    // =================================================================
+    // test adapter: selector
+    // =================================================================
+    #[test]
+    fn test_selector(value_control in -2_f64..2.0, point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::constant(value_control)
+            .selector(vec![
+                (0.5, Source::constant(3.0).boxed()),
+                (-0.5, Source::constant(2.0).boxed()),
+                (-1.0, Source::constant(1.0).abs().boxed()),
+            ])
+            .sample(point);
+        let expected = match value_control {
+            t if t >= 0.5 => 3.0,
+            t if t >= -0.5 => 2.0,
+            _ => 1.0,
+        };
+        prop_assert_eq!(n, expected, "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_selector_edge_falloff(value_control in -2_f64..2.0, edge_falloff in 0_f64..1.0, point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::constant(value_control)
+            .selector([(-1.0, Source::constant(1.0)), (-0.5, Source::constant(2.0)), (0.5, Source::constant(3.0))])
+            .with_edge_falloff(edge_falloff)
+            .sample(point);
+        let falloff = edge_falloff.min(0.25);
+        let smoothstep = |t: f64| t * t * (3.0 - 2.0 * t);
+        let expected = match value_control {
+            t if t <= -0.5 - falloff => 1.0,
+            t if t < -0.5 + falloff => 1.0 + smoothstep((t + 0.5 + falloff) / (2.0 * falloff)),
+            t if t <= 0.5 - falloff => 2.0,
+            t if t < 0.5 + falloff => 2.0 + smoothstep((t - 0.5 + falloff) / (2.0 * falloff)),
+            _ => 3.0,
+        };
+        prop_assert!((n - expected).abs() <= 1e-9, "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_selector_edge_falloff_nan(value_control in -2_f64..2.0, point in strategy_array_float_numeric!()) {
+        let selector = Source::<2>::constant(value_control)
+            .selector([(-1.0, Source::constant(1.0)), (-0.5, Source::constant(2.0)), (0.5, Source::constant(3.0))]);
+        let n = selector.clone().with_edge_falloff(f64::NAN).sample(point);
+        let expected = selector.sample(point);
+        prop_assert_eq!(n, expected, "expected value {}, instead: {}", expected, n);
+        prop_assert_eq!(selector.clone().try_with_edge_falloff(f64::NAN).unwrap_err(), NoiseError::NanEdgeFalloff);
+        prop_assert!(selector.try_with_edge_falloff(0.1).is_ok());
+    }
+
+    #[test]
+    fn test_try_selector(threshold in strategy_float_numeric!() | prop::num::f64::QUIET_NAN) {
+        let result = Source::<2>::simplex(42).try_selector([(threshold, Source::simplex(43))]);
+        match threshold.is_nan() {
+            true => prop_assert_eq!(result.unwrap_err(), NoiseError::NanThreshold),
+            false => prop_assert!(result.is_ok()),
+        };
+        let result = Source::<2>::simplex(42).try_selector(Vec::<(f64, Simplex<2>)>::new());
+        prop_assert_eq!(result.unwrap_err(), NoiseError::EmptySelector);
+    }
+
+    // =================================================================
+    // test adapter: shared
+    // =================================================================
+    #[test]
+    fn test_shared_1d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let generator = Source::<1>::simplex(seed).shared();
+        let n = generator.clone().sample(point);
+        let expected = Source::<1>::simplex(seed).sample(point);
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_shared_2d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let generator = Source::<2>::simplex(seed).shared();
+        let n = generator.clone().sample(point);
+        let expected = Source::<2>::simplex(seed).sample(point);
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_shared_3d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let generator = Source::<3>::simplex(seed).shared();
+        let n = generator.clone().sample(point);
+        let expected = Source::<3>::simplex(seed).sample(point);
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_shared_4d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let generator = Source::<4>::simplex(seed).shared();
+        let n = generator.clone().sample(point);
+        let expected = Source::<4>::simplex(seed).sample(point);
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_shared_clones(point in strategy_array_float_numeric!()) {
+        let generator = Source::<2>::custom(|point: [f64; 2]| point[0] - point[1]).shared();
+        let clones = [generator.clone(), generator.clone()];
+        // clones refer to the same generator instead of copying it
+        let shared = std::sync::Arc::new(Source::<2>::simplex(42));
+        let from_arc = Shared::from(shared.clone());
+        prop_assert_eq!(std::sync::Arc::strong_count(&shared), 2);
+        drop(from_arc.clone());
+        prop_assert_eq!(std::sync::Arc::strong_count(&shared), 2);
+        let n = from_arc.sample(point);
+        let expected = shared.sample(point);
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+        for clone in clones {
+            let n = clone.sample(point);
+            let expected = point[0] - point[1];
+            prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+        }
+    }
+
+    // =================================================================
+    // test adapter: smooth_max
+    // =================================================================
+    #[test]
+    fn test_smooth_max(value_a in -1_f64..1.0, value_b in -1_f64..1.0, radius in 0_f64..1.0, point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::constant(value_a).smooth_max(Source::<2>::constant(value_b), radius).sample(point);
+        let max = value_a.max(value_b);
+        let expected = match (value_a - value_b).abs() >= radius {
+            true => max,
+            false => max + (radius - (value_a - value_b).abs()).powi(2) / radius * 0.25,
+        };
+        prop_assert!((n - expected).abs() <= 1e-12, "expected value {}, instead: {}", expected, n);
+        prop_assert!(max <= n && n <= max + radius * 0.25, "expected value in [{}, {}], instead: {}", max, max + radius * 0.25, n);
+    }
+
+    // =================================================================
+    // test adapter: smooth_max_all
+    // =================================================================
+    #[test]
+    fn test_smooth_max_all(values in prop::collection::vec(-1_f64..1.0, 1..16), radius in 0_f64..1.0, point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::smooth_max_all(values.iter().map(|&value| Source::constant(value)), radius).sample(point);
+        let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        prop_assert!(max <= n && n <= max + radius * 0.25 * values.len() as f64, "expected value in [{}, {}], instead: {}", max, max + radius * 0.25 * values.len() as f64, n);
+    }
+
+    #[test]
+    fn test_smooth_max_all_outside_radius(value_a in -1_f64..1.0, value_b in -1_f64..1.0, radius in 0_f64..1.0, point in strategy_array_float_numeric!()) {
+        prop_assume!((value_a - value_b).abs() >= radius);
+        let n = Source::<2>::smooth_max_all([Source::constant(value_a), Source::constant(value_b)], radius).sample(point);
+        let expected = value_a.max(value_b);
+        prop_assert_eq!(n, expected, "expected value {}, instead: {}", expected, n);
+    }
+
+    // =================================================================
+    // test adapter: smooth_min
+    // =================================================================
+    #[test]
+    fn test_smooth_min(value_a in -1_f64..1.0, value_b in -1_f64..1.0, radius in 0_f64..1.0, point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::constant(value_a).smooth_min(Source::<2>::constant(value_b), radius).sample(point);
+        let min = value_a.min(value_b);
+        let expected = match (value_a - value_b).abs() >= radius {
+            true => min,
+            false => min - (radius - (value_a - value_b).abs()).powi(2) / radius * 0.25,
+        };
+        prop_assert!((n - expected).abs() <= 1e-12, "expected value {}, instead: {}", expected, n);
+        prop_assert!(min - radius * 0.25 <= n && n <= min, "expected value in [{}, {}], instead: {}", min - radius * 0.25, min, n);
+    }
+
+    // =================================================================
+    // test adapter: smooth_min_all
+    // =================================================================
+    #[test]
+    fn test_smooth_min_all(values in prop::collection::vec(-1_f64..1.0, 1..16), radius in 0_f64..1.0, point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::smooth_min_all(values.iter().map(|&value| Source::constant(value)), radius).sample(point);
+        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        prop_assert!(min - radius * 0.25 * values.len() as f64 <= n && n <= min, "expected value in [{}, {}], instead: {}", min - radius * 0.25 * values.len() as f64, min, n);
+    }
+
+    #[test]
+    fn test_smooth_min_all_outside_radius(value_a in -1_f64..1.0, value_b in -1_f64..1.0, radius in 0_f64..1.0, point in strategy_array_float_numeric!()) {
+        prop_assume!((value_a - value_b).abs() >= radius);
+        let n = Source::<2>::smooth_min_all([Source::constant(value_a), Source::constant(value_b)], radius).sample(point);
+        let expected = value_a.min(value_b);
+        prop_assert_eq!(n, expected, "expected value {}, instead: {}", expected, n);
+    }
+
     // =================================================================
     // test adapter: spline
     // =================================================================
@@ -873,6 +1711,107 @@ proptest! {
         };
     }
 
+    #[test]
+    fn test_spline_linear(value in (-10.0..15.0).prop_map(|x| x), point in strategy_array_float_numeric!()) {
+        let knot_vector = [-1.0, 1.5, 3.0, 7.5, 9.2];
+        let knots = [5.0, 0.0, -6.5, 2.0, -11.0];
+        let n = Source::<2>::constant(value).spline::<LinearSpline>(&knot_vector, &knots).sample(point);
+        match value {
+            x if x < *knot_vector.first().unwrap() || x > *knot_vector.last().unwrap() => prop_assert!(n.is_nan(), "expected value {}, instead: {}", f64::NAN, n),
+            x => {
+                let i = knot_vector.windows(2).position(|w| w[0] <= x && x <= w[1]).unwrap();
+                let (lo, hi) = (knots[i].min(knots[i + 1]), knots[i].max(knots[i + 1]));
+                prop_assert!(lo - 1e-9 <= n && n <= hi + 1e-9, "expected value in [{}, {}], instead: {}", lo, hi, n);
+            }
+        };
+    }
+
+    #[test]
+    fn test_spline_catmull_rom(value in (-10.0..15.0).prop_map(|x| x), point in strategy_array_float_numeric!()) {
+        let knot_vector = [-1.0, 1.5, 3.0, 7.5, 9.2];
+        let knots = [5.0, 0.0, -6.5, 2.0, -11.0];
+        let n = Source::<2>::constant(value).spline::<CatmullRomSpline>(&knot_vector, &knots).sample(point);
+        match value {
+            x if x < *knot_vector.first().unwrap() || x > *knot_vector.last().unwrap() => prop_assert!(n.is_nan(), "expected value {}, instead: {}", f64::NAN, n),
+            _ => prop_assert!(!n.is_nan(), "expected value {}, instead: {}", f64::NAN, n),
+        };
+    }
+
+    #[test]
+    fn test_spline_monotone_cubic(value in (-10.0..15.0).prop_map(|x| x), point in strategy_array_float_numeric!()) {
+        let knot_vector = [-1.0, 1.5, 3.0, 7.5, 9.2];
+        let knots = [5.0, 0.0, -6.5, 2.0, -11.0];
+        let n = Source::<2>::constant(value).spline::<MonotoneCubicSpline>(&knot_vector, &knots).sample(point);
+        match value {
+            x if x < *knot_vector.first().unwrap() || x > *knot_vector.last().unwrap() => prop_assert!(n.is_nan(), "expected value {}, instead: {}", f64::NAN, n),
+            x => {
+                let i = knot_vector.windows(2).position(|w| w[0] <= x && x <= w[1]).unwrap();
+                let (lo, hi) = (knots[i].min(knots[i + 1]), knots[i].max(knots[i + 1]));
+                prop_assert!(lo - 1e-9 <= n && n <= hi + 1e-9, "expected value in [{}, {}], instead: {}", lo, hi, n);
+            }
+        };
+    }
+
+    #[test]
+    fn test_spline_hermite(value in (-10.0..15.0).prop_map(|x| x), point in strategy_array_float_numeric!()) {
+        let knot_vector = [-1.0, 1.5, 3.0, 7.5, 9.2];
+        let knots = [5.0, 0.0, -6.5, 2.0, -11.0];
+        let tangents = [0.0, -1.0, 0.0, 0.0, 2.0];
+        let spline = HermiteSpline::with_tangents(&knot_vector, &knots, &tangents).unwrap();
+        let n = Source::<2>::constant(value).spline_with(spline).sample(point);
+        match value {
+            x if x < *knot_vector.first().unwrap() || x > *knot_vector.last().unwrap() => prop_assert!(n.is_nan(), "expected value {}, instead: {}", f64::NAN, n),
+            x => if let Some(i) = knot_vector.iter().position(|&k| k == x) {
+                prop_assert!((n - knots[i]).abs() < 1e-9, "expected value {}, instead: {}", knots[i], n);
+            } else {
+                prop_assert!(!n.is_nan(), "expected value {}, instead: {}", f64::NAN, n);
+            },
+        };
+    }
+
+    #[test]
+    fn test_spline_extrapolation_clamp(value in (-10.0..15.0).prop_map(|x| x), point in strategy_array_float_numeric!()) {
+        let knot_vector = [-1.0, 1.5, 3.0, 7.5, 9.2];
+        let knots = [5.0, 0.0, -6.5, 2.0, -11.0];
+        let n = Source::<2>::constant(value).spline::<NaturalCubicSpline>(&knot_vector, &knots).with_extrapolation(SplineExtrapolation::Clamp).sample(point);
+        let expected = Source::<2>::constant(value.clamp(-1.0, 9.2)).spline::<NaturalCubicSpline>(&knot_vector, &knots).sample(point);
+        prop_assert_eq!(n, expected, "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_spline_extrapolation_linear(value in (-10.0..15.0).prop_map(|x| x), point in strategy_array_float_numeric!()) {
+        let knot_vector = [-1.0, 1.5, 3.0, 7.5, 9.2];
+        let knots = [5.0, 0.0, -6.5, 2.0, -11.0];
+        let n = Source::<2>::constant(value).spline::<LinearSpline>(&knot_vector, &knots).with_extrapolation(SplineExtrapolation::Linear).sample(point);
+        let expected = match value {
+            x if x < -1.0 => 5.0 + (x + 1.0) * (0.0 - 5.0) / 2.5,
+            x if x > 9.2 => -11.0 + (x - 9.2) * (-11.0 - 2.0) / 1.7,
+            _ => Source::<2>::constant(value).spline::<LinearSpline>(&knot_vector, &knots).sample(point),
+        };
+        prop_assert!((n - expected).abs() < 1e-9, "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_try_spline(idx in 0_usize..5, swap in prop::bool::ANY) {
+        let mut knot_vector = [-1.0, 1.5, 3.0, 7.5, 9.2];
+        let mut knots = [5.0, 0.0, -6.5, 2.0, -11.0];
+        let expected = match swap {
+            true => {
+                knot_vector.swap(idx, (idx + 1) % 5);
+                SplineError::UnsortedKnotVector
+            }
+            false => {
+                knots[idx] = f64::NAN;
+                SplineError::NonFiniteKnots
+            }
+        };
+        let result = Source::<2>::constant(0.0).try_spline::<NaturalCubicSpline>(&knot_vector, &knots);
+        prop_assert_eq!(result.unwrap_err(), expected);
+        let result = Source::<2>::constant(0.0).try_spline::<NaturalCubicSpline>(&knot_vector[..3], &knots[..3]);
+        let valid = matches!(result, Err(SplineError::NotEnoughKnots { required: 4, provided: 3, .. }));
+        prop_assert!(valid, "expected NotEnoughKnots error");
+    }
+
     // =================================================================
     // test adapter: sum
     // =================================================================
@@ -904,6 +1843,76 @@ proptest! {
         prop_assert_eq!(n, expected, "expected value {}, instead: {}", expected, n);
     }
 
+    // =================================================================
+    // test adapter: sum_all
+    // =================================================================
+    #[test]
+    fn test_sum_all_1d(values in prop::collection::vec(strategy_float_numeric!(), 0..16), point in strategy_array_float_numeric!()) {
+        let n = Source::<1>::sum_all(values.iter().map(|&value| Source::constant(value))).sample(point);
+        let expected = values.iter().sum::<f64>();
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_sum_all_2d(values in prop::collection::vec(strategy_float_numeric!(), 0..16), point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::sum_all(values.iter().map(|&value| Source::constant(value))).sample(point);
+        let expected = values.iter().sum::<f64>();
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_sum_all_3d(values in prop::collection::vec(strategy_float_numeric!(), 0..16), point in strategy_array_float_numeric!()) {
+        let n = Source::<3>::sum_all(values.iter().map(|&value| Source::constant(value))).sample(point);
+        let expected = values.iter().sum::<f64>();
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_sum_all_4d(values in prop::collection::vec(strategy_float_numeric!(), 0..16), point in strategy_array_float_numeric!()) {
+        let n = Source::<4>::sum_all(values.iter().map(|&value| Source::constant(value))).sample(point);
+        let expected = values.iter().sum::<f64>();
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_sum_all_boxed(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::sum_all([Source::simplex(seed).boxed(), Source::perlin(seed).boxed(), Source::constant(1.0).boxed()]).sample(point);
+        let expected = Source::<2>::simplex(seed).sum(Source::perlin(seed)).sum(Source::constant(1.0)).sample(point);
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    // =================================================================
+    // test adapter: transform
+    // =================================================================
+    #[test]
+    fn test_transform_identity_2d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::simplex(seed).transform([[1.0, 0.0], [0.0, 1.0]], [0.0; 2]).sample(point);
+        let expected = Source::<2>::simplex(seed).sample(point);
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_transform_2d(matrix in prop::array::uniform2(prop::array::uniform2(-10_f64..10.0)), offset in prop::array::uniform2(-10_f64..10.0), point in prop::array::uniform2(-10_f64..10.0)) {
+        let n = Source::<2>::custom(|[x, y]| x * 3.0 + y * 7.0).transform(matrix, offset).sample(point);
+        let x = matrix[0][0] * point[0] + matrix[0][1] * point[1] + offset[0];
+        let y = matrix[1][0] * point[0] + matrix[1][1] * point[1] + offset[1];
+        let expected = x * 3.0 + y * 7.0;
+        prop_assert!((n - expected).abs() <= 1e-9, "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_transform_scale_translate_3d(seed in prop::num::u64::ANY, scale in strategy_array_float_numeric!(), translation in strategy_array_float_numeric!(), point in strategy_array_float_numeric!()) {
+        let matrix = [[scale[0], 0.0, 0.0], [0.0, scale[1], 0.0], [0.0, 0.0, scale[2]]];
+        let n = Source::<3>::simplex(seed).transform(matrix, translation).sample(point);
+        let expected = Source::<3>::simplex(seed).translate(translation).scale(scale).sample(point);
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_transform_4d(seed in prop::num::u64::ANY, matrix in prop::array::uniform4(strategy_array_float_numeric!()), offset in strategy_array_float_numeric!(), point in strategy_array_float_numeric!()) {
+        Source::<4>::simplex(seed).transform(matrix, offset).sample(point);
+    }
+
     // =================================================================
     // test adapter: translate
     // =================================================================
@@ -934,4 +1943,14 @@ proptest! {
         let expected = Source::<4>::simplex(seed).sample([point[0] + translation[0], point[1] + translation[1], point[2] + translation[2], point[3] + translation[3]]);
         prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
     }
+
+    // =================================================================
+    // test adapter: weighted_sum
+    // =================================================================
+    #[test]
+    fn test_weighted_sum(weighted_values in prop::collection::vec((-1_f64..1.0, -1_f64..1.0), 0..16), point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::weighted_sum(weighted_values.iter().map(|&(weight, value)| (weight, Source::constant(value)))).sample(point);
+        let expected = weighted_values.iter().map(|(weight, value)| weight * value).sum::<f64>();
+        prop_assert_eq!(n, expected, "expected value {}, instead: {}", expected, n);
+    }
 }
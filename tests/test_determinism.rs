@@ -0,0 +1,415 @@
+//! Golden output vectors guarding against unintended changes of the generated noise.
+//!
+//! Every source and adapter is sampled at fixed seeds and points, and the results are compared
+//! against checked-in values. Outputs of generators which only rely on basic arithmetic and square
+//! roots must match exactly on every platform. Outputs of generators relying on floating point
+//! functions of the platform math library are compared with a small tolerance. See the
+//! determinism policy in the crate documentation for when these values may change. If a change
+//! is intended, the failing test prints the updated table.
+
+use libnoise::prelude::*;
+
+type Golden = &'static [(&'static str, [f64; 4])];
+
+/// Pipelines whose outputs depend on the platform math library, such as `exp`, `pow`, or `sin`.
+const PLATFORM_DEPENDENT: &[&str] = &[
+    "exp",
+    "powf",
+    "powi",
+    "power",
+    "rotate",
+    "rotate_quaternion",
+];
+
+const POINTS_1D: [[f64; 1]; 4] = [[0.37], [-13.7], [101.3], [-1000.25]];
+const POINTS_2D: [[f64; 2]; 4] = [
+    [0.37, -1.21],
+    [-13.7, 5.2],
+    [101.3, -77.9],
+    [-1000.25, 333.3],
+];
+const POINTS_3D: [[f64; 3]; 4] = [
+    [0.37, -1.21, 2.93],
+    [-13.7, 5.2, -0.45],
+    [101.3, -77.9, 42.1],
+    [-1000.25, 333.3, 12.75],
+];
+const POINTS_4D: [[f64; 4]; 4] = [
+    [0.37, -1.21, 2.93, -0.61],
+    [-13.7, 5.2, -0.45, 7.7],
+    [101.3, -77.9, 42.1, -3.3],
+    [-1000.25, 333.3, 12.75, 64.5],
+];
+
+macro_rules! sources {
+    ($d:literal) => {{
+        let options = SourceOptions { table_size: 1000 };
+        let sources: Vec<(&str, Box<dyn Generator<$d>>)> = vec![
+            ("checkerboard", Source::checkerboard().boxed()),
+            ("constant", Source::constant(0.25).boxed()),
+            ("improved_perlin", Source::improved_perlin(42).boxed()),
+            (
+                "improved_perlin_hashed",
+                Source::improved_perlin_hashed(42).boxed(),
+            ),
+            (
+                "improved_perlin_with",
+                Source::improved_perlin_with(42, options).boxed(),
+            ),
+            ("perlin", Source::perlin(42).boxed()),
+            ("perlin_hashed", Source::perlin_hashed(42).boxed()),
+            ("perlin_with", Source::perlin_with(42, options).boxed()),
+            ("simplex", Source::simplex(42).boxed()),
+            ("simplex_byte_array_seed", Source::simplex([7; 32]).boxed()),
+            (
+                "simplex_derived_seed",
+                Source::simplex(SeedSource::new(42).derive("a")).boxed(),
+            ),
+            ("simplex_hashed", Source::simplex_hashed(42).boxed()),
+            ("simplex_with", Source::simplex_with(42, options).boxed()),
+            ("value", Source::value(42).boxed()),
+            ("value_hashed", Source::value_hashed(42).boxed()),
+            ("value_with", Source::value_with(42, options).boxed()),
+            ("worley", Source::worley(42).boxed()),
+            ("worley_hashed", Source::worley_hashed(42).boxed()),
+            ("worley_with", Source::worley_with(42, options).boxed()),
+        ];
+        sources
+    }};
+}
+
+fn adapters() -> Vec<(&'static str, Box<dyn Generator<3>>)> {
+    let a = || Source::<3>::simplex(42);
+    let b = || Source::<3>::perlin(43);
+    let c = || Source::<3>::worley(44);
+    let s = Source::<3>::simplex;
+    vec![
+        ("abs", a().abs().boxed()),
+        ("difference", a().difference(b()).boxed()),
+        ("add", a().add(0.5).boxed()),
+        ("billow", a().billow(4, 0.5, 2.0, 0.5).boxed()),
+        ("blend", a().blend(b(), c()).boxed()),
+        ("blend_clamped", a().blend_clamped(b(), c()).boxed()),
+        ("cached", a().cached().boxed()),
+        ("clamp", a().clamp(-0.3, 0.4).boxed()),
+        ("displace_x", a().displace_x(b()).boxed()),
+        ("displace_y", a().displace_y(b()).boxed()),
+        ("displace_z", a().displace_z(b()).boxed()),
+        ("exp", a().exp().boxed()),
+        ("fbm", a().fbm(5, 0.013, 2.0, 0.5).boxed()),
+        ("instrument", a().instrument("golden").boxed()),
+        ("lambda", a().lambda(|x| x * x - 0.5).boxed()),
+        ("lerp", a().lerp(b(), 0.3).boxed()),
+        ("max", a().max(b()).boxed()),
+        ("max_all", Source::max_all([s(42), s(43)]).boxed()),
+        ("min", a().min(b()).boxed()),
+        ("min_all", Source::min_all([s(42), s(43)]).boxed()),
+        ("mul", a().mul(1.5).boxed()),
+        ("neg", a().neg().boxed()),
+        (
+            "normalize",
+            a().lambda(|x| x * 0.5)
+                .normalize_estimated([(-1000.0, 1000.0); 3], 10000, 0, 0.0, 1.0)
+                .boxed(),
+        ),
+        ("operators", (a() * 0.5 + b() - c() / 2.0).boxed()),
+        ("overlay", a().overlay(b()).boxed()),
+        ("powf", a().abs().powf(1.5).boxed()),
+        ("powi", a().powi(3).boxed()),
+        ("power", a().abs().power(b()).boxed()),
+        ("product", a().product(b()).boxed()),
+        ("product_all", Source::product_all([s(42), s(43)]).boxed()),
+        ("remap", a().remap(-1.0, 1.0, 0.0, 255.0).boxed()),
+        ("ridgedmulti", a().ridgedmulti(4, 0.5, 2.0, 2.0).boxed()),
+        ("rotate", a().rotate([0.3, -1.1, 2.0]).boxed()),
+        (
+            "rotate_quaternion",
+            a().rotate_quaternion([0.5, 0.5, 0.5, 0.5]).boxed(),
+        ),
+        ("scale", a().scale([0.5, 2.0, 0.25]).boxed()),
+        ("scale_bias", a().scale_bias(0.5, 0.25).boxed()),
+        ("screen", a().screen(b()).boxed()),
+        ("select", a().select(b(), c(), -0.2, 0.3).boxed()),
+        (
+            "selector",
+            c().selector([(-0.5, s(42)), (0.0, s(43)), (0.5, s(45))])
+                .with_edge_falloff(0.1)
+                .boxed(),
+        ),
+        ("shared", a().shared().boxed()),
+        ("smooth_max", a().smooth_max(b(), 0.3).boxed()),
+        (
+            "smooth_max_all",
+            Source::smooth_max_all([s(42), s(43)], 0.3).boxed(),
+        ),
+        ("smooth_min", a().smooth_min(b(), 0.3).boxed()),
+        (
+            "smooth_min_all",
+            Source::smooth_min_all([s(42), s(43)], 0.3).boxed(),
+        ),
+        (
+            "spline_catmull_rom",
+            a().spline::<CatmullRomSpline>(
+                &[-1.0, -0.5, 0.0, 0.5, 1.0],
+                &[0.0, 0.8, -0.2, 0.4, 1.0],
+            )
+            .boxed(),
+        ),
+        (
+            "spline_hermite",
+            a().spline_with(
+                HermiteSpline::with_tangents(
+                    &[-1.0, 0.0, 1.0],
+                    &[0.0, 0.5, 1.0],
+                    &[0.0, 2.0, -1.0],
+                )
+                .unwrap(),
+            )
+            .boxed(),
+        ),
+        (
+            "spline_linear",
+            a().spline::<LinearSpline>(&[-1.0, 0.0, 1.0], &[0.0, 0.8, 1.0])
+                .boxed(),
+        ),
+        (
+            "spline_monotone_cubic",
+            a().spline::<MonotoneCubicSpline>(
+                &[-1.0, -0.5, 0.0, 0.5, 1.0],
+                &[0.0, 0.8, 0.8, 0.9, 1.0],
+            )
+            .boxed(),
+        ),
+        (
+            "spline_natural_cubic",
+            a().spline::<NaturalCubicSpline>(
+                &[-1.0, -0.5, 0.0, 0.5, 1.0],
+                &[0.0, 0.8, -0.2, 0.4, 1.0],
+            )
+            .boxed(),
+        ),
+        ("sum", a().sum(b()).boxed()),
+        ("sum_all", Source::sum_all([s(42), s(43), s(45)]).boxed()),
+        (
+            "transform",
+            a().transform(
+                [[0.5, 1.0, 0.0], [0.0, 2.0, -1.0], [1.0, 0.0, 0.25]],
+                [3.0, -2.0, 1.0],
+            )
+            .boxed(),
+        ),
+        ("translate", a().translate([3.5, -2.25, 1.0]).boxed()),
+        (
+            "weighted_sum",
+            Source::weighted_sum([(0.5, s(42)), (2.0, s(43)), (-1.0, s(45))]).boxed(),
+        ),
+    ]
+}
+
+/// Compares the outputs of the given pipelines against the golden values.
+fn check<const D: usize>(
+    pipelines: Vec<(&str, Box<dyn Generator<D>>)>,
+    points: [[f64; D]; 4],
+    golden: Golden,
+) {
+    let actual = pipelines
+        .iter()
+        .map(|(name, generator)| (*name, points.map(|point| generator.sample(point))))
+        .collect::<Vec<_>>();
+    let matches = actual.len() == golden.len()
+        && actual
+            .iter()
+            .zip(golden)
+            .all(|((name, values), (golden_name, golden_values))| {
+                name == golden_name
+                    && values.iter().zip(golden_values).all(|(&n, &expected)| {
+                        match PLATFORM_DEPENDENT.contains(name) {
+                            true => (n - expected).abs() <= 1e-12 * expected.abs().max(1.0),
+                            false => n.to_bits() == expected.to_bits(),
+                        }
+                    })
+            });
+    let table = actual
+        .iter()
+        .map(|(name, values)| format!("    (\"{name}\", {values:?}),\n"))
+        .collect::<String>();
+    assert!(
+        matches,
+        "outputs differ from golden values, actual values:\n{table}"
+    );
+}
+
+#[test]
+fn test_determinism_sources_1d() {
+    check(sources!(1), POINTS_1D, GOLDEN_SOURCES_1D);
+}
+
+#[test]
+fn test_determinism_sources_2d() {
+    check(sources!(2), POINTS_2D, GOLDEN_SOURCES_2D);
+}
+
+#[test]
+fn test_determinism_sources_3d() {
+    check(sources!(3), POINTS_3D, GOLDEN_SOURCES_3D);
+}
+
+#[test]
+fn test_determinism_sources_4d() {
+    check(sources!(4), POINTS_4D, GOLDEN_SOURCES_4D);
+}
+
+#[test]
+fn test_determinism_adapters() {
+    check(adapters(), POINTS_3D, GOLDEN_ADAPTERS);
+}
+
+#[rustfmt::skip]
+const GOLDEN_SOURCES_1D: Golden = &[
+    ("checkerboard", [-1.0, -1.0, 1.0, 1.0]),
+    ("constant", [0.25, 0.25, 0.25, 0.25]),
+    ("improved_perlin", [0.878846356584, 0.7304640000000018, 0.7304639999999931, 0.29296875]),
+    ("improved_perlin_hashed", [-0.878846356584, 0.7304640000000018, 0.7304639999999931, 0.29296875]),
+    ("improved_perlin_with", [0.878846356584, 0.27383999999999953, -0.7304639999999931, -0.29296875]),
+    ("perlin", [0.90088488, 0.7728000000000015, 0.7727999999999939, 0.1875]),
+    ("perlin_hashed", [-0.90088488, 0.7728000000000015, 0.7727999999999939, 0.1875]),
+    ("perlin_with", [0.90088488, 0.16799999999999965, -0.7727999999999939, -0.1875]),
+    ("simplex", [0.34675578028878995, -0.5761074833126226, 0.6913287706613647, 0.9959082787137927]),
+    ("simplex_byte_array_seed", [-0.3448207742544405, -0.1152211922061049, 0.6913293415171514, -0.7469312090353445]),
+    ("simplex_derived_seed", [-0.35449580442618767, -0.9217718210719863, -0.11522128734873703, 0.9959082787137927]),
+    ("simplex_hashed", [0.09418709345530153, 0.23044352612378333, -0.8065502482953639, -0.6224426741961204]),
+    ("simplex_with", [0.3448207742544405, -0.11522147763399829, 0.3456653843283092, -0.49795413935689636]),
+    ("value", [0.4557551093750001, 0.23812499999999925, -0.7347500000000027, 0.33154296875]),
+    ("value_hashed", [-0.17748823437499994, -0.16812499999999897, 0.4861250000000046, 0.7705078125]),
+    ("value_with", [0.07314006800000006, 0.2881119999999995, -0.16340800000000444, -0.34587499999999993]),
+    ("worley", [0.2209375, -0.20156250000000142, -0.5015625000000057, 0.078125]),
+    ("worley_hashed", [-0.5525, -0.9703124999999986, 0.15781250000000568, -0.875]),
+    ("worley_with", [-0.986, -0.14600000000000146, -0.9640000000000056, 0.22599999999999998]),
+];
+
+#[rustfmt::skip]
+const GOLDEN_SOURCES_2D: Golden = &[
+    ("checkerboard", [-1.0, 1.0, 1.0, -1.0]),
+    ("constant", [0.25, 0.25, 0.25, 0.25]),
+    ("improved_perlin", [0.19240200957154602, -0.45422428073589893, 0.06278805385948184, 0.18570999470823937]),
+    ("improved_perlin_hashed", [-0.03235636830857377, 0.43541370053187745, -0.2679973044384037, 0.7094302585845018]),
+    ("improved_perlin_with", [0.27818729446842666, -0.3257790660912646, -0.6662666564441809, 0.5180149489009328]),
+    ("perlin", [-0.03561599999999987, -0.4550720000000002, -0.14035199999999867, 0.5499000000000047]),
+    ("perlin_hashed", [-0.34523126741711996, 0.33187200000000094, 0.04310399999999097, -0.1109999999999953]),
+    ("perlin_with", [-0.1140159557594399, 0.15992960000000023, -0.2748864000000035, 0.4593749999999994]),
+    ("simplex", [0.032463396635849524, -0.1625584633501793, -0.9228458841541507, -0.3047467407545787]),
+    ("simplex_byte_array_seed", [0.30235670632614076, -0.7021352998764224, -0.9228458841541507, 0.7377347758539132]),
+    ("simplex_derived_seed", [-0.96117820441934, -0.702134757123684, 0.9021625343440304, -0.3047467407545787]),
+    ("simplex_hashed", [0.41843323912345526, 0.7021347571145246, -0.116522797210325, 0.7377347758539132]),
+    ("simplex_with", [-0.5082491731928918, 0.702135299867263, -0.1235735789272932, -0.4210851083340705]),
+    ("value", [0.09299671865362491, -0.15866700000000034, -0.4808492500000009, -0.6397226562499841]),
+    ("value_hashed", [0.40772824143456265, -0.36265250000000016, 0.11102124999999918, -0.2474277343750091]),
+    ("value_with", [-0.41307287155436, 0.42655961600000003, -0.33317376000000176, -0.14308050000001016]),
+    ("worley", [0.22571666695702963, -0.6972739416464957, -0.9622081668048577, -0.4239882372891485]),
+    ("worley_hashed", [0.1389193154175803, -0.2668610801619863, -0.35886072471537844, -0.5498996422671278]),
+    ("worley_with", [-0.45331178904241953, -0.07223709925434207, -0.8359268455840384, 0.18579930848350168]),
+];
+
+#[rustfmt::skip]
+const GOLDEN_SOURCES_3D: Golden = &[
+    ("checkerboard", [-1.0, -1.0, 1.0, -1.0]),
+    ("constant", [0.25, 0.25, 0.25, 0.25]),
+    ("improved_perlin", [-0.02520426867099697, -0.02986929000742189, -0.3457066900614009, -0.2160075586527204]),
+    ("improved_perlin_hashed", [0.10197281034369604, -0.16032265825697384, 0.44786904985739373, -0.2605942795562245]),
+    ("improved_perlin_with", [0.19959067382376613, 0.13184772318781376, -0.12609434125186983, -0.3537020632246455]),
+    ("perlin", [0.14117689042003878, -0.12147361706666618, -0.04449971200000444, 0.42389687500000617]),
+    ("perlin_hashed", [0.25339868256080755, 0.07149936639999907, -0.08787112960000378, -0.18375781249999706]),
+    ("perlin_with", [-0.45828290035214897, -0.0387610048000004, 0.05815536639999669, -0.18371093749999293]),
+    ("simplex", [0.2634859836912158, -0.27865635787306114, -0.25728336411617864, -0.10378107914284643]),
+    ("simplex_byte_array_seed", [-0.010184442956443046, 0.33936460908440336, 0.25728336411617864, -0.37010817542604907]),
+    ("simplex_derived_seed", [0.09727110303908519, 0.37465697727299907, -0.7718500923485637, -0.17520127564068935]),
+    ("simplex_hashed", [-0.22970521133119667, 0.09302113893872811, 2.742162381070133e-14, -0.08258674214561354]),
+    ("simplex_with", [0.2664230029922707, -0.345353605361826, -0.7718500923485637, 0.043668632058057225]),
+    ("value", [0.004174570739387429, -0.44730641650000025, -0.0052666929999999335, -0.09677459716797443]),
+    ("value_hashed", [0.1329915674061395, -0.008346107000000158, 0.07106520000000316, 0.2702789306640536]),
+    ("value_with", [0.10060573915041315, -0.4021666270079993, 0.13603862118400234, -0.12090015624998185]),
+    ("worley", [0.22690835092214612, -0.10761139525708774, 0.6218900529166564, 0.029663789560088638]),
+    ("worley_hashed", [0.26150231309240946, -0.06686170890242549, 0.19134759076906582, 0.22886030786662204]),
+    ("worley_with", [0.5706622806956305, -0.6816982563666998, 0.04070168636358207, -0.13032189863144572]),
+];
+
+#[rustfmt::skip]
+const GOLDEN_SOURCES_4D: Golden = &[
+    ("checkerboard", [1.0, 1.0, 1.0, -1.0]),
+    ("constant", [0.25, 0.25, 0.25, 0.25]),
+    ("improved_perlin", [-0.2544577247676112, 0.08462390514274808, -0.020661133533256673, 0.28760470726816423]),
+    ("improved_perlin_hashed", [0.20858816668374264, 0.39155868995113186, -0.17518292028584997, -0.2393600011308299]),
+    ("improved_perlin_with", [-0.061838841491532764, 0.29882515758697537, 0.14270748819048593, -0.46928209418234845]),
+    ("perlin", [0.18874055582576824, 0.5613779517234361, 0.0177816636579316, 0.12957767316010838]),
+    ("perlin_hashed", [-0.20031804272649165, 0.09326563803742967, 0.3532908221582199, 0.03650876487138351]),
+    ("perlin_with", [0.1716752891492676, 0.04706845503885571, 0.3695875678889655, -0.12069149159805441]),
+    ("simplex", [-0.09404131337537927, -0.03533246768826638, -0.02883665719796026, 0.15422793243040284]),
+    ("simplex_byte_array_seed", [0.0534267908174126, 0.258414787540819, 0.09453259374312283, 0.15403681474411665]),
+    ("simplex_derived_seed", [0.0991215298954782, 0.055468874551295004, 0.08025372897285585, -0.1573067105899427]),
+    ("simplex_hashed", [0.0938419242023975, 0.33059986550669695, -0.10801968924088147, -0.1540989260413972]),
+    ("simplex_with", [-0.05458165953187868, -0.3254983067732209, -0.19535596532403676, -0.2152948388764943]),
+    ("value", [0.3265423447513067, -0.16831548978099953, 0.1901069721760038, -0.10502325439452243]),
+    ("value_hashed", [0.21957486752264943, 0.2138795712039998, -0.23518498507200536, 0.11770504760742839]),
+    ("value_with", [-0.09604374451317188, 0.046480576541440444, 0.24149590770483043, 0.025277765624999837]),
+    ("worley", [0.6521427552102601, -0.3588816686104275, 0.6385962158487197, 0.1394268107391834]),
+    ("worley_hashed", [0.09045284278768806, 0.6783490306697231, 0.6146265955225532, -0.12840226182745373]),
+    ("worley_with", [0.549612854876985, -0.0794914449066757, 0.2859393453814192, 0.0849092127915545]),
+];
+
+#[rustfmt::skip]
+const GOLDEN_ADAPTERS: Golden = &[
+    ("abs", [0.2634859836912158, 0.27865635787306114, 0.25728336411617864, 0.10378107914284643]),
+    ("difference", [-0.581552127437034, -0.6009166266602719, -0.9328772969161793, -0.9986795166428476]),
+    ("add", [0.7634859836912158, 0.22134364212693886, 0.24271663588382136, 0.3962189208571536]),
+    ("billow", [-0.09174892885418805, -0.3523526136993328, -0.4227275666983477, -0.7633830332706726]),
+    ("blend", [0.17599213187657764, -0.11427408842822093, -0.2866576924538569, -0.10409438837523673]),
+    ("blend_clamped", [0.17599213187657764, -0.11427408842822093, -0.2866576924538569, -0.10409438837523673]),
+    ("cached", [0.2634859836912158, -0.27865635787306114, -0.25728336411617864, -0.10378107914284643]),
+    ("clamp", [0.2634859836912158, -0.27865635787306114, -0.25728336411617864, -0.10378107914284643]),
+    ("displace_x", [0.2867831633220916, -0.12159434224693208, 0.8463748552743346, -0.00855879134641719]),
+    ("displace_y", [0.043249772461610964, -0.23823397550222436, -0.1160170247570141, 0.052541890484726385]),
+    ("displace_z", [0.3848099954076997, -0.2728856793426784, 0.16169904966190254, -0.08289496350053137]),
+    ("exp", [1.301459053161844, 0.7567999268714847, 0.7731491000110287, 0.9014226160335965]),
+    ("fbm", [0.15296539970700695, 0.3127928034799733, 0.08616459090120082, -0.1939845420045283]),
+    ("instrument", [0.2634859836912158, -0.27865635787306114, -0.25728336411617864, -0.10378107914284643]),
+    ("lambda", [-0.4305751363982724, -0.4223506342169205, -0.4338052705490618, -0.48922948761194623]),
+    ("lerp", [0.137951621922326, -0.15893134587114277, -0.2774201750413249, -0.10417722414999213]),
+    ("max", [0.2634859836912158, 0.12042701546666679, -0.25728336411617864, -0.10378107914284643]),
+    ("max_all", [0.2634859836912158, 0.12293863170949476, 2.742162381070133e-14, -0.10378107914284643]),
+    ("min", [-0.15496188887175025, -0.27865635787306114, -0.3244060671999994, -0.10510156249999876]),
+    ("min_all", [0.23123449495230866, -0.27865635787306114, -0.25728336411617864, -0.2147022124429063]),
+    ("mul", [0.3952289755368237, -0.4179845368095917, -0.3859250461742679, -0.15567161871426966]),
+    ("neg", [-0.2634859836912158, 0.27865635787306114, 0.25728336411617864, 0.10378107914284643]),
+    ("normalize", [0.6336214662838332, 0.3603437398476443, 0.3711172262426803, 0.4484931333139392]),
+    ("operators", [0.26768970222483723, 0.06919926760245146, -0.39066910519479014, 0.10573930177909155]),
+    ("overlay", [0.1493543805434876, -0.19178709592585852, -0.49822514700726317, -0.19797508806699604]),
+    ("powf", [0.13524968938496565, 0.14709687100773436, 0.13050211753032076, 0.033433148199857196]),
+    ("powi", [0.018292478478729685, -0.02163748946026604, -0.017030802679897657, -0.0011177753985536145]),
+    ("power", [1.229590528621716, 0.857375976761852, 1.553338189899802, 1.2688419139369884]),
+    ("product", [-0.04083028572402198, -0.03355775351946417, 0.08346428430891496, 0.010907553575849192]),
+    ("product_all", [0.06092704836585052, -0.03425763135406543, -7.055127623545544e-15, 0.022282027301681485]),
+    ("remap", [161.09446292063, 91.9713143711847, 94.69637107518722, 114.26791240928708]),
+    ("ridgedmulti", [-0.7902631965878422, -0.33807429392356325, -0.27579373373840876, 0.47441956097547267]),
+    ("rotate", [-0.7118266089365204, -0.21669806272918496, 0.1368836942855007, 0.3806177278166906]),
+    ("rotate_quaternion", [0.09015126916172908, -0.35666720770199795, 0.7718500923485362, -0.03096185830531722]),
+    ("scale", [0.23459307787942696, 0.38494602335401945, -0.8187766070864619, 0.18817956745547207]),
+    ("scale_bias", [0.3817429918456079, 0.11067182106346943, 0.12135831794191068, 0.1981094604285768]),
+    ("screen", [0.5746771902717438, 0.43766420555653496, 0.16742314218745324, 0.3901049023906529]),
+    ("select", [-0.15496188887175025, -0.27865635787306114, -0.25728336411617864, -0.10510156249999876]),
+    ("selector", [0.2634859836912158, -0.27865635787306114, -0.25728336411617864, -0.10378107914284643]),
+    ("shared", [0.2634859836912158, -0.27865635787306114, -0.25728336411617864, -0.10378107914284643]),
+    ("smooth_max", [0.2634859836912158, 0.12042701546666679, -0.21209016793369007, -0.029439867757842172]),
+    ("smooth_max_all", [0.3232270380933254, 0.12293863170949476, 0.0015205924843846147, -0.07398873094906833]),
+    ("smooth_min", [-0.15496188887175025, -0.27865635787306114, -0.369599263382488, -0.17944277388500302]),
+    ("smooth_min_all", [0.171493440550199, -0.27865635787306114, -0.25880395660053584, -0.2444945606366844]),
+    ("spline_catmull_rom", [0.021852871310700475, 0.3936863443204017, 0.33324172370924504, -0.06598418988265309]),
+    ("spline_hermite", [0.9228346719798401, 0.11517320925131047, 0.13388929271509042, 0.31824634728588796]),
+    ("spline_linear", [0.8526971967382432, 0.5770749137015511, 0.5941733087070571, 0.7169751366857229]),
+    ("spline_monotone_cubic", [0.8409059080983984, 0.8, 0.8, 0.8]),
+    ("spline_natural_cubic", [-0.0431471330550931, 0.3779931890860878, 0.3228825097240279, -0.04665572871315282]),
+    ("sum", [0.10852409481946554, -0.15822934240639436, -0.581689431316178, -0.2088826416428452]),
+    ("sum_all", [0.3665156553533522, -0.08950873318864908, 2.7422508708241367e-14, -0.555835447421737]),
+    ("transform", [0.6537206798152816, 0.02754031856405646, 0.14316176131804395, -0.3324869436330865]),
+    ("translate", [0.4924630749235568, 0.0660638602808062, -0.002555016343595564, 0.10272054736686907]),
+    ("weighted_sum", [0.7224168050403974, 0.04034009150754164, -0.3859250461742131, -0.24394280862125156]),
+];
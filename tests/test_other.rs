@@ -22,6 +22,14 @@ macro_rules! strategy_byte_array_seed {
     };
 }
 
+struct Ramp;
+
+impl Generator<1> for Ramp {
+    fn sample(&self, point: [f64; 1]) -> f64 {
+        point[0]
+    }
+}
+
 proptest! {
     // =================================================================
     // test [u8; 32] seeding
@@ -49,4 +57,322 @@ proptest! {
         let n = Source::<4>::simplex(seed).sample(point);
         prop_assert!((-1.0..=1.0).contains(&n) || n.is_nan(), "value not in [-1, 1] range, instead: {}", n);
     }
+
+    #[test]
+    fn test_seed_source(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::perlin(SeedSource::new(seed)).sample(point);
+        let expected = Source::<2>::perlin(seed).sample(point);
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+        let n = Source::<2>::perlin(SeedSource::from(seed).derive("a").derive(0)).sample(point);
+        let expected = Source::<2>::perlin(SeedSource::new(seed).derive("a").derive(0)).sample(point);
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_seed_source_derive(seed in strategy_byte_array_seed!(), name in "[a-z]{0,8}", index in prop::num::u32::ANY) {
+        let source = SeedSource::new(seed);
+        prop_assert_eq!(source.derive(name.as_str()), source.derive(name.clone()));
+        prop_assert_eq!(source.derive(index), source.derive(index as u64));
+        prop_assert_eq!(source.derive(-1_i32), source.derive(-1_i64));
+        prop_assert_ne!(source.derive(-1_i32), source.derive(u64::MAX));
+        prop_assert_ne!(source.derive(index), source.derive(index.to_string()));
+        prop_assert_ne!(source.derive(index), source.derive(index as u64 + 1));
+        prop_assert_ne!(source.derive(name.as_str()), source.derive(format!("{name}_")));
+        prop_assert_ne!(source.derive(name.as_str()).derive(index), source.derive(index).derive(name.as_str()));
+        prop_assert_ne!(source.derive(index), source);
+    }
+
+    #[test]
+    fn test_shared_permutation_tables(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let generator = Source::<3>::perlin(seed);
+        let expected = generator.sample(point);
+        // clones sharing the table, and sources constructed with the same seed on the same or
+        // another thread, or after the table was dropped, behave identically
+        let n = generator.clone().sample(point);
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+        let n = Source::<3>::perlin(seed).sample(point);
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+        let n = std::thread::spawn(move || Source::<3>::perlin(seed).sample(point)).join().unwrap();
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+        drop(generator);
+        let n = Source::<3>::perlin(seed).sample(point);
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    // =================================================================
+    // test describe
+    // =================================================================
+    #[test]
+    fn test_describe_parameters(seed in prop::num::u64::ANY, octaves in 1_u32..10, frequency in strategy_float_numeric!(), offset in strategy_float_numeric!()) {
+        let node = Source::<3>::simplex(seed).fbm(octaves, frequency, 2.0, 0.5).add(offset).describe();
+        prop_assert_eq!(&node.name, "Add");
+        prop_assert_eq!(&node.parameters, &[("offset".to_string(), format!("{offset:?}"))]);
+        let (label, fbm) = &node.children[0];
+        prop_assert_eq!(label, "");
+        prop_assert_eq!(&fbm.name, "Fbm");
+        prop_assert_eq!(&fbm.parameters[0], &("octaves".to_string(), octaves.to_string()));
+        prop_assert_eq!(&fbm.parameters[1], &("frequency".to_string(), format!("{frequency:?}")));
+        prop_assert_eq!(&fbm.children[0].1.name, "Simplex");
+    }
+
+    #[test]
+    fn test_describe_sources(seed in prop::num::u64::ANY, other_seed in prop::num::u64::ANY, table_size in 1_usize..4096) {
+        let parameters = |node: GraphNode| node.parameters.into_iter().collect::<std::collections::HashMap<_, _>>();
+        let table = parameters(Source::<2>::perlin_with(seed, SourceOptions { table_size }).describe());
+        prop_assert_eq!(&table["hasher"], "PermutationTable");
+        prop_assert_eq!(&table["table_size"], &table_size.to_string());
+        let hashed = parameters(Source::<2>::perlin_hashed(seed).describe());
+        prop_assert_eq!(&hashed["hasher"], "IntegerHasher");
+        prop_assert!(!hashed.contains_key("table_size"), "unexpected table size for hashed source");
+        // both hashers identify the seed by the same fingerprint
+        prop_assert_eq!(&table["seed"], &hashed["seed"]);
+        let other = parameters(Source::<2>::perlin(other_seed).describe());
+        prop_assert_eq!(table["seed"] == other["seed"], seed == other_seed, "fingerprints of seeds {} and {}: {}, {}", seed, other_seed, &table["seed"], &other["seed"]);
+    }
+
+    #[test]
+    fn test_describe_labels(seed in prop::num::u64::ANY, min in strategy_float_numeric!(), max in strategy_float_numeric!()) {
+        let node = Source::<2>::simplex(seed).select(Source::perlin(seed), Source::worley(seed), min, max).describe();
+        let labels = node.children.iter().map(|(label, child)| (label.as_str(), child.name.as_str())).collect::<Vec<_>>();
+        prop_assert_eq!(labels, [("a", "Simplex"), ("b", "Perlin"), ("control", "Worley")]);
+        for node in [
+            Source::<2>::simplex(seed).overlay(Source::perlin(seed)).describe(),
+            Source::<2>::simplex(seed).screen(Source::perlin(seed)).describe(),
+            Source::<2>::simplex(seed).difference(Source::perlin(seed)).describe(),
+        ] {
+            let labels = node.children.iter().map(|(label, child)| (label.as_str(), child.name.as_str())).collect::<Vec<_>>();
+            prop_assert_eq!(labels, [("a", "Simplex"), ("b", "Perlin")], "unexpected children of {}", node.name);
+        }
+    }
+
+    #[test]
+    fn test_describe_export(seed in prop::num::u64::ANY, weight in strategy_float_numeric!()) {
+        let generator = Source::<2>::weighted_sum([
+            (weight, Source::simplex(seed).boxed()),
+            (1.0, Source::value(seed).lambda(|x| x * 2.0).boxed()),
+            (0.5, Source::checkerboard().spline::<LinearSpline>(&[-1.0, 1.0], &[0.0, 1.0]).boxed()),
+        ]);
+        let node = generator.describe();
+        prop_assert_eq!(node.children[0].0.clone(), format!("weight {weight:?}"));
+        let text = node.to_string();
+        prop_assert_eq!(text.lines().count(), 7, "unexpected tree:\n{}", text);
+        let dot = node.to_dot();
+        prop_assert_eq!(dot.lines().filter(|line| line.contains("[label=") && !line.contains("->")).count(), 7, "unexpected graph:\n{}", dot);
+        prop_assert_eq!(dot.matches(" -> ").count(), 6, "unexpected graph:\n{}", dot);
+        prop_assert!(dot.starts_with("digraph {") && dot.ends_with("}\n"), "unexpected graph:\n{}", dot);
+    }
+
+    #[test]
+    fn test_describe_default(offset in strategy_float_numeric!()) {
+        prop_assert_eq!(Ramp.describe(), GraphNode::new("Ramp"));
+        prop_assert_eq!(Ramp.add(offset).describe().children[0].1.name.clone(), "Ramp");
+    }
+
+    // =================================================================
+    // test operator overloading
+    // =================================================================
+    #[test]
+    fn test_op_add_generator(value_a in strategy_float_numeric!(), value_b in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+        let n = (Source::<2>::constant(value_a) + Source::<2>::constant(value_b)).sample(point);
+        let expected = value_a + value_b;
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_op_add_scalar(value_a in strategy_float_numeric!(), value_b in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+        let n = (Source::<2>::constant(value_a) + value_b).sample(point);
+        let expected = value_a + value_b;
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+        let n = (value_b + Source::<2>::constant(value_a)).sample(point);
+        let expected = value_b + value_a;
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_op_sub_generator(value_a in strategy_float_numeric!(), value_b in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+        let n = (Source::<2>::constant(value_a) - Source::<2>::constant(value_b)).sample(point);
+        let expected = value_a - value_b;
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_op_sub_scalar(value_a in strategy_float_numeric!(), value_b in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+        let n = (Source::<2>::constant(value_a) - value_b).sample(point);
+        let expected = value_a - value_b;
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+        let n = (value_b - Source::<2>::constant(value_a)).sample(point);
+        let expected = value_b - value_a;
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_op_mul_generator(value_a in strategy_float_numeric!(), value_b in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+        let n = (Source::<2>::constant(value_a) * Source::<2>::constant(value_b)).sample(point);
+        let expected = value_a * value_b;
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_op_mul_scalar(value_a in strategy_float_numeric!(), value_b in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+        let n = (Source::<2>::constant(value_a) * value_b).sample(point);
+        let expected = value_a * value_b;
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+        let n = (value_b * Source::<2>::constant(value_a)).sample(point);
+        let expected = value_b * value_a;
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_op_div_generator(value_a in strategy_float_numeric!(), value_b in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+        let n = (Source::<2>::constant(value_a) / Source::<2>::constant(value_b)).sample(point);
+        let expected = value_a / value_b;
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_op_div_scalar(value_a in strategy_float_numeric!(), value_b in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+        let n = (Source::<2>::constant(value_a) / value_b).sample(point);
+        let expected = value_a / value_b;
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+        let n = (value_b / Source::<2>::constant(value_a)).sample(point);
+        let expected = value_b / value_a;
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_op_neg(value in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+        let n = (-Source::<2>::constant(value)).sample(point);
+        let expected = -value;
+        prop_assert_eq!(n, expected, "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_op_expression(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = ((Source::<3>::simplex(seed) + Source::perlin(seed).scale([2.0; 3]) * 0.5) / 1.5 - -Source::value(seed)).sample(point);
+        let expected = (Source::<3>::simplex(seed).sample(point) + Source::<3>::perlin(seed).scale([2.0; 3]).sample(point) * 0.5) / 1.5 - -Source::<3>::value(seed).sample(point);
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    // =================================================================
+    // test output range
+    // =================================================================
+    #[test]
+    fn test_output_range_constant(value in strategy_float_numeric!(), offset in strategy_float_numeric!(), scale in strategy_float_numeric!(), point in strategy_array_float_numeric!()) {
+        let generator = Source::<2>::constant(value).add(offset).mul(scale).abs();
+        let n = generator.sample(point);
+        let expected = ((value + offset) * scale).abs();
+        prop_assert_eq!(generator.output_range(), Some((expected, expected)).filter(|_| !expected.is_nan()));
+        prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_output_range_clamp(seed in prop::num::u64::ANY, min in -2_f64..2.0, max in -2_f64..2.0) {
+        // includes a case where the clamped range lies entirely below the range of the source
+        for (min, max) in [(min, max), (-1.483721933991082, -1.1167365573849795)] {
+            if min > max {
+                continue;
+            }
+            let expected = ((-1_f64).clamp(min, max), 1_f64.clamp(min, max));
+            prop_assert_eq!(Source::<2>::simplex(seed).clamp(min, max).output_range(), Some(expected));
+        }
+    }
+
+    #[test]
+    fn test_output_range_fbm(seed in prop::num::u64::ANY, octaves in 1_u32..8, persistence in 0.1_f64..1.0) {
+        let (min, max) = Source::<2>::simplex(seed).fbm(octaves, 0.013, 2.0, persistence).output_range().unwrap();
+        prop_assert!((min + 1.0).abs() <= 1e-12 && (max - 1.0).abs() <= 1e-12, "expected range [-1, 1], instead: [{}, {}]", min, max);
+    }
+
+    #[test]
+    fn test_output_range_unknown(seed in prop::num::u64::ANY) {
+        prop_assert_eq!(Source::<2>::simplex(seed).lambda(|x| x * 2.0).output_range(), None);
+        prop_assert_eq!(Source::<2>::custom(|p| p[0]).output_range(), None);
+        prop_assert_eq!(Source::<2>::simplex(seed).product(Source::custom(|p| p[0])).output_range(), None);
+    }
+
+    #[test]
+    fn test_output_range_contains_samples(seed in prop::num::u64::ANY, point in prop::array::uniform3(-1000_f64..1000.0)) {
+        let generators: [Box<dyn Generator<3>>; 7] = [
+            Source::simplex(seed).fbm(5, 0.013, 2.0, 0.5).abs().mul(0.5).add(0.5).boxed(),
+            Source::perlin(seed).billow(3, 0.02, 2.0, 0.6).exp().boxed(),
+            Source::value(seed).ridgedmulti(4, 0.02, 2.0, 2.0).powi(3).boxed(),
+            Source::worley(seed).blend(Source::constant(2.0), Source::simplex(seed)).boxed(),
+            Source::improved_perlin(seed).max(Source::checkerboard()).overlay(Source::simplex(seed)).smooth_min(Source::perlin(seed), 0.5).boxed(),
+            Source::simplex(seed).spline::<NaturalCubicSpline>(&[-1.0, -0.5, 0.5, 1.0], &[0.0, -1.0, 2.0, 0.5]).boxed(),
+            Source::simplex(seed).select(Source::constant(3.0), Source::perlin(seed), -0.2, 0.2).with_edge_falloff(0.1).boxed(),
+        ];
+        for generator in generators {
+            let (min, max) = generator.output_range().unwrap();
+            let n = generator.sample(point);
+            prop_assert!(min - 1e-12 <= n && n <= max + 1e-12, "value not in [{}, {}] range, instead: {}", min, max, n);
+        }
+    }
+
+    // =================================================================
+    // test profiling
+    // =================================================================
+    #[test]
+    fn test_profile_counts(samples in 0_usize..100, seed in prop::num::u64::ANY) {
+        let generator = Source::<2>::simplex(seed)
+            .instrument("test_profile_counts_inner")
+            .fbm(3, 0.013, 2.0, 0.5)
+            .instrument("test_profile_counts");
+        let count = |path: &[&str]| {
+            profile::report().entries.iter().find(|entry| entry.path == path).map_or(0, |entry| entry.samples)
+        };
+        let before = (count(&["test_profile_counts"]), count(&["test_profile_counts", "test_profile_counts_inner"]));
+        for x in 0..samples {
+            let n = generator.sample([x as f64, 0.5]);
+            let expected = Source::<2>::simplex(seed).fbm(3, 0.013, 2.0, 0.5).sample([x as f64, 0.5]);
+            prop_assert!(n == expected || (n.is_nan() && expected.is_nan()), "expected value {}, instead: {}", expected, n);
+        }
+        let after = (count(&["test_profile_counts"]), count(&["test_profile_counts", "test_profile_counts_inner"]));
+        prop_assert_eq!(after.0 - before.0, samples as u64);
+        prop_assert_eq!(after.1 - before.1, 3 * samples as u64);
+    }
+
+    #[test]
+    fn test_profile_report(seed in prop::num::u64::ANY) {
+        let generator = Source::<3>::worley(seed)
+            .instrument("test_profile_report_inner")
+            .instrument("test_profile_report");
+        generator.sample([0.5; 3]);
+        let report = profile::report();
+        let outer = report.entries.iter().find(|entry| entry.path == ["test_profile_report"]).unwrap();
+        let inner = report.entries.iter().find(|entry| entry.path == ["test_profile_report", "test_profile_report_inner"]).unwrap();
+        prop_assert!(inner.total_time <= outer.total_time && outer.self_time <= outer.total_time);
+        let text = report.to_string();
+        prop_assert!(text.lines().any(|line| line.ends_with("  test_profile_report_inner")), "missing entry in report:\n{}", text);
+        let folded = report.folded();
+        prop_assert!(folded.lines().any(|line| line.starts_with("test_profile_report;test_profile_report_inner ")), "missing entry in folded stacks:\n{}", folded);
+    }
+
+    #[test]
+    fn test_profile_threads(samples in 0_usize..50, threads in 1_usize..4, seed in prop::num::u64::ANY) {
+        let generator = Source::<2>::simplex(seed).instrument("test_profile_threads");
+        let count = || {
+            profile::report().entries.iter().find(|entry| entry.path == ["test_profile_threads"]).map_or(0, |entry| entry.samples)
+        };
+        let before = count();
+        std::thread::scope(|scope| {
+            for _ in 0..threads {
+                scope.spawn(|| (0..samples).for_each(|x| { generator.sample([x as f64, 0.5]); }));
+            }
+        });
+        prop_assert_eq!(count() - before, (threads * samples) as u64);
+    }
+
+    #[test]
+    fn test_profile_panic(seed in prop::num::u64::ANY) {
+        let panicking = Source::<2>::simplex(seed)
+            .lambda(|_| panic!("sampling failed"))
+            .instrument("test_profile_panic_inner");
+        let result = std::panic::catch_unwind(|| panicking.sample([0.5; 2]));
+        prop_assert!(result.is_err());
+        // the panicking generator must not remain on the path of subsequently sampled generators
+        Source::<2>::simplex(seed).instrument("test_profile_panic").sample([0.5; 2]);
+        let report = profile::report();
+        prop_assert!(report.entries.iter().any(|entry| entry.path == ["test_profile_panic"]), "missing entry in report:\n{}", report);
+        prop_assert!(report.entries.iter().all(|entry| entry.path.len() == 1 || entry.path[0] != "test_profile_panic_inner"), "unexpected nesting in report:\n{}", report);
+    }
 }
@@ -16,6 +16,60 @@ macro_rules! strategy_array_float_numeric {
     };
 }
 
+macro_rules! strategy_array_float_lattice {
+    () => {
+        prop::array::uniform(prop_oneof![-4_f64..4.0, -1e6_f64..1e6])
+    };
+}
+
+macro_rules! all_sources {
+    ($dim:literal, $seed:expr) => {{
+        let seed = $seed;
+        let generators: [(&str, Box<dyn Generator<$dim>>); 12] = [
+            ("checkerboard", Source::<$dim>::checkerboard().boxed()),
+            (
+                "improved_perlin",
+                Source::<$dim>::improved_perlin(seed).boxed(),
+            ),
+            ("perlin", Source::<$dim>::perlin(seed).boxed()),
+            ("simplex", Source::<$dim>::simplex(seed).boxed()),
+            ("value", Source::<$dim>::value(seed).boxed()),
+            ("worley", Source::<$dim>::worley(seed).boxed()),
+            (
+                "improved_perlin_hashed",
+                Source::<$dim>::improved_perlin_hashed(seed).boxed(),
+            ),
+            ("perlin_hashed", Source::<$dim>::perlin_hashed(seed).boxed()),
+            (
+                "simplex_hashed",
+                Source::<$dim>::simplex_hashed(seed).boxed(),
+            ),
+            ("value_hashed", Source::<$dim>::value_hashed(seed).boxed()),
+            ("worley_hashed", Source::<$dim>::worley_hashed(seed).boxed()),
+            ("constant", Source::<$dim>::constant(0.5).boxed()),
+        ];
+        generators
+    }};
+}
+
+fn lattice_sources(seed: u64) -> Vec<(&'static str, Box<dyn Generator<3>>)> {
+    vec![
+        ("improved_perlin", Source::improved_perlin(seed).boxed()),
+        ("perlin", Source::perlin(seed).boxed()),
+        ("simplex", Source::simplex(seed).boxed()),
+        ("value", Source::value(seed).boxed()),
+        ("worley", Source::worley(seed).boxed()),
+        (
+            "improved_perlin_hashed",
+            Source::improved_perlin_hashed(seed).boxed(),
+        ),
+        ("perlin_hashed", Source::perlin_hashed(seed).boxed()),
+        ("simplex_hashed", Source::simplex_hashed(seed).boxed()),
+        ("value_hashed", Source::value_hashed(seed).boxed()),
+        ("worley_hashed", Source::worley_hashed(seed).boxed()),
+    ]
+}
+
 proptest! {
     // =================================================================
     // test source: checkerboard
@@ -44,6 +98,26 @@ proptest! {
         prop_assert!((-1.0..=1.0).contains(&n), "value not in [-1, 1] range, instead: {}", n);
     }
 
+    #[test]
+    fn test_checkerboard_adjacent_cells(x in prop_oneof![-4_f64..4.0, -1e15_f64..1e15], point in strategy_array_float_numeric!(), axis in 0_usize..4) {
+        let generator = Source::<4>::checkerboard();
+        let mut point = point.map(|x| x.clamp(-1e15, 1e15));
+        point[axis] = x;
+        let n = generator.sample(point);
+        point[axis] += 1.0;
+        let expected = -n;
+        let n = generator.sample(point);
+        prop_assert_eq!(n, expected, "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_checkerboard_origin(x in 0_f64..1.0) {
+        let generator = Source::<1>::checkerboard();
+        let n = generator.sample([-x - f64::EPSILON]);
+        let expected = -generator.sample([x]);
+        prop_assert_eq!(n, expected, "expected value {}, instead: {}", expected, n);
+    }
+
     // =================================================================
     // test source: constant
     // =================================================================
@@ -134,6 +208,83 @@ proptest! {
         prop_assert!((-1.0..=1.0).contains(&n), "value not in [-1, 1] range, instead: {}", n);
     }
 
+    #[test]
+    fn test_improved_perlin_hashed_1d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<1>::improved_perlin_hashed(seed).sample(point);
+        prop_assert!((-1.0..=1.0).contains(&n), "value not in [-1, 1] range, instead: {}", n);
+    }
+
+    #[test]
+    fn test_improved_perlin_hashed_2d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::improved_perlin_hashed(seed).sample(point);
+        prop_assert!((-1.0..=1.0).contains(&n), "value not in [-1, 1] range, instead: {}", n);
+    }
+
+    #[test]
+    fn test_improved_perlin_hashed_3d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<3>::improved_perlin_hashed(seed).sample(point);
+        prop_assert!((-1.0..=1.0).contains(&n), "value not in [-1, 1] range, instead: {}", n);
+    }
+
+    #[test]
+    fn test_improved_perlin_hashed_4d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<4>::improved_perlin_hashed(seed).sample(point);
+        prop_assert!((-1.0..=1.0).contains(&n), "value not in [-1, 1] range, instead: {}", n);
+    }
+
+    #[test]
+    fn test_improved_perlin_2d_peak(seed in prop::num::u64::ANY, cell in prop::array::uniform2(-1000_i64..1000)) {
+        // the kernel attains its extrema at these offsets within a cell for some gradients
+        let offsets = [0.6509123343443224, 1.0 - 0.6509123343443224];
+        let generator = Source::<2>::improved_perlin(seed);
+        let (min, max) = generator.output_range().unwrap();
+        for offset in offsets {
+            for point in [[offset, 0.5], [0.5, offset]] {
+                let n = generator.sample([cell[0] as f64 + point[0], cell[1] as f64 + point[1]]);
+                prop_assert!(min <= n && n <= max, "value not in [{}, {}] range, instead: {}", min, max, n);
+            }
+        }
+    }
+
+    // =================================================================
+    // test source: output_range
+    // =================================================================
+    #[test]
+    fn test_output_range_1d(seed in prop::num::u64::ANY, point in strategy_array_float_lattice!()) {
+        for (name, generator) in all_sources!(1, seed) {
+            let (min, max) = generator.output_range().unwrap();
+            let n = generator.sample(point);
+            prop_assert!(min <= n && n <= max, "{}: value not in [{}, {}] range, instead: {}", name, min, max, n);
+        }
+    }
+
+    #[test]
+    fn test_output_range_2d(seed in prop::num::u64::ANY, point in strategy_array_float_lattice!()) {
+        for (name, generator) in all_sources!(2, seed) {
+            let (min, max) = generator.output_range().unwrap();
+            let n = generator.sample(point);
+            prop_assert!(min <= n && n <= max, "{}: value not in [{}, {}] range, instead: {}", name, min, max, n);
+        }
+    }
+
+    #[test]
+    fn test_output_range_3d(seed in prop::num::u64::ANY, point in strategy_array_float_lattice!()) {
+        for (name, generator) in all_sources!(3, seed) {
+            let (min, max) = generator.output_range().unwrap();
+            let n = generator.sample(point);
+            prop_assert!(min <= n && n <= max, "{}: value not in [{}, {}] range, instead: {}", name, min, max, n);
+        }
+    }
+
+    #[test]
+    fn test_output_range_4d(seed in prop::num::u64::ANY, point in strategy_array_float_lattice!()) {
+        for (name, generator) in all_sources!(4, seed) {
+            let (min, max) = generator.output_range().unwrap();
+            let n = generator.sample(point);
+            prop_assert!(min <= n && n <= max, "{}: value not in [{}, {}] range, instead: {}", name, min, max, n);
+        }
+    }
+
     // =================================================================
     // test source: perlin
     // =================================================================
@@ -161,6 +312,30 @@ proptest! {
         prop_assert!((-1.0..=1.0).contains(&n), "value not in [-1, 1] range, instead: {}", n);
     }
 
+    #[test]
+    fn test_perlin_hashed_1d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<1>::perlin_hashed(seed).sample(point);
+        prop_assert!((-1.0..=1.0).contains(&n), "value not in [-1, 1] range, instead: {}", n);
+    }
+
+    #[test]
+    fn test_perlin_hashed_2d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::perlin_hashed(seed).sample(point);
+        prop_assert!((-1.0..=1.0).contains(&n), "value not in [-1, 1] range, instead: {}", n);
+    }
+
+    #[test]
+    fn test_perlin_hashed_3d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<3>::perlin_hashed(seed).sample(point);
+        prop_assert!((-1.0..=1.0).contains(&n), "value not in [-1, 1] range, instead: {}", n);
+    }
+
+    #[test]
+    fn test_perlin_hashed_4d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<4>::perlin_hashed(seed).sample(point);
+        prop_assert!((-1.0..=1.0).contains(&n), "value not in [-1, 1] range, instead: {}", n);
+    }
+
     // =================================================================
     // test source: simplex
     // =================================================================
@@ -188,6 +363,42 @@ proptest! {
         prop_assert!((-1.0..=1.0).contains(&n) || n.is_nan(), "value not in [-1, 1] range, instead: {}", n);
     }
 
+    #[test]
+    fn test_simplex_hashed_1d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<1>::simplex_hashed(seed).sample(point);
+        prop_assert!((-1.0..=1.0).contains(&n), "value not in [-1, 1] range, instead: {}", n);
+    }
+
+    #[test]
+    fn test_simplex_hashed_2d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::simplex_hashed(seed).sample(point);
+        prop_assert!((-1.0..=1.0).contains(&n) || n.is_nan(), "value not in [-1, 1] range, instead: {}", n);
+    }
+
+    #[test]
+    fn test_simplex_hashed_3d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<3>::simplex_hashed(seed).sample(point);
+        prop_assert!((-1.0..=1.0).contains(&n) || n.is_nan(), "value not in [-1, 1] range, instead: {}", n);
+    }
+
+    #[test]
+    fn test_simplex_hashed_4d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<4>::simplex_hashed(seed).sample(point);
+        prop_assert!((-1.0..=1.0).contains(&n) || n.is_nan(), "value not in [-1, 1] range, instead: {}", n);
+    }
+
+    #[test]
+    fn test_simplex_1d_peak(seed in prop::num::u64::ANY, cell in -1000_i64..1000) {
+        // the kernel attains its extrema at these offsets within a cell for the largest gradients
+        let offsets = [0.23570226039551584, 1.0 - 0.23570226039551584];
+        let generator = Source::<1>::simplex(seed);
+        let (min, max) = generator.output_range().unwrap();
+        for offset in offsets {
+            let n = generator.sample([cell as f64 + offset]);
+            prop_assert!(min <= n && n <= max, "value not in [{}, {}] range, instead: {}", min, max, n);
+        }
+    }
+
     // =================================================================
     // test source: value
     // =================================================================
@@ -215,6 +426,41 @@ proptest! {
         prop_assert!((-1.0..=1.0).contains(&n), "value not in [-1, 1] range, instead: {}", n);
     }
 
+    #[test]
+    fn test_value_hashed_1d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<1>::value_hashed(seed).sample(point);
+        prop_assert!((-1.0..=1.0).contains(&n), "value not in [-1, 1] range, instead: {}", n);
+    }
+
+    #[test]
+    fn test_value_hashed_2d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::value_hashed(seed).sample(point);
+        prop_assert!((-1.0..=1.0).contains(&n), "value not in [-1, 1] range, instead: {}", n);
+    }
+
+    #[test]
+    fn test_value_hashed_3d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<3>::value_hashed(seed).sample(point);
+        prop_assert!((-1.0..=1.0).contains(&n), "value not in [-1, 1] range, instead: {}", n);
+    }
+
+    #[test]
+    fn test_value_hashed_4d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<4>::worley(seed).sample(point);
+        prop_assert!((-1.0..=1.0).contains(&n), "value not in [-1, 1] range, instead: {}", n);
+    }
+
+    #[test]
+    fn test_value_hashed_no_repetition(seed in prop::num::u64::ANY, x in -1e6_f64..1e6, y in -1e6_f64..1e6) {
+        let generator = Source::<2>::value_hashed(seed);
+        // lattice points 256 units apart are hashed independently, unlike with permutation tables
+        let repeats = (0..16).all(|i| {
+            let point = [(x + i as f64).floor(), y.floor()];
+            generator.sample(point) == generator.sample([point[0] + 256.0, point[1]])
+        });
+        prop_assert!(!repeats, "noise repeats every 256 units");
+    }
+
     // =================================================================
     // test source: worley
     // =================================================================
@@ -241,4 +487,120 @@ proptest! {
         let n = Source::<4>::worley(seed).sample(point);
         prop_assert!((-1.0..=1.0).contains(&n), "value not in [-1, 1] range, instead: {}", n);
     }
+
+    #[test]
+    fn test_worley_hashed_1d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<1>::worley_hashed(seed).sample(point);
+        prop_assert!((-1.0..=1.0).contains(&n), "value not in [-1, 1] range, instead: {}", n);
+    }
+
+    #[test]
+    fn test_worley_hashed_2d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<2>::worley_hashed(seed).sample(point);
+        prop_assert!((-1.0..=1.0).contains(&n), "value not in [-1, 1] range, instead: {}", n);
+    }
+
+    #[test]
+    fn test_worley_hashed_3d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<3>::worley_hashed(seed).sample(point);
+        prop_assert!((-1.0..=1.0).contains(&n), "value not in [-1, 1] range, instead: {}", n);
+    }
+
+    #[test]
+    fn test_worley_hashed_4d(seed in prop::num::u64::ANY, point in strategy_array_float_numeric!()) {
+        let n = Source::<4>::worley_hashed(seed).sample(point);
+        prop_assert!((-1.0..=1.0).contains(&n), "value not in [-1, 1] range, instead: {}", n);
+    }
+
+    // =================================================================
+    // test lattice coordinates
+    // =================================================================
+    #[test]
+    fn test_lattice_continuity(seed in prop::num::u64::ANY, point in strategy_array_float_lattice!()) {
+        for (name, generator) in lattice_sources(seed) {
+            let n = generator.sample(point);
+            let expected = generator.sample(point.map(|x| x + 1e-7));
+            prop_assert!((n - expected).abs() < 1e-3, "{}: expected value close to {}, instead: {}", name, expected, n);
+        }
+    }
+
+    #[test]
+    fn test_lattice_periodicity_across_origin(seed in prop::num::u64::ANY, point in prop::array::uniform3(-256_f64..0.0), axis in 0_usize..3) {
+        // sources using permutation tables repeat every 256 units in the negative range as well,
+        // except for simplex noise, as its lattice is skewed
+        let sources = lattice_sources(seed).into_iter().take(5).filter(|(name, _)| *name != "simplex");
+        for (name, generator) in sources {
+            let mut shifted = point;
+            shifted[axis] += 256.0;
+            let n = generator.sample(point);
+            let expected = generator.sample(shifted);
+            prop_assert!((n - expected).abs() < 1e-9, "{}: expected value {}, instead: {}", name, expected, n);
+        }
+    }
+
+    #[test]
+    fn test_lattice_distinct_negative_cells(seed in prop::num::u64::ANY, point in prop::array::uniform3(0_f64..1.0)) {
+        // every negative cell is assigned its own values, instead of sharing those of the origin
+        for (name, generator) in lattice_sources(seed).into_iter().filter(|(name, _)| name.starts_with("value")) {
+            let cells = (1..=8).map(|i| generator.sample(point.map(|x| x - i as f64))).collect::<Vec<f64>>();
+            prop_assert!(cells.iter().any(|&n| n != cells[0]), "{}: negative cells are identical: {:?}", name, cells);
+        }
+    }
+
+    // =================================================================
+    // test source options
+    // =================================================================
+    #[test]
+    fn test_source_options_default(seed in prop::num::u64::ANY, point in strategy_array_float_lattice!()) {
+        let n = Source::<3>::perlin_with(seed, SourceOptions::default()).sample(point);
+        let expected = Source::<3>::perlin(seed).sample(point);
+        prop_assert_eq!(n, expected, "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_source_options_table_size(seed in prop::num::u64::ANY, table_size in 1_usize..4096, point in strategy_array_float_lattice!()) {
+        let options = SourceOptions { table_size };
+        let generators: [(&str, Box<dyn Generator<3>>); 5] = [
+            ("improved_perlin", Source::improved_perlin_with(seed, options).boxed()),
+            ("perlin", Source::perlin_with(seed, options).boxed()),
+            ("simplex", Source::simplex_with(seed, options).boxed()),
+            ("value", Source::value_with(seed, options).boxed()),
+            ("worley", Source::worley_with(seed, options).boxed()),
+        ];
+        for (name, generator) in generators {
+            let n = generator.sample(point);
+            prop_assert!((-1.0..=1.0).contains(&n), "{}: value not in [-1, 1] range, instead: {}", name, n);
+        }
+    }
+
+    #[test]
+    fn test_source_options_period(seed in prop::num::u64::ANY, point in prop::array::uniform3(-1024_f64..0.0), axis in 0_usize..3) {
+        let generator = Source::<3>::value_with(seed, SourceOptions { table_size: 1024 });
+        let mut shifted = point;
+        shifted[axis] += 1024.0;
+        let n = generator.sample(point);
+        let expected = generator.sample(shifted);
+        prop_assert!((n - expected).abs() < 1e-9, "expected value {}, instead: {}", expected, n);
+    }
+
+    #[test]
+    fn test_source_options_invalid_table_size(seed in prop::num::u64::ANY, table_size in prop_oneof![Just(0_usize), 65537_usize..]) {
+        let result = std::panic::catch_unwind(|| Source::<2>::perlin_with(seed, SourceOptions { table_size }));
+        prop_assert!(result.is_err(), "expected panic for table size {}", table_size);
+        let options = SourceOptions { table_size };
+        let expected = NoiseError::InvalidTableSize(table_size);
+        prop_assert_eq!(Source::<2>::try_improved_perlin_with(seed, options).unwrap_err(), expected.clone());
+        prop_assert_eq!(Source::<2>::try_perlin_with(seed, options).unwrap_err(), expected.clone());
+        prop_assert_eq!(Source::<2>::try_simplex_with(seed, options).unwrap_err(), expected.clone());
+        prop_assert_eq!(Source::<2>::try_value_with(seed, options).unwrap_err(), expected.clone());
+        prop_assert_eq!(Source::<2>::try_worley_with(seed, options).unwrap_err(), expected);
+    }
+
+    #[test]
+    fn test_source_options_try(seed in prop::num::u64::ANY, table_size in 1_usize..4096, point in strategy_array_float_lattice!()) {
+        let options = SourceOptions { table_size };
+        let n = Source::<3>::try_perlin_with(seed, options).unwrap().sample(point);
+        let expected = Source::<3>::perlin_with(seed, options).sample(point);
+        prop_assert_eq!(n, expected, "expected value {}, instead: {}", expected, n);
+    }
 }
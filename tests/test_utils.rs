@@ -39,6 +39,132 @@ proptest! {
         NoiseBuffer::<4>::new([10, 10, 10, 10], &generator);
     }
 
+    #[test]
+    fn test_noise_buffer_from_region(seed in prop::num::u64::ANY, origin in prop::array::uniform3(-1e4_f64..1e4), spacing in prop::array::uniform3(-10_f64..10.0), index in prop::array::uniform3(0_usize..12)) {
+        let generator = Source::<3>::simplex(seed);
+        let buf = NoiseBuffer::<3>::from_region(origin, spacing, [12, 12, 12], &generator);
+        let expected = generator.sample([0, 1, 2].map(|i| origin[i] + index[i] as f64 * spacing[i]));
+        prop_assert_eq!(buf[index], expected);
+    }
+
+    #[test]
+    fn test_noise_buffer_from_region_unit(seed in prop::num::u64::ANY) {
+        let generator = Source::<2>::simplex(seed).scale([0.1; 2]);
+        let buf = NoiseBuffer::<2>::from_region([0.0; 2], [1.0; 2], [30, 20], &generator);
+        prop_assert_eq!(buf.buffer, NoiseBuffer::<2>::new([30, 20], &generator).buffer);
+    }
+
+    // =================================================================
+    // test ChunkSampler
+    // =================================================================
+    #[test]
+    fn test_chunk_sampler_region(seed in prop::num::u64::ANY, origin in prop::array::uniform2(-1e4_f64..1e4), spacing in prop::array::uniform2(1e-3_f64..10.0), chunk in prop::array::uniform2(0_i64..4)) {
+        let generator = Source::<2>::simplex(seed);
+        let sampler = ChunkSampler::new(generator.clone(), [8, 5]).with_origin(origin).with_spacing(spacing);
+        let region = NoiseBuffer::<2>::from_region(origin, spacing, [32, 20], &generator);
+        let buf = sampler.chunk(chunk);
+        prop_assert_eq!(buf.shape, [8, 5]);
+        for x in 0..8 {
+            for y in 0..5 {
+                let index = [chunk[0] as usize * 8 + x, chunk[1] as usize * 5 + y];
+                prop_assert_eq!(buf[[x, y]], region[index]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_chunk_sampler_seams(seed in prop::num::u64::ANY, origin in prop::array::uniform3(-1e4_f64..1e4), spacing in prop::array::uniform3(1e-3_f64..10.0), chunk in prop::array::uniform3(-1000_i64..1000), padding in 0_usize..3) {
+        let sampler = ChunkSampler::new(Source::<3>::simplex(seed), [4, 3, 5])
+            .with_origin(origin)
+            .with_spacing(spacing)
+            .with_padding(padding);
+        let buf = sampler.chunk(chunk);
+        let neighbor = sampler.chunk([chunk[0] + 1, chunk[1], chunk[2]]);
+        prop_assert_eq!(buf.shape, [4 + 2 * padding, 3 + 2 * padding, 5 + 2 * padding]);
+        // the columns of both buffers which cover the same points of the world are identical
+        for x in 0..2 * padding {
+            for y in 0..buf.shape[1] {
+                for z in 0..buf.shape[2] {
+                    prop_assert_eq!(buf[[4 + x, y, z]], neighbor[[x, y, z]]);
+                }
+            }
+        }
+        // padding does not change the points of the chunk itself
+        let unpadded = ChunkSampler::new(Source::<3>::simplex(seed), [4, 3, 5]).with_origin(origin).with_spacing(spacing);
+        prop_assert_eq!(unpadded.chunk([chunk[0] + 1, chunk[1], chunk[2]])[[0, 0, 0]], neighbor[[padding, padding, padding]]);
+    }
+
+    #[test]
+    fn test_chunk_sampler_index_range(chunk in prop_oneof![any::<i64>(), Just(i64::MIN), Just(i64::MAX), -4_i64..4], size in 1_usize..4, padding in 0_usize..3) {
+        let sampler = ChunkSampler::new(Source::<1>::custom(|p| p[0]), [size]).with_padding(padding);
+        let start = chunk as i128 * size as i128 - padding as i128;
+        let last = start + (size + 2 * padding) as i128 - 1;
+        let valid = i64::try_from(start).is_ok() && i64::try_from(last).is_ok();
+        let result = std::panic::catch_unwind(|| sampler.chunk([chunk]));
+        prop_assert_eq!(result.is_ok(), valid, "unexpected result for chunk {} of size {} with padding {}", chunk, size, padding);
+        if let Ok(buffer) = result {
+            let expected = start as i64 as f64;
+            prop_assert_eq!(buffer[[0]], expected, "expected value {}, instead: {}", expected, buffer[[0]]);
+        }
+    }
+
+    // =================================================================
+    // test stats
+    // =================================================================
+    #[test]
+    fn test_stats_noise_buffer_constant(val in strategy_float_numeric!()) {
+        // includes a large value for which a naive mean does not reproduce the value exactly
+        for val in [val, -5.074922449306511e117] {
+            let stats = NoiseBuffer::<2>::new([30, 20], &Source::constant(val)).stats();
+            prop_assert_eq!(stats.count, 600);
+            prop_assert_eq!((stats.min, stats.max, stats.mean), (val, val, val));
+            prop_assert_eq!(stats.variance, 0.0);
+            prop_assert_eq!(stats.histogram[0], 600);
+            prop_assert!(stats.percentiles.iter().all(|&p| p == val), "percentiles not all {}, instead: {:?}", val, stats.percentiles);
+        }
+    }
+
+    #[test]
+    fn test_stats_noise_buffer_nan(size in 0_usize..100) {
+        let stats = NoiseBuffer::<1>::new([size], &Source::constant(f64::NAN)).stats();
+        prop_assert_eq!((stats.count, stats.nan_count), (0, size));
+        prop_assert!(stats.min.is_nan() && stats.mean.is_nan() && stats.percentiles[50].is_nan());
+        prop_assert_eq!(stats.histogram.iter().sum::<usize>(), 0);
+    }
+
+    #[test]
+    fn test_stats_noise_buffer_values(seed in prop::num::u64::ANY) {
+        let generator = Source::<2>::simplex(seed).scale([0.1; 2]);
+        let buf = NoiseBuffer::<2>::new([50, 40], &generator);
+        let stats = buf.stats();
+        let mean = buf.buffer.iter().sum::<f64>() / buf.buffer.len() as f64;
+        prop_assert!((stats.mean - mean).abs() <= 1e-12, "expected mean {}, instead: {}", mean, stats.mean);
+        prop_assert_eq!(stats.min, buf.buffer.iter().copied().fold(f64::INFINITY, f64::min));
+        prop_assert_eq!(stats.max, buf.buffer.iter().copied().fold(f64::NEG_INFINITY, f64::max));
+        prop_assert_eq!((stats.percentiles[0], stats.percentiles[100]), (stats.min, stats.max));
+        prop_assert!(stats.percentiles.windows(2).all(|w| w[0] <= w[1]), "percentiles not sorted: {:?}", stats.percentiles);
+        prop_assert_eq!(stats.histogram.iter().sum::<usize>(), 2000);
+    }
+
+    #[test]
+    fn test_stats_estimate(seed in prop::num::u64::ANY, samples in 1_usize..2000) {
+        let generator = Source::<3>::simplex(seed).fbm(3, 0.02, 2.0, 0.5);
+        let region = [(-100.0, 100.0), (0.0, 50.0), (20.0, 20.5)];
+        let stats = stats::estimate(&generator, region, samples, seed);
+        let (min, max) = generator.output_range().unwrap();
+        prop_assert_eq!(stats.count, samples);
+        prop_assert!(min <= stats.min && stats.max <= max, "values not in [{}, {}] range, instead: [{}, {}]", min, max, stats.min, stats.max);
+        prop_assert!(stats.variance >= 0.0, "negative variance: {}", stats.variance);
+        prop_assert_eq!(stats.clone(), stats::estimate(&generator, region, samples, seed));
+    }
+
+    #[test]
+    fn test_stats_estimate_region(seed in prop::num::u64::ANY, min in -1e6_f64..1e6, width in 0_f64..1e3) {
+        let generator = Source::<2>::custom(|p| p[0]);
+        let stats = stats::estimate(&generator, [(min, min + width), (0.0, 1.0)], 500, seed);
+        prop_assert!(min <= stats.min && stats.max <= min + width, "values not in [{}, {}] range, instead: [{}, {}]", min, min + width, stats.min, stats.max);
+    }
+
     // =================================================================
     // test Visualizer
     // =================================================================